@@ -4,6 +4,8 @@ use oxc_span::{Atom, GetSpan, Span};
 use crate::ast::*;
 
 /// Untyped AST Node Kind
+///
+/// Each variant has a counterpart in [`AstType`].
 #[derive(Debug, Clone, Copy)]
 pub enum AstKind<'a> {
     Program(&'a Program<'a>),
@@ -108,6 +110,9 @@ pub enum AstKind<'a> {
     JSXElement(&'a JSXElement<'a>),
     JSXOpeningElement(&'a JSXOpeningElement<'a>),
     JSXElementName(&'a JSXElementName<'a>),
+    JSXFragment(&'a JSXFragment<'a>),
+    JSXAttributeItem(&'a JSXAttributeItem<'a>),
+    JSXText(&'a JSXText),
 
     // TypeScript
     TSModuleBlock(&'a TSModuleBlock<'a>),
@@ -151,6 +156,133 @@ pub enum AstKind<'a> {
 unsafe impl<'a> Send for AstKind<'a> {}
 unsafe impl<'a> Sync for AstKind<'a> {}
 
+/// The type of an [`AstKind`] without the node, e.g. to look up what handles nodes of a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AstType {
+    Program,
+    Directive,
+    Hashbang,
+    BlockStatement,
+    BreakStatement,
+    ContinueStatement,
+    DebuggerStatement,
+    DoWhileStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    ForStatementInit,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+    SwitchCase,
+    CatchClause,
+    FinallyClause,
+    VariableDeclaration,
+    VariableDeclarator,
+    IdentifierName,
+    IdentifierReference,
+    BindingIdentifier,
+    LabelIdentifier,
+    PrivateIdentifier,
+    NumberLiteral,
+    StringLiteral,
+    BooleanLiteral,
+    NullLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    TemplateLiteral,
+    MetaProperty,
+    Super,
+    ArrayExpression,
+    ArrowExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ConditionalExpression,
+    LogicalExpression,
+    MemberExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    ThisExpression,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+    ObjectProperty,
+    PropertyKey,
+    Argument,
+    AssignmentTarget,
+    SimpleAssignmentTarget,
+    AssignmentTargetWithDefault,
+    ArrayExpressionElement,
+    Elision,
+    SpreadElement,
+    RestElement,
+    Function,
+    FunctionBody,
+    FormalParameters,
+    FormalParameter,
+    Class,
+    ClassHeritage,
+    StaticBlock,
+    PropertyDefinition,
+    MethodDefinition,
+    ArrayPattern,
+    ObjectPattern,
+    AssignmentPattern,
+    Decorator,
+    ModuleDeclaration,
+    JSXElement,
+    JSXOpeningElement,
+    JSXElementName,
+    JSXFragment,
+    JSXAttributeItem,
+    JSXText,
+    TSModuleBlock,
+    TSAnyKeyword,
+    TSIntersectionType,
+    TSLiteralType,
+    TSMethodSignature,
+    TSNullKeyword,
+    TSTypeLiteral,
+    TSTypeReference,
+    TSUnionType,
+    TSVoidKeyword,
+    TSIndexedAccessType,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSNonNullExpression,
+    TSEnumDeclaration,
+    TSEnumMember,
+    TSEnumBody,
+    TSImportEqualsDeclaration,
+    TSInterfaceDeclaration,
+    TSModuleDeclaration,
+    TSTypeAliasDeclaration,
+    TSTypeAnnotation,
+    TSTypeAssertion,
+    TSTypeParameter,
+    TSTypeParameterDeclaration,
+    TSTypeParameterInstantiation,
+    // Update `AstType::COUNT` when adding a variant after this one
+    TSPropertySignature,
+}
+
+impl AstType {
+    /// Number of types, for tables indexed by `AstType as usize`
+    pub const COUNT: usize = Self::TSPropertySignature as usize + 1;
+}
+
 impl<'a> AstKind<'a> {
     #[rustfmt::skip]
     pub fn is_statement(self) -> bool {
@@ -223,7 +355,15 @@ impl<'a> AstKind<'a> {
     }
 
     pub fn is_jsx(self) -> bool {
-        matches!(self, Self::JSXElement(_) | Self::JSXOpeningElement(_) | Self::JSXElementName(_))
+        matches!(
+            self,
+            Self::JSXElement(_)
+                | Self::JSXOpeningElement(_)
+                | Self::JSXElementName(_)
+                | Self::JSXFragment(_)
+                | Self::JSXAttributeItem(_)
+                | Self::JSXText(_)
+        )
     }
 
     pub fn is_specific_id_reference(&self, name: &str) -> bool {
@@ -234,6 +374,130 @@ impl<'a> AstKind<'a> {
     }
 }
 
+impl<'a> AstKind<'a> {
+    #[allow(clippy::too_many_lines)]
+    pub fn ty(&self) -> AstType {
+        match self {
+            Self::Program(_) => AstType::Program,
+            Self::Directive(_) => AstType::Directive,
+            Self::Hashbang(_) => AstType::Hashbang,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::FinallyClause(_) => AstType::FinallyClause,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::NumberLiteral(_) => AstType::NumberLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::BigintLiteral(_) => AstType::BigintLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::Super(_) => AstType::Super,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrowExpression(_) => AstType::ArrowExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::ObjectProperty(_) => AstType::ObjectProperty,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::Argument(_) => AstType::Argument,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::ArrayExpressionElement(_) => AstType::ArrayExpressionElement,
+            Self::Elision(_) => AstType::Elision,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::RestElement(_) => AstType::RestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::Class(_) => AstType::Class,
+            Self::ClassHeritage(_) => AstType::ClassHeritage,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::JSXElement(_) => AstType::JSXElement,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXFragment(_) => AstType::JSXFragment,
+            Self::JSXAttributeItem(_) => AstType::JSXAttributeItem,
+            Self::JSXText(_) => AstType::JSXText,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSSatisfiesExpression(_) => AstType::TSSatisfiesExpression,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSEnumBody(_) => AstType::TSEnumBody,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+        }
+    }
+}
+
 impl<'a> GetSpan for AstKind<'a> {
     #[allow(clippy::match_same_arms, clippy::too_many_lines)]
     fn span(&self) -> Span {
@@ -338,6 +602,9 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::JSXOpeningElement(x) => x.span,
             Self::JSXElementName(x) => x.span(),
             Self::JSXElement(x) => x.span,
+            Self::JSXFragment(x) => x.span,
+            Self::JSXAttributeItem(x) => x.span(),
+            Self::JSXText(x) => x.span,
 
             Self::TSModuleBlock(x) => x.span,
 
@@ -497,6 +764,9 @@ impl<'a> AstKind<'a> {
             Self::JSXOpeningElement(_) => "JSXOpeningElement".into(),
             Self::JSXElementName(_) => "JSXElementName".into(),
             Self::JSXElement(_) => "JSXElement".into(),
+            Self::JSXFragment(_) => "JSXFragment".into(),
+            Self::JSXAttributeItem(_) => "JSXAttributeItem".into(),
+            Self::JSXText(_) => "JSXText".into(),
 
             Self::TSModuleBlock(_) => "TSModuleBlock".into(),
 
@@ -22,7 +22,11 @@ mod visit_mut;
 pub use num_bigint::BigUint;
 
 pub use crate::{
-    ast_builder::AstBuilder, ast_kind::AstKind, trivia::Trivias, visit::Visit, visit_mut::VisitMut,
+    ast_builder::AstBuilder,
+    ast_kind::{AstKind, AstType},
+    trivia::Trivias,
+    visit::Visit,
+    visit_mut::VisitMut,
 };
 
 // After experimenting with two types of boxed enum variants:
@@ -937,12 +937,15 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_jsx_attribute_item(&mut self, item: &'a JSXAttributeItem<'a>) {
+        let kind = AstKind::JSXAttributeItem(item);
+        self.enter_node(kind);
         match &item {
             JSXAttributeItem::Attribute(attribute) => self.visit_jsx_attribute(attribute),
             JSXAttributeItem::SpreadAttribute(attribute) => {
                 self.visit_jsx_spread_attribute(attribute);
             }
         }
+        self.leave_node(kind);
     }
 
     fn visit_jsx_attribute(&mut self, attribute: &'a JSXAttribute<'a>) {
@@ -978,9 +981,12 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_jsx_fragment(&mut self, elem: &'a JSXFragment<'a>) {
+        let kind = AstKind::JSXFragment(elem);
+        self.enter_node(kind);
         for child in &elem.children {
             self.visit_jsx_child(child);
         }
+        self.leave_node(kind);
     }
 
     fn visit_jsx_child(&mut self, child: &'a JSXChild<'a>) {
@@ -989,10 +995,16 @@ pub trait Visit<'a>: Sized {
             JSXChild::Fragment(elem) => self.visit_jsx_fragment(elem),
             JSXChild::ExpressionContainer(expr) => self.visit_jsx_expression_container(expr),
             JSXChild::Spread(expr) => self.visit_jsx_spread_child(expr),
-            JSXChild::Text(_) => {}
+            JSXChild::Text(text) => self.visit_jsx_text(text),
         }
     }
 
+    fn visit_jsx_text(&mut self, text: &'a JSXText) {
+        let kind = AstKind::JSXText(text);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_jsx_spread_child(&mut self, child: &'a JSXSpreadChild<'a>) {
         self.visit_expression(&child.expression);
     }
@@ -1386,6 +1398,7 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
             _ => {}
         }
     }
@@ -1501,10 +1514,15 @@ pub trait Visit<'a>: Sized {
     fn visit_ts_type_name(&mut self, name: &'a TSTypeName<'a>) {
         match &name {
             TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
-            TSTypeName::QualifiedName(_) => {}
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
         }
     }
 
+    fn visit_ts_qualified_name(&mut self, name: &'a TSQualifiedName<'a>) {
+        self.visit_ts_type_name(&name.left);
+        self.visit_identifier_name(&name.right);
+    }
+
     fn visit_ts_null_keyword(&mut self, ty: &'a TSNullKeyword) {
         let kind = AstKind::TSNullKeyword(ty);
         self.enter_node(kind);
@@ -1542,6 +1560,13 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_ts_type_query(&mut self, ty: &'a TSTypeQuery<'a>) {
+        self.visit_ts_type_name(&ty.expr_name);
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
     fn visit_ts_union_type(&mut self, ty: &'a TSUnionType<'a>) {
         let kind = AstKind::TSUnionType(ty);
         self.enter_node(kind);
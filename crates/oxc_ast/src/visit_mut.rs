@@ -1161,10 +1161,15 @@ pub trait VisitMut<'a, 'b>: Sized {
     fn visit_ts_type_name(&mut self, name: &'b mut TSTypeName<'a>) {
         match name {
             TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
-            TSTypeName::QualifiedName(_) => {}
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
         }
     }
 
+    fn visit_ts_qualified_name(&mut self, name: &'b mut TSQualifiedName<'a>) {
+        self.visit_ts_type_name(&mut name.left);
+        self.visit_identifier_name(&mut name.right);
+    }
+
     fn visit_ts_null_keyword(&mut self, _ty: &'b mut TSNullKeyword) {}
 
     fn visit_ts_any_keyword(&mut self, _ty: &'b mut TSAnyKeyword) {}
@@ -1,8 +1,12 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use git2::Repository;
+use git2::{Delta, Diff, DiffOptions, Repository, Status};
 use miette::Diagnostic;
-use oxc_diagnostics::{thiserror::Error, Error};
+use oxc_diagnostics::{
+    thiserror::{self, Error},
+    Error,
+};
+use rustc_hash::FxHashSet;
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("No repository found")]
@@ -15,52 +19,172 @@ struct NoRepositoryFound;
 struct MultipleRepositoriesFound;
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Uncommitted changes")]
-#[diagnostic(severity(warning), help("Commit any changes before linting"))]
-struct UncommittedChanges;
+#[error("Uncommitted changes in {0:?}")]
+#[diagnostic(
+    severity(warning),
+    help("Stage or stash the unstaged changes of staged files before linting")
+)]
+struct UncommittedChanges(PathBuf);
 
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
-}
+#[derive(Debug, Error, Diagnostic)]
+#[error("Revision {0:?} not found")]
+#[diagnostic(severity(warning), help("Use a commit, branch or tag, e.g. `main` or `HEAD~1`"))]
+struct RevisionNotFound(String);
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
-    }
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read the repository: {0}")]
+#[diagnostic(severity(warning))]
+struct GitError(git2::Error);
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
-            return Err(NoRepositoryFound.into());
+/// The repository of the linted paths, used to lint changed files only.
+pub struct Git {
+    repo: Repository,
+}
+
+impl Git {
+    /// Discover the repository all `paths` belong to.
+    ///
+    /// # Errors
+    ///
+    /// * A path does not belong to a repository
+    /// * The paths belong to different repositories
+    pub fn discover(paths: &[PathBuf]) -> Result<Self, Error> {
+        let repos = paths
+            .iter()
+            .map(|path| Repository::discover(path).map_err(|_| NoRepositoryFound))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut repos = repos.into_iter();
+        let repo = repos.next().ok_or(NoRepositoryFound)?;
+        if repos.any(|other| other.path() != repo.path()) {
+            return Err(MultipleRepositoriesFound.into());
         }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(UncommittedChanges.into());
-                    }
-                }
-                Ok(repo)
-            }
-            err => err,
+        if repo.is_bare() {
+            return Err(NoRepositoryFound.into());
         }
+        Ok(Self { repo })
+    }
+
+    /// Files added or modified in the working directory since `revision`, including untracked files.
+    ///
+    /// # Errors
+    ///
+    /// * `revision` does not exist
+    /// * The repository cannot be read
+    pub fn changed_since(&self, revision: &str) -> Result<FxHashSet<PathBuf>, Error> {
+        let tree = self
+            .repo
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_tree())
+            .map_err(|_| RevisionNotFound(revision.to_string()))?;
+        let mut options = DiffOptions::new();
+        options.include_untracked(true).recurse_untracked_dirs(true);
+        let diff = self
+            .repo
+            .diff_tree_to_workdir_with_index(Some(&tree), Some(&mut options))
+            .map_err(GitError)?;
+        Ok(self.changed_files(&diff))
     }
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(MultipleRepositoriesFound.into());
+    /// Files added or modified in the index, which are linted as they are in the working directory.
+    ///
+    /// # Errors
+    ///
+    /// * A staged file has unstaged changes, so its linted content is not the committed one
+    /// * The repository cannot be read
+    pub fn staged(&self) -> Result<FxHashSet<PathBuf>, Error> {
+        // `HEAD` does not exist before the first commit
+        let head = self.repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+        let diff = self.repo.diff_tree_to_index(head.as_ref(), None, None).map_err(GitError)?;
+        let files = self.changed_files(&diff);
+        for path in &files {
+            let relative_path = path.strip_prefix(self.workdir()).unwrap_or(path);
+            let status = self.repo.status_file(relative_path).map_err(GitError)?;
+            if status.intersects(Status::WT_MODIFIED | Status::WT_DELETED) {
+                return Err(UncommittedChanges(relative_path.to_path_buf()).into());
             }
         }
-        Ok(first_repo)
+        Ok(files)
+    }
+
+    fn workdir(&self) -> &Path {
+        // Bare repositories are rejected by `Git::discover`
+        self.repo.workdir().unwrap_or_else(|| self.repo.path())
     }
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+    /// Canonical paths of the files which exist after `diff`, as compared by [`oxc_linter::Walk`].
+    fn changed_files(&self, diff: &Diff) -> FxHashSet<PathBuf> {
+        diff.deltas()
+            .filter(|delta| {
+                matches!(
+                    delta.status(),
+                    Delta::Added
+                        | Delta::Modified
+                        | Delta::Renamed
+                        | Delta::Copied
+                        | Delta::Typechange
+                        | Delta::Untracked
+                )
+            })
+            .filter_map(|delta| delta.new_file().path())
+            .map(|path| self.workdir().join(path))
+            .map(|path| path.canonicalize().unwrap_or(path))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use git2::{Repository, Signature};
+
+    use super::Git;
+
+    fn commit(repo: &Repository, file: &str) {
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(file)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("oxc", "oxc@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents = parent.iter().collect::<Vec<_>>();
+        repo.commit(Some("HEAD"), &signature, &signature, "commit", &tree, &parents).unwrap();
+    }
+
+    #[test]
+    fn changed_files() {
+        // Unique per process, so concurrent test runs do not share the repository
+        let dir = env::temp_dir().join(format!("oxc_cli_git_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        let workdir = repo.workdir().unwrap().canonicalize().unwrap();
+        fs::write(dir.join("committed.js"), "a").unwrap();
+        fs::write(dir.join("modified.js"), "a").unwrap();
+        commit(&repo, "committed.js");
+        commit(&repo, "modified.js");
+        fs::write(dir.join("modified.js"), "b").unwrap();
+        fs::write(dir.join("untracked.js"), "a").unwrap();
+
+        let git = Git::discover(&[dir.clone()]).unwrap();
+        let changed = git.changed_since("HEAD").unwrap();
+        assert_eq!(changed.len(), 2);
+        assert!(changed.contains(&workdir.join("modified.js")));
+        assert!(changed.contains(&workdir.join("untracked.js")));
+        assert!(git.changed_since("HEAD~1").unwrap().contains(&workdir.join("modified.js")));
+        assert!(git.changed_since("does-not-exist").is_err());
+
+        assert!(git.staged().unwrap().is_empty());
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("untracked.js")).unwrap();
+        index.write().unwrap();
+        let staged = git.staged().unwrap();
+        assert_eq!(staged.into_iter().collect::<Vec<_>>(), vec![workdir.join("untracked.js")]);
+
+        // The staged content differs from the linted content
+        fs::write(dir.join("untracked.js"), "b").unwrap();
+        assert!(git.staged().is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
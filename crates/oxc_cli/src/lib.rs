@@ -1,16 +1,16 @@
-// mod git;
+mod git;
 mod lint;
 mod runner;
 mod type_check;
-mod walk;
 
 use clap::{Arg, Command};
+pub use oxc_linter::Walk;
 
 pub use crate::{
+    git::Git,
     lint::{LintOptions, LintRunner},
     runner::{CliRunResult, Runner, RunnerOptions},
     type_check::{TypeCheckOptions, TypeCheckRunner},
-    walk::Walk,
 };
 
 pub fn command() -> Command {
@@ -0,0 +1,240 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    hash::Hasher,
+    io,
+    path::{Component, Path, PathBuf},
+    sync::Mutex,
+};
+
+use fnv::FnvHasher;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Error as DiagnosticError,
+};
+use oxc_linter::Message;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read the baseline file {0:?}: {1}")]
+#[diagnostic(help("Create it with --write-baseline"))]
+struct FailedToReadBaseline(PathBuf, String);
+
+/// Violations recorded by `--write-baseline` and suppressed by `--baseline`,
+/// so new rules can be enabled on existing code without fixing all of its violations first.
+///
+/// Violations are identified by their rule and a fingerprint of their message and the source line they start on,
+/// which stays the same when the code around them changes.
+pub struct Baseline {
+    /// Suppress the recorded violations, or record the current ones
+    is_write: bool,
+    /// Violations of the baseline file by file path
+    recorded: BTreeMap<String, Vec<BaselineEntry>>,
+    /// Current violations when writing, or recorded violations which no longer occur when suppressing
+    entries: Mutex<BTreeMap<String, Vec<BaselineEntry>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub rule: String,
+    pub message: String,
+    pub fingerprint: String,
+    /// Number of violations with the same fingerprint in the file
+    pub count: usize,
+}
+
+impl Baseline {
+    /// Suppress the violations recorded in `path`.
+    ///
+    /// # Errors
+    ///
+    /// * The baseline file cannot be read or parsed
+    pub fn load(path: &Path) -> Result<Self, DiagnosticError> {
+        let recorded = fs::read_to_string(path)
+            .map_err(|error| error.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|error| error.to_string()))
+            .map_err(|error| FailedToReadBaseline(path.to_path_buf(), error))?;
+        Ok(Self { is_write: false, recorded, entries: Mutex::default() })
+    }
+
+    /// Record the violations of all linted files for [`Baseline::write`].
+    pub fn new_for_write() -> Self {
+        Self { is_write: true, recorded: BTreeMap::new(), entries: Mutex::default() }
+    }
+
+    /// Remove the messages of `path` which are recorded in the baseline,
+    /// or record them when writing the baseline.
+    /// Messages without a rule, e.g. parse errors, are never recorded.
+    pub fn apply<'a>(
+        &self,
+        path: &Path,
+        source_text: &str,
+        messages: Vec<Message<'a>>,
+    ) -> Vec<Message<'a>> {
+        let file = normalize_path(path);
+        if self.is_write {
+            let mut entries: Vec<BaselineEntry> = vec![];
+            for entry in messages.iter().filter_map(|message| entry(message, source_text)) {
+                match entries.iter_mut().find(|e| e.fingerprint == entry.fingerprint) {
+                    Some(existing) => existing.count += 1,
+                    None => entries.push(entry),
+                }
+            }
+            if !entries.is_empty() {
+                self.entries.lock().unwrap().insert(file, entries);
+            }
+            return messages;
+        }
+
+        let Some(recorded) = self.recorded.get(&file) else { return messages };
+        let mut remaining = recorded.clone();
+        let messages = messages
+            .into_iter()
+            .filter(|message| {
+                let Some(entry) = entry(message, source_text) else { return true };
+                let Some(recorded) = remaining
+                    .iter_mut()
+                    .find(|recorded| recorded.fingerprint == entry.fingerprint && recorded.count > 0)
+                else {
+                    return true;
+                };
+                recorded.count -= 1;
+                false
+            })
+            .collect();
+        remaining.retain(|entry| entry.count > 0);
+        if !remaining.is_empty() {
+            self.entries.lock().unwrap().insert(file, remaining);
+        }
+        messages
+    }
+
+    /// Recorded violations which no longer occur in the linted files, or files which no longer exist.
+    pub fn unused_entries(&self) -> BTreeMap<String, Vec<BaselineEntry>> {
+        let mut unused = self.entries.lock().unwrap().clone();
+        let cwd = env::current_dir().unwrap_or_default();
+        for (file, entries) in &self.recorded {
+            if !cwd.join(file).exists() {
+                unused.entry(file.clone()).or_insert_with(|| entries.clone());
+            }
+        }
+        unused
+    }
+
+    /// Write the recorded violations, returns their number.
+    ///
+    /// # Errors
+    ///
+    /// * The baseline file cannot be written
+    pub fn write(&self, path: &Path) -> io::Result<usize> {
+        let entries = self.entries.lock().unwrap();
+        let mut json = serde_json::to_string_pretty(&*entries)?;
+        json.push('\n');
+        fs::write(path, json)?;
+        Ok(entries.values().flatten().map(|entry| entry.count).sum())
+    }
+}
+
+fn entry(message: &Message, source_text: &str) -> Option<BaselineEntry> {
+    let rule = message.rule?;
+    let text = message.error.to_string();
+    let text = text.strip_prefix(&format!("{rule}: ")).unwrap_or(&text).to_string();
+    let start = (message.start() as usize).min(source_text.len());
+    let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source_text[start..].find('\n').map_or(source_text.len(), |i| start + i);
+    let line = source_text.get(line_start..line_end).unwrap_or_default().trim();
+    let rule = rule.rule_id();
+    let fingerprint = fingerprint(&[&rule, &text, line]);
+    Some(BaselineEntry { rule, message: text, fingerprint, count: 1 })
+}
+
+/// Stable across platforms and Rust versions, so baseline files can be committed.
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hasher = FnvHasher::default();
+    for part in parts {
+        hasher.write(part.as_bytes());
+        hasher.write_u8(0);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+/// Path relative to the working directory with `/` separators, the same on all platforms.
+fn normalize_path(path: &Path) -> String {
+    let cwd = env::current_dir().unwrap_or_default();
+    let path = path.strip_prefix(&cwd).unwrap_or(path);
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic},
+        thiserror::Error,
+        Error as DiagnosticError,
+    };
+    use oxc_linter::{Message, RuleInfo, RULES};
+    use oxc_span::Span;
+
+    use super::{normalize_path, Baseline};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    struct TestDiagnostic(#[label] pub Span);
+
+    fn messages(source_text: &str) -> Vec<Message<'static>> {
+        let rule = RULES.iter().find(|rule| rule.name() == "no-debugger").map(RuleInfo::new);
+        source_text
+            .match_indices("debugger")
+            .map(|(start, _)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let span = Span::new(start as u32, start as u32 + 8);
+                Message::new(DiagnosticError::new(TestDiagnostic(span)), None)
+                    .with_rule(rule.unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_path(Path::new("./src/a.js")), "src/a.js");
+        assert_eq!(normalize_path(&env::current_dir().unwrap().join("a.js")), "a.js");
+    }
+
+    #[test]
+    fn write_and_suppress() {
+        let path = Path::new("src/a.js");
+        let source_text = "debugger;\nfoo();\ndebugger;\n";
+        let baseline = Baseline::new_for_write();
+        assert_eq!(baseline.apply(path, source_text, messages(source_text)).len(), 2);
+        let file =
+            env::temp_dir().join(format!("oxc_cli_baseline_test_{}.json", std::process::id()));
+        assert_eq!(baseline.write(&file).unwrap(), 2);
+
+        let baseline = Baseline::load(&file).unwrap();
+        fs::remove_file(&file).unwrap();
+        let entries = &baseline.recorded["src/a.js"];
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].rule, "no-debugger");
+        assert_eq!(entries[0].message, "`debugger` statement is not allowed");
+        assert_eq!(entries[0].count, 2);
+
+        // Moved violations are still suppressed
+        let source_text = "bar();\n  debugger;\ndebugger;\n";
+        assert!(baseline.apply(path, source_text, messages(source_text)).is_empty());
+        // New violations are reported
+        let source_text = "debugger;\ndebugger;\ndebugger; foo();\n";
+        assert_eq!(baseline.apply(path, source_text, messages(source_text)).len(), 1);
+        // Fixed violations are unused entries
+        let source_text = "debugger;\n";
+        assert!(baseline.apply(path, source_text, messages(source_text)).is_empty());
+        let unused = baseline.unused_entries();
+        assert_eq!(unused["src/a.js"][0].count, 1);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    fmt, fs,
+    hash::Hasher,
+    io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use fnv::FnvHasher;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, Severity},
+    Error,
+};
+use oxc_linter::{Fix, Message, RuleInfo, RULES};
+use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+
+/// Default file name of `--cache-location`
+pub const DEFAULT_CACHE_FILE: &str = ".oxlintcache";
+
+/// Diagnostics of previous runs, keyed by the source text and configuration of each file,
+/// so unchanged files are not linted again.
+///
+/// The whole cache is discarded when it was written by another version of oxlint.
+pub struct LintCache {
+    location: PathBuf,
+    files: DashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: Vec<(PathBuf, CacheEntry)>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    source_hash: u64,
+    config_hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+impl LintCache {
+    /// Load the cache from `location`, a missing or unreadable cache file starts an empty cache.
+    pub fn load(location: PathBuf) -> Self {
+        let files = fs::read(&location)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|cache| cache.version == version())
+            .map(|cache| cache.files.into_iter().collect())
+            .unwrap_or_default();
+        Self { location, files }
+    }
+
+    /// The cache file for `--cache-location`, which is `.oxlintcache` inside of it when it is a directory.
+    pub fn resolve_location(location: Option<&Path>) -> PathBuf {
+        match location {
+            Some(location) if location.is_dir() || location.to_string_lossy().ends_with('/') => {
+                location.join(DEFAULT_CACHE_FILE)
+            }
+            Some(location) => location.to_path_buf(),
+            None => PathBuf::from(DEFAULT_CACHE_FILE),
+        }
+    }
+
+    /// The messages of `path` from a previous run, when neither its source text nor its configuration changed.
+    pub fn get(
+        &self,
+        path: &Path,
+        source_hash: u64,
+        config_hash: u64,
+    ) -> Option<Vec<Message<'static>>> {
+        let entry = self.files.get(path)?;
+        if entry.source_hash != source_hash || entry.config_hash != config_hash {
+            return None;
+        }
+        Some(entry.diagnostics.iter().cloned().map(CachedDiagnostic::into_message).collect())
+    }
+
+    pub fn insert(&self, path: &Path, source_hash: u64, config_hash: u64, messages: &[Message]) {
+        let diagnostics = messages.iter().map(CachedDiagnostic::from_message).collect();
+        self.files.insert(path.to_path_buf(), CacheEntry { source_hash, config_hash, diagnostics });
+    }
+
+    /// Write the cache, dropping the entries of deleted files.
+    ///
+    /// # Errors
+    ///
+    /// * The cache file cannot be written
+    pub fn save(&self) -> io::Result<()> {
+        let mut files = self
+            .files
+            .iter()
+            .filter(|entry| entry.key().exists())
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        // for stable output
+        files.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        let cache = CacheFile { version: version(), files };
+        if let Some(dir) = self.location.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.location, serde_json::to_vec(&cache)?)
+    }
+}
+
+/// Cache entries are only valid for the version of oxlint which wrote them,
+/// since rules and their diagnostics change between versions.
+/// Changes of the enabled rules and their options are covered by the configuration hash of each entry.
+fn version() -> String {
+    format!("{}-{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// Written to the cache file, so it is stable across Rust versions, see [`FnvHasher`]
+pub fn hash_source(source_text: &str) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(source_text.as_bytes());
+    hasher.finish()
+}
+
+/// A [`Message`] detached from its diagnostic type,
+/// with everything needed to report it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    message: String,
+    severity: CachedSeverity,
+    help: Option<String>,
+    labels: Vec<CachedLabel>,
+    fix: Option<CachedFix>,
+    /// Plugin and name of the rule
+    rule: Option<(String, String)>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    start: u32,
+    end: u32,
+}
+
+impl CachedDiagnostic {
+    fn from_message(message: &Message) -> Self {
+        let error = &message.error;
+        let severity = match error.severity() {
+            Some(Severity::Advice) => CachedSeverity::Advice,
+            Some(Severity::Warning) => CachedSeverity::Warning,
+            Some(Severity::Error) | None => CachedSeverity::Error,
+        };
+        let labels = error.labels().map_or(vec![], |labels| {
+            labels
+                .map(|label| CachedLabel {
+                    label: label.label().map(ToString::to_string),
+                    offset: label.offset(),
+                    len: label.len(),
+                })
+                .collect()
+        });
+        Self {
+            message: error.to_string(),
+            severity,
+            help: error.help().map(|help| help.to_string()),
+            labels,
+            fix: message.fix.as_ref().map(|fix| CachedFix {
+                content: fix.content.to_string(),
+                start: fix.span.start,
+                end: fix.span.end,
+            }),
+            rule: message.rule.map(|rule| (rule.plugin.to_string(), rule.name.to_string())),
+        }
+    }
+
+    fn into_message(self) -> Message<'static> {
+        let fix = self
+            .fix
+            .as_ref()
+            .map(|fix| Fix::new(fix.content.clone(), Span::new(fix.start, fix.end)));
+        // Rules which no longer exist are not reported as rules
+        let rule = self.rule.as_ref().and_then(|(plugin, name)| {
+            RULES
+                .iter()
+                .find(|rule| rule.plugin_name() == plugin && rule.name() == name)
+                .map(RuleInfo::new)
+        });
+        let message = Message::new(Error::new(self), fix);
+        match rule {
+            Some(rule) => message.with_rule(rule),
+            None => message,
+        }
+    }
+}
+
+impl fmt::Display for CachedDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for CachedDiagnostic {}
+
+impl Diagnostic for CachedDiagnostic {
+    fn severity(&self) -> Option<Severity> {
+        Some(match self.severity {
+            CachedSeverity::Advice => Severity::Advice,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Error => Severity::Error,
+        })
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help.as_ref().map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            return None;
+        }
+        Some(Box::new(
+            self.labels
+                .iter()
+                .map(|label| LabeledSpan::new(label.label.clone(), label.offset, label.len)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic, Severity},
+        thiserror::Error,
+        Error as DiagnosticError,
+    };
+    use oxc_linter::{Fix, Message};
+    use oxc_span::Span;
+
+    use super::{hash_source, LintCache};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("`debugger` statement is not allowed")]
+    #[diagnostic(severity(warning), help("Remove it"))]
+    struct TestDiagnostic(#[label("here")] pub Span);
+
+    fn message() -> Message<'static> {
+        let error = DiagnosticError::new(TestDiagnostic(Span::new(0, 8)));
+        Message::new(error, Some(Fix::delete(Span::new(0, 9))))
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = env::temp_dir().join(format!("oxc_cli_cache_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let location = LintCache::resolve_location(Some(&dir));
+        assert_eq!(location, dir.join(".oxlintcache"));
+        let path = Path::new("Cargo.toml");
+        let source_hash = hash_source("debugger;");
+
+        let cache = LintCache::load(location.clone());
+        assert!(cache.get(path, source_hash, 1).is_none());
+        cache.insert(path, source_hash, 1, &[message()]);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(location);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(cache.get(path, hash_source("debugger"), 1).is_none());
+        assert!(cache.get(path, source_hash, 2).is_none());
+
+        let messages = cache.get(path, source_hash, 1).unwrap();
+        assert_eq!(messages.len(), 1);
+        let cached = &messages[0];
+        let expected = message();
+        assert_eq!(cached.error.to_string(), expected.error.to_string());
+        assert_eq!(cached.error.severity(), Some(Severity::Warning));
+        assert_eq!(cached.error.help().unwrap().to_string(), "Remove it");
+        assert_eq!((cached.start(), cached.end()), (expected.start(), expected.end()));
+        assert_eq!(cached.fix.as_ref().unwrap().span, Span::new(0, 9));
+    }
+}
@@ -1,14 +1,19 @@
 use clap::{builder::ValueParser, Arg, ArgAction, Command};
 
+use super::reporter::OutputFormat;
+
+#[allow(clippy::too_many_lines)]
 pub(super) fn lint_command(command: Command) -> Command {
     command
             .arg_required_else_help(true)
             .after_help(
                 "# Rule Selection
 
-To allow or deny a rule, multiple -A <NAME> or -D <NAME>.
+To allow, warn or deny a rule, multiple -A <NAME>, -W <NAME> or -D <NAME>.
+
+For example: -D correctness -W no-debugger -A no-empty.
 
-For example: -D correctness -A no-debugger.
+Denied rules are reported as errors, warned rules are reported as warnings.
 
 The categories are:
   * correctness - code that is outright wrong or useless
@@ -17,6 +22,24 @@ The categories are:
 
 The default category is -D correctness.
 
+# Configuration File
+
+Rules are further configured by the closest `.oxlintrc.json` or `.eslintrc.json` found from the current directory upwards,
+followed by the configuration files of nested directories. Supported properties are `root`, `rules`, `overrides`,
+`ignorePatterns`, `env` and `globals`.
+
+# Output Format
+
+The format of the report is selected by -f <FORMAT> or --format <FORMAT>:
+  * default    - human readable report with code frames
+  * json       - ESLint compatible JSON
+  * checkstyle - Checkstyle XML
+  * junit      - JUnit XML
+  * sarif      - SARIF 2.1.0 for code scanning tools
+  * github     - GitHub Actions annotations
+
+Only the report is written to stdout when a format other than the default is selected.
+
 # Profile Rule Performance
 
 Setting the TIMING environment variable will display the execution time of each rule.
@@ -27,9 +50,9 @@ TIMING=1 \"the lint command\"
                 Arg::new("path")
                     .value_name("PATH")
                     .num_args(1..)
-                    .required_unless_present("rules")
+                    .required_unless_present_any(["rules", "changed-since", "staged", "stdin"])
                     .value_parser(ValueParser::path_buf())
-                    .help("File or Directory paths to scan. Directories are scanned recursively.")
+                    .help("File or Directory paths to scan. Directories are scanned recursively. Defaults to the working directory with --changed-since and --staged.")
             )
             .arg(
                 Arg::new("allow")
@@ -39,6 +62,14 @@ TIMING=1 \"the lint command\"
                 .action(ArgAction::Append)
                 .help("Allow a rule or a category")
             )
+            .arg(
+                Arg::new("warn")
+                .long("warn")
+                .short('W')
+                .required(false)
+                .action(ArgAction::Append)
+                .help("Warn a rule or a category")
+            )
             .arg(
                 Arg::new("deny")
                 .long("deny")
@@ -47,6 +78,14 @@ TIMING=1 \"the lint command\"
                 .action(ArgAction::Append)
                 .help("Deny a rule or a category")
             )
+            .arg(
+                Arg::new("config")
+                .long("config")
+                .short('c')
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .help("This option allows you to use a configuration file, instead of the discovered `.oxlintrc.json` or `.eslintrc.json` files.")
+            )
             .arg(
                 Arg::new("fix")
                 .long("fix")
@@ -54,6 +93,121 @@ TIMING=1 \"the lint command\"
                 .action(ArgAction::SetTrue)
                 .help("This option allows you to enable oxc to fix as many issues as possible. If enabled, only unfixed issues are reported in the output")
             )
+            .arg(
+                Arg::new("fix-suggestions")
+                .long("fix-suggestions")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Also apply suggestions, which are fixes that may change the behavior of the code. Implies --fix.")
+            )
+            .arg(
+                Arg::new("fix-dry-run")
+                .long("fix-dry-run")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print a unified diff of the fixes for each file instead of writing them. Can be combined with --fix-suggestions.")
+            )
+            .arg(
+                Arg::new("format")
+                .long("format")
+                .short('f')
+                .required(false)
+                .value_parser(OutputFormat::NAMES)
+                .help("Use a specific output format.")
+            )
+            .arg(
+                Arg::new("report-unused-disable-directives")
+                .long("report-unused-disable-directives")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report eslint-disable directive comments which did not suppress any problem. These can be removed with --fix.")
+            )
+            .arg(
+                Arg::new("no-inline-config")
+                .long("no-inline-config")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Ignore configuration comments like /* eslint no-console: off */ and /* global foo */, and eslint-disable directives.")
+            )
+            .arg(
+                Arg::new("import-plugin")
+                .long("import-plugin")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Resolve imports to check them against the imported files with the import plugin rules. Disables --cache.")
+            )
+            .arg(
+                Arg::new("cache")
+                .long("cache")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Only lint files which changed since the last run, reusing the diagnostics of unchanged files. The cache is invalidated when the configuration or the enabled rules change.")
+            )
+            .arg(
+                Arg::new("cache-location")
+                .long("cache-location")
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .help("Path to the cache file or directory, defaults to .oxlintcache in the working directory.")
+            )
+            .arg(
+                Arg::new("changed-since")
+                .long("changed-since")
+                .value_name("REVISION")
+                .required(false)
+                .conflicts_with("staged")
+                .help("Only lint files added or modified since a git revision, including uncommitted and untracked files.")
+            )
+            .arg(
+                Arg::new("staged")
+                .long("staged")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Only lint files added or modified in the git index, e.g. in a pre-commit hook.")
+            )
+            .arg(
+                Arg::new("baseline")
+                .long("baseline")
+                .value_name("FILE")
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .conflicts_with("write-baseline")
+                .help("Suppress the violations recorded in a baseline file, only new violations are reported. Recorded violations which no longer occur are listed so the file can shrink.")
+            )
+            .arg(
+                Arg::new("write-baseline")
+                .long("write-baseline")
+                .value_name("FILE")
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .help("Record the current violations in a baseline file for --baseline.")
+            )
+            .arg(
+                Arg::new("watch")
+                .long("watch")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Lint all files, then lint files again when they change. All files are linted again when a configuration or ignore file changes.")
+            )
+            .arg(
+                Arg::new("stdin")
+                .long("stdin")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["path", "watch", "changed-since", "staged"])
+                .help("Lint the source text from stdin. With --fix, the fixed source text is printed to stdout and the problems to stderr.")
+            )
+            .arg(
+                Arg::new("stdin-filename")
+                .long("stdin-filename")
+                .value_name("PATH")
+                .required(false)
+                // `requires("stdin")` is always satisfied by the default value of the flag,
+                // instead the path is required without `--stdin`
+                .conflicts_with_all(["path", "watch", "changed-since", "staged"])
+                .value_parser(ValueParser::path_buf())
+                .help("Path of the source text from stdin, which determines its source type and configuration.")
+            )
             .arg(
               Arg::new("quiet")
                 .long("quiet")
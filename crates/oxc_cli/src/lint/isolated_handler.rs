@@ -1,33 +1,59 @@
 use std::{
     fs,
-    io::{BufWriter, Write},
+    io::{self, BufWriter, Write},
     path::{Path, PathBuf},
-    rc::Rc,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        mpsc, Arc,
+        mpsc, Arc, Mutex,
     },
 };
 
 use miette::NamedSource;
-use oxc_allocator::Allocator;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
     Error, GraphicalReportHandler, Severity,
 };
-use oxc_linter::{Fixer, LintContext, Linter};
-use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
-use oxc_span::SourceType;
+use oxc_linter::{LineIndex, LintResult, LintService, Linter, Location, Message, SkippedFix, Walk};
+use rustc_hash::{FxHashMap, FxHashSet};
+use similar::TextDiff;
 
-use super::options::LintOptions;
-use crate::{CliRunResult, Walk};
+use super::{
+    baseline::Baseline,
+    cache::{self, LintCache},
+    options::LintOptions,
+    reporter::{FileDiagnostics, OutputFormat, ReportedDiagnostic},
+};
+use crate::CliRunResult;
 
 pub struct IsolatedLintHandler {
     options: Arc<LintOptions>,
 
-    linter: Arc<Linter>,
+    service: LintService,
+
+    /// Diagnostics of unchanged files from previous runs, enabled by `--cache`
+    cache: Option<Arc<LintCache>>,
+
+    /// Files to lint, from `--changed-since` or `--staged`
+    changed_files: Option<Arc<FxHashSet<PathBuf>>>,
+
+    /// Violations to suppress with `--baseline`, or to record with `--write-baseline`
+    baseline: Option<Arc<Baseline>>,
+
+    /// Fixes which were not applied because they overlap with other fixes, see [`Self::describe_skipped_fixes`]
+    skipped_fixes: Arc<Mutex<Vec<String>>>,
+
+    /// Warnings and errors of each file, see [`Self::take_file_counts`]
+    file_counts: Mutex<FxHashMap<PathBuf, (usize, usize)>>,
+}
+
+/// Everything besides the service and the path needed to lint a file on a worker thread.
+#[derive(Clone, Copy)]
+struct LintPathContext<'a> {
+    cache: Option<&'a LintCache>,
+    baseline: Option<&'a Baseline>,
+    fix_dry_run: bool,
+    skipped_fixes: &'a Mutex<Vec<String>>,
 }
 
 #[derive(Debug, Error, Diagnostic)]
@@ -37,7 +63,36 @@ pub struct MinifiedFileError(pub PathBuf);
 
 impl IsolatedLintHandler {
     pub(super) fn new(options: Arc<LintOptions>, linter: Arc<Linter>) -> Self {
-        Self { options, linter }
+        // Fixes are not cached, so `--fix-dry-run` always lints to print them.
+        // Diagnostics of the import plugin depend on other files, which are not part of the cache key.
+        let cache = (options.cache && !options.fix_dry_run && !options.import_plugin).then(|| {
+            let location = LintCache::resolve_location(options.cache_location.as_deref());
+            Arc::new(LintCache::load(location))
+        });
+        // The fixes are reported instead of printed as a diff by `--fix-dry-run`
+        let apply_fixes = !options.format.reports_fixes() || (options.fix && !options.fix_dry_run);
+        let service = LintService::new(linter).with_apply_fixes(apply_fixes);
+        Self {
+            options,
+            service,
+            cache,
+            changed_files: None,
+            baseline: None,
+            skipped_fixes: Arc::default(),
+            file_counts: Mutex::default(),
+        }
+    }
+
+    #[must_use]
+    pub(super) fn with_changed_files(mut self, changed_files: Option<FxHashSet<PathBuf>>) -> Self {
+        self.changed_files = changed_files.map(Arc::new);
+        self
+    }
+
+    #[must_use]
+    pub(super) fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline.map(Arc::new);
+        self
     }
 
     /// # Panics
@@ -47,79 +102,162 @@ impl IsolatedLintHandler {
         let now = std::time::Instant::now();
 
         let number_of_files = Arc::new(AtomicUsize::new(0));
-        let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<Error>)>();
+        let (tx_error, rx_error) = mpsc::channel::<FileDiagnostics>();
 
         self.process_paths(&number_of_files, tx_error);
-        let (number_of_warnings, number_of_errors) = self.process_diagnostics(&rx_error);
+        let (number_of_warnings, number_of_errors) =
+            self.process_diagnostics(&rx_error, io::stdout());
+        self.save_cache();
+        self.finish_baseline();
 
         CliRunResult::LintResult {
             duration: now.elapsed(),
-            number_of_rules: self.linter.number_of_rules(),
+            number_of_rules: self.linter().number_of_rules(),
             number_of_files: number_of_files.load(Ordering::Relaxed),
-            number_of_warnings,
+            number_of_warnings: self.options.reported_warnings(number_of_warnings),
+            number_of_errors,
+            max_warnings_exceeded: self.options.max_warnings_exceeded(number_of_warnings),
+            print_summary: !self.options.format.is_machine_readable(),
+            skipped_fixes: self.take_skipped_fixes(),
+        }
+    }
+
+    /// Lint `source_text` read from stdin as if it was the file at `path`.
+    /// With `--fix`, the fixed code is printed to stdout and the diagnostics to stderr.
+    pub(super) fn run_stdin(&self, path: &Path, source_text: &str) -> CliRunResult {
+        let now = std::time::Instant::now();
+
+        let Some(LintResult { fixed_code, messages, skipped_fixes }) =
+            self.service.lint_source(path, source_text)
+        else {
+            return CliRunResult::InvalidOptions {
+                message: format!("Unsupported file extension of --stdin-filename {path:?}"),
+            };
+        };
+        let fix_diff = fixed_code
+            .as_ref()
+            .filter(|_| self.options.fix_dry_run)
+            .map(|fixed_code| Self::fix_diff(path, source_text, fixed_code));
+        let print_fixed_code = self.options.fix && !self.options.fix_dry_run;
+        if print_fixed_code {
+            // Also print the unchanged code, so the output can always replace the input
+            print!("{}", fixed_code.as_deref().unwrap_or(source_text));
+        }
+        let source_text = fixed_code.as_deref().unwrap_or(source_text);
+        let skipped_fixes = Self::describe_skipped_fixes(path, source_text, &skipped_fixes);
+
+        let (tx_error, rx_error) = mpsc::channel::<FileDiagnostics>();
+        let baseline = self.baseline.as_deref();
+        if let Some(diagnostics) = Self::report(baseline, path, source_text, messages, fix_diff) {
+            tx_error.send(diagnostics).unwrap();
+        }
+        drop(tx_error);
+        let (number_of_warnings, number_of_errors) = if print_fixed_code {
+            self.process_diagnostics(&rx_error, io::stderr())
+        } else {
+            self.process_diagnostics(&rx_error, io::stdout())
+        };
+        self.finish_baseline();
+
+        CliRunResult::LintResult {
+            duration: now.elapsed(),
+            number_of_rules: self.linter().number_of_rules(),
+            number_of_files: 1,
+            number_of_warnings: self.options.reported_warnings(number_of_warnings),
             number_of_errors,
-            max_warnings_exceeded: self
-                .options
-                .max_warnings
-                .map_or(false, |max_warnings| number_of_warnings > max_warnings),
+            max_warnings_exceeded: self.options.max_warnings_exceeded(number_of_warnings),
+            // The summary would be mixed into the fixed code
+            print_summary: !self.options.format.is_machine_readable() && !print_fixed_code,
+            skipped_fixes,
+        }
+    }
+
+    /// Warnings are not reported with `--quiet`, they only count towards `--max-warnings`.
+    /// The number of warnings and errors of each file with diagnostics since the last call,
+    /// for the summary of the whole tree in watch mode
+    pub(super) fn take_file_counts(&self) -> FxHashMap<PathBuf, (usize, usize)> {
+        std::mem::take(&mut *self.file_counts.lock().unwrap())
+    }
+
+    /// The skipped fixes of all files, sorted for stable output
+    fn take_skipped_fixes(&self) -> Vec<String> {
+        let mut skipped_fixes = std::mem::take(&mut *self.skipped_fixes.lock().unwrap());
+        skipped_fixes.sort_unstable();
+        skipped_fixes
+    }
+
+    /// `path:line:column rule` of each skipped fix, `source_text` is the fixed code the spans refer to.
+    fn describe_skipped_fixes(
+        path: &Path,
+        source_text: &str,
+        skipped_fixes: &[SkippedFix],
+    ) -> Vec<String> {
+        if skipped_fixes.is_empty() {
+            return vec![];
         }
+        let line_index = LineIndex::new(source_text);
+        skipped_fixes
+            .iter()
+            .map(|fix| {
+                let Location { line, column } = line_index.location(fix.span.start as usize);
+                let rule = fix.rule.map(|rule| format!(" {rule}")).unwrap_or_default();
+                format!("{}:{line}:{column}{rule}", path.display())
+            })
+            .collect()
+    }
+
+    pub(super) fn linter(&self) -> &Linter {
+        self.service.linter()
     }
 
     fn process_paths(
         &self,
         number_of_files: &Arc<AtomicUsize>,
-        tx_error: mpsc::Sender<(PathBuf, Vec<Error>)>,
+        tx_error: mpsc::Sender<FileDiagnostics>,
     ) {
-        let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
-
-        let walk = Walk::new(&self.options);
-        let number_of_files = Arc::clone(number_of_files);
-        rayon::spawn(move || {
-            let mut count = 0;
-            walk.iter().for_each(|path| {
-                count += 1;
-                tx_path.send(path).unwrap();
-            });
-            number_of_files.store(count, Ordering::Relaxed);
-        });
-
-        let linter = Arc::clone(&self.linter);
-        rayon::spawn(move || {
-            while let Ok(path) = rx_path.recv() {
-                let tx_error = tx_error.clone();
-                let linter = Arc::clone(&linter);
-                rayon::spawn(move || {
-                    if let Some(diagnostics) = Self::lint_path(&linter, &path) {
-                        tx_error.send(diagnostics).unwrap();
-                    }
-                    drop(tx_error);
-                });
-            }
+        let walk = Walk::new(&self.options.walk_options())
+            .with_config_store(self.linter().config_store().cloned())
+            .with_changed_files(self.changed_files.clone());
+        let cache = self.cache.clone();
+        let baseline = self.baseline.clone();
+        let fix_dry_run = self.options.fix_dry_run;
+        let skipped_fixes = Arc::clone(&self.skipped_fixes);
+        self.service.spawn(walk, number_of_files, tx_error, move |service, path| {
+            let context = LintPathContext {
+                cache: cache.as_deref(),
+                baseline: baseline.as_deref(),
+                fix_dry_run,
+                skipped_fixes: &skipped_fixes,
+            };
+            Self::lint_path(service, &context, path)
         });
     }
 
-    fn process_diagnostics(
+    fn process_diagnostics<W: Write>(
         &self,
-        rx_error: &mpsc::Receiver<(PathBuf, Vec<Error>)>,
+        rx_error: &mpsc::Receiver<FileDiagnostics>,
+        writer: W,
     ) -> (usize, usize) {
         let mut number_of_warnings = 0;
         let mut number_of_errors = 0;
-        let mut buf_writer = BufWriter::new(std::io::stdout());
+        let mut buf_writer = BufWriter::new(writer);
         let handler = GraphicalReportHandler::new();
+        let format = self.options.format;
+        // Machine readable formats are written as a whole after all files are linted.
+        let mut reported_files = vec![];
 
-        while let Ok((path, diagnostics)) = rx_error.recv() {
-            let mut output = String::new();
+        while let Ok(FileDiagnostics { path, diagnostics, fix_diff }) = rx_error.recv() {
+            // The diff can only be mixed into the graphical report.
+            let mut output = fix_diff.filter(|_| !format.is_machine_readable()).unwrap_or_default();
+            let mut reported = vec![];
+            let mut file_counts = (0, 0);
             for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
+                let severity = diagnostic.error.severity();
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity.is_none() || severity == Some(Severity::Error);
-                if is_warning || is_error {
-                    if is_warning {
-                        number_of_warnings += 1;
-                    }
-                    if is_error {
-                        number_of_errors += 1;
-                    }
+                if is_warning {
+                    number_of_warnings += 1;
+                    file_counts.0 += 1;
                     // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
                     // Note that it does not disable ALL diagnostics, only Warning diagnostics
                     if self.options.quiet {
@@ -131,10 +269,18 @@ impl IsolatedLintHandler {
                             continue;
                         }
                     }
+                } else if is_error {
+                    number_of_errors += 1;
+                    file_counts.1 += 1;
+                }
+
+                if format.is_machine_readable() {
+                    reported.push(diagnostic);
+                    continue;
                 }
 
                 let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+                handler.render_report(&mut err, diagnostic.error.as_ref()).unwrap();
                 // Skip large output and print only once
                 if err.lines().any(|line| line.len() >= 400) {
                     let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
@@ -144,66 +290,228 @@ impl IsolatedLintHandler {
                 }
                 output.push_str(&err);
             }
+            self.file_counts.lock().unwrap().insert(path.clone(), file_counts);
+            if !reported.is_empty() {
+                reported_files.push(FileDiagnostics {
+                    path,
+                    diagnostics: reported,
+                    fix_diff: None,
+                });
+            }
             buf_writer.write_all(output.as_bytes()).unwrap();
         }
 
+        if format.is_machine_readable() {
+            // for stable output ordering
+            OutputFormat::sort(&mut reported_files);
+            format.write(&mut buf_writer, &reported_files).unwrap();
+        }
+
         buf_writer.flush().unwrap();
         (number_of_warnings, number_of_errors)
     }
 
-    fn lint_path(linter: &Linter, path: &Path) -> Option<(PathBuf, Vec<Error>)> {
-        let source_text =
-            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
-        let allocator = Allocator::default();
-        let source_type =
-            SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
-        let ret = Parser::new(&allocator, &source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
-
-        if !ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, ret.errors));
-        };
-
-        let program = allocator.alloc(ret.program);
-        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
-            .with_trivias(ret.trivias)
-            .with_check_syntax_error(true)
-            .with_module_record_builder(true)
-            .build(program);
+    fn save_cache(&self) {
+        let Some(cache) = &self.cache else { return };
+        if let Err(error) = cache.save() {
+            eprintln!("Failed to write the cache file: {error}");
+        }
+    }
 
-        if !semantic_ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, semantic_ret.errors));
-        };
+    /// Write the baseline for `--write-baseline`,
+    /// or print the entries of `--baseline` which no longer occur so they can be removed.
+    /// Both go to stderr to keep machine-readable and `--stdin` output intact.
+    fn finish_baseline(&self) {
+        let Some(baseline) = &self.baseline else { return };
+        if let Some(path) = &self.options.write_baseline {
+            match baseline.write(path) {
+                Ok(count) => eprintln!("Wrote {count} violations to the baseline {path:?}."),
+                Err(error) => eprintln!("Failed to write the baseline {path:?}: {error}"),
+            }
+            return;
+        }
+        let unused = baseline.unused_entries();
+        if unused.is_empty() {
+            return;
+        }
+        eprintln!("Baseline entries which no longer occur, remove them with --write-baseline:");
+        for (file, entries) in unused {
+            for entry in entries {
+                eprintln!("  {file}: {} {} ({}x)", entry.rule, entry.message, entry.count);
+            }
+        }
+    }
 
-        let lint_ctx = LintContext::new(&Rc::new(semantic_ret.semantic));
-        let result = linter.run(lint_ctx);
+    fn lint_path(
+        service: &LintService,
+        context: &LintPathContext,
+        path: &Path,
+    ) -> Option<FileDiagnostics> {
+        let LintPathContext { cache, baseline, fix_dry_run, skipped_fixes } = *context;
+        let source_text =
+            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
 
-        if result.is_empty() {
-            return None;
+        // Errors of nested configuration files are reported by linting
+        let cache_key = cache
+            .zip(service.linter().config_hash(path).ok())
+            .map(|(cache, config_hash)| (cache, cache::hash_source(&source_text), config_hash));
+        if let Some((cache, source_hash, config_hash)) = cache_key {
+            if let Some(messages) = cache.get(path, source_hash, config_hash) {
+                return Self::report(baseline, path, &source_text, messages, None);
+            }
         }
 
-        if linter.has_fix() {
-            let fix_result = Fixer::new(&source_text, result).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            let errors = fix_result.messages.into_iter().map(|m| m.error).collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, errors));
+        let LintResult { fixed_code, messages, skipped_fixes: skipped } =
+            service.lint_source(path, &source_text).unwrap_or_else(|| panic!("Incorrect {path:?}"));
+        if !skipped.is_empty() {
+            let fixed_source_text = fixed_code.as_deref().unwrap_or(&source_text);
+            let skipped = Self::describe_skipped_fixes(path, fixed_source_text, &skipped);
+            skipped_fixes.lock().unwrap().extend(skipped);
         }
-
-        let errors = result.into_iter().map(|diagnostic| diagnostic.error).collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        let mut fix_diff = None;
+        if let Some(fixed_code) = &fixed_code {
+            if fix_dry_run {
+                fix_diff = Some(Self::fix_diff(path, &source_text, fixed_code));
+            } else {
+                fs::write(path, fixed_code.as_bytes()).unwrap();
+            }
+        }
+        if let Some((cache, source_hash, config_hash)) = cache_key {
+            // The messages are those of the fixed code written to the file
+            let source_hash = fixed_code.as_deref().map_or(source_hash, cache::hash_source);
+            cache.insert(path, source_hash, config_hash, &messages);
+        }
+        let source_text = fixed_code.unwrap_or(source_text);
+        Self::report(baseline, path, &source_text, messages, fix_diff)
     }
 
-    fn wrap_diagnostics(
+    /// The diagnostics of `path` which are not suppressed by the baseline.
+    fn report(
+        baseline: Option<&Baseline>,
         path: &Path,
         source_text: &str,
-        diagnostics: Vec<Error>,
-    ) -> (PathBuf, Vec<Error>) {
+        messages: Vec<Message>,
+        fix_diff: Option<String>,
+    ) -> Option<FileDiagnostics> {
+        let messages = match baseline {
+            Some(baseline) => baseline.apply(path, source_text, messages),
+            None => messages,
+        };
+        if messages.is_empty() && fix_diff.is_none() {
+            return None;
+        }
+        let mut diagnostics = Self::wrap_messages(path, source_text, messages);
+        diagnostics.fix_diff = fix_diff;
+        Some(diagnostics)
+    }
+
+    fn wrap_messages(path: &Path, source_text: &str, messages: Vec<Message>) -> FileDiagnostics {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
-        let diagnostics = diagnostics
+        let line_index = LineIndex::new(source_text);
+        let diagnostics = messages
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|message| {
+                let error = message.error.with_source_code(Arc::clone(&source));
+                ReportedDiagnostic::new(error, message.fix.as_ref(), message.rule, &line_index)
+            })
             .collect();
-        (path.to_path_buf(), diagnostics)
+        FileDiagnostics { path: path.to_path_buf(), diagnostics, fix_diff: None }
+    }
+
+    fn fix_diff(path: &Path, source_text: &str, fixed_code: &str) -> String {
+        let path = path.to_string_lossy();
+        TextDiff::from_lines(source_text, fixed_code)
+            .unified_diff()
+            .header(&path, &path)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::Path,
+        sync::{mpsc, Arc},
+    };
+
+    use clap::Command;
+    use oxc_linter::{AllowWarnDeny, Linter, RuleWithSeverity, MAX_FIX_PASSES, RULES};
+
+    use super::IsolatedLintHandler;
+    use crate::{lint::LintOptions, runner::RunnerOptions, CliRunResult};
+
+    #[test]
+    fn skipped_fixes() {
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--fix", "--fix-suggestions", "--stdin"])
+            .unwrap();
+        let options = Arc::new(LintOptions::from(&matches));
+        let rule = RULES.iter().find(|rule| rule.name() == "no-unsafe-negation").unwrap();
+        let linter =
+            Linter::from_rules(vec![RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Deny)])
+                .with_fix(true)
+                .with_fix_suggestions(true);
+        let handler = IsolatedLintHandler::new(options, Arc::new(linter));
+
+        // Each pass fixes the outermost negation only, the fixes of the others overlap with it
+        let source_text = (0..20).fold(String::from("a"), |code, i| format!("!({code}) in b{i}"));
+        let result = handler.run_stdin(Path::new("a.js"), &source_text);
+        let CliRunResult::LintResult { skipped_fixes, .. } = result else { unreachable!() };
+        assert_eq!(skipped_fixes.len(), 20 - MAX_FIX_PASSES);
+        assert!(skipped_fixes.iter().all(|fix| fix.starts_with("a.js:1:")));
+        assert!(skipped_fixes[0].ends_with(" eslint(no-unsafe-negation)"));
+    }
+
+    #[test]
+    fn quiet_warnings() {
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--quiet", "--max-warnings", "0", "--stdin"])
+            .unwrap();
+        let options = Arc::new(LintOptions::from(&matches));
+        let rule = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let linter =
+            Linter::from_rules(vec![RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn)]);
+        let handler = IsolatedLintHandler::new(options, Arc::new(linter));
+
+        // Left out of the summary, but still counted for `--max-warnings`
+        let result = handler.run_stdin(Path::new("a.js"), "debugger;");
+        let CliRunResult::LintResult { number_of_warnings, max_warnings_exceeded, .. } = result
+        else {
+            unreachable!()
+        };
+        assert_eq!(number_of_warnings, 0);
+        assert!(max_warnings_exceeded);
+    }
+
+    #[test]
+    fn json_fix_dry_run() {
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--fix-dry-run", "--format", "json", "--stdin"])
+            .unwrap();
+        let options = Arc::new(LintOptions::from(&matches));
+        let rule = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let linter =
+            Linter::from_rules(vec![RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Deny)])
+                .with_fix(true);
+        let handler = IsolatedLintHandler::new(options, Arc::new(linter));
+
+        // The fix is reported instead of applied
+        let path = Path::new("a.js");
+        let result = handler.service.lint_source(path, "debugger;").unwrap();
+        assert!(result.fixed_code.is_none());
+        let (tx_error, rx_error) = mpsc::channel();
+        tx_error
+            .send(
+                IsolatedLintHandler::report(None, path, "debugger;", result.messages, None)
+                    .unwrap(),
+            )
+            .unwrap();
+        drop(tx_error);
+        let mut output = vec![];
+        handler.process_diagnostics(&rx_error, &mut output);
+
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(json[0]["fixableErrorCount"], 1);
+        assert_eq!(json[0]["messages"][0]["fix"]["range"], serde_json::json!([0, 9]));
     }
 }
@@ -1,21 +1,35 @@
+mod baseline;
+mod cache;
 mod command;
 mod error;
 mod isolated_handler;
 mod options;
+mod reporter;
+mod watch;
 
-use std::{io::BufWriter, sync::Arc, time::Duration};
+use std::{
+    env,
+    io::{self, BufWriter, Read},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
+use oxc_diagnostics::Error as DiagnosticError;
 use oxc_index::assert_impl_all;
-use oxc_linter::{Linter, RuleCategory, RuleEnum, RULES};
-use rustc_hash::FxHashSet;
+use oxc_linter::{
+    config::{ConfigStore, ESLintRuleConfig},
+    rule::rule_name_of_alias,
+    AllowWarnDeny, Linter, RuleCategory, RuleEnum, RuleWithSeverity, RULES,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 
+use self::{baseline::Baseline, isolated_handler::IsolatedLintHandler};
 pub use self::{error::Error, options::LintOptions};
-use self::{isolated_handler::IsolatedLintHandler, options::AllowWarnDeny};
-use crate::{CliRunResult, Runner};
+use crate::{CliRunResult, Git, Runner};
 
 pub struct LintRunner {
     options: Arc<LintOptions>,
-    linter: Arc<Linter>,
 }
 assert_impl_all!(LintRunner: Send, Sync);
 
@@ -32,10 +46,7 @@ impl Runner for LintRunner {
     const NAME: &'static str = "lint";
 
     fn new(options: LintOptions) -> Self {
-        let linter = Linter::from_rules(Self::derive_rules(&options))
-            .with_fix(options.fix)
-            .with_print_execution_times(options.print_execution_times);
-        Self { options: Arc::new(options), linter: Arc::new(linter) }
+        Self { options: Arc::new(options) }
     }
 
     fn run(&self) -> CliRunResult {
@@ -44,75 +55,192 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
-        let result =
-            IsolatedLintHandler::new(Arc::clone(&self.options), Arc::clone(&self.linter)).run();
+        if self.options.watch {
+            return self.watch();
+        }
 
-        if self.options.print_execution_times {
-            self.print_execution_times();
+        if self.options.stdin {
+            return self.lint_stdin();
         }
 
-        result
+        self.lint(None)
     }
 }
 
 impl LintRunner {
+    /// Lint all files, or only `files` when linting changed files in watch mode.
+    fn lint(&self, files: Option<FxHashSet<PathBuf>>) -> CliRunResult {
+        self.lint_files(files).0
+    }
+
+    /// Lint all files, or only `files`, together with the number of warnings and errors
+    /// of each file with diagnostics, see [`IsolatedLintHandler::take_file_counts`].
+    fn lint_files(
+        &self,
+        files: Option<FxHashSet<PathBuf>>,
+    ) -> (CliRunResult, FxHashMap<PathBuf, (usize, usize)>) {
+        let handler = match self.create_handler(files) {
+            Ok(handler) => handler,
+            Err(error) => {
+                let result = CliRunResult::InvalidOptions { message: error.to_string() };
+                return (result, FxHashMap::default());
+            }
+        };
+
+        let result = handler.run();
+
+        if self.options.print_execution_times {
+            Self::print_execution_times(handler.linter());
+        }
+
+        (result, handler.take_file_counts())
+    }
+
+    fn create_handler(
+        &self,
+        files: Option<FxHashSet<PathBuf>>,
+    ) -> Result<IsolatedLintHandler, DiagnosticError> {
+        // Configuration files are read again for each run of the watch mode
+        let linter = Arc::new(self.create_linter()?);
+        let changed_files = files.map_or_else(|| self.changed_files(), |files| Ok(Some(files)))?;
+        let baseline = self.baseline()?;
+        Ok(IsolatedLintHandler::new(Arc::clone(&self.options), linter)
+            .with_changed_files(changed_files)
+            .with_baseline(baseline))
+    }
+
     fn print_rules() {
         let mut stdout = BufWriter::new(std::io::stdout());
         Linter::print_rules(&mut stdout);
     }
 
-    fn derive_rules(options: &LintOptions) -> Vec<RuleEnum> {
-        let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
+    /// Create the linter from the rule selection on the command line,
+    /// followed by the configuration file given by `--config` or the discovered ones.
+    /// The rules given by `-A`, `-W` and `-D` take precedence over the configuration files.
+    fn create_linter(&self) -> Result<Linter, DiagnosticError> {
+        let cwd = env::current_dir().unwrap_or_default();
+        let config_store = match &self.options.config {
+            Some(path) => ConfigStore::from_config_file(path, &cwd)?,
+            None => ConfigStore::discover(&cwd)?,
+        };
+        let rules = Self::derive_rules(&self.options);
+        Ok(Linter::from_rules(rules)
+            .with_config_store(Arc::new(config_store))
+            .with_cli_rules(Self::derive_cli_rules(&self.options))
+            .with_fix(
+                self.options.fix || self.options.fix_dry_run || self.options.format.reports_fixes(),
+            )
+            .with_fix_suggestions(self.options.fix_suggestions)
+            .with_report_unused_directives(self.options.report_unused_disable_directives)
+            .with_inline_config(!self.options.no_inline_config)
+            .with_import_plugin(self.options.import_plugin)
+            .with_walk_options(self.options.walk_options())
+            .with_print_execution_times(self.options.print_execution_times))
+    }
 
-        for (allow_warn_deny, name_or_category) in &options.rules {
-            let maybe_category = RuleCategory::from(name_or_category.as_str());
-            match allow_warn_deny {
-                AllowWarnDeny::Deny => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            RULES.iter().filter(|rule| rule.category() == category).cloned(),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(RULES.iter().cloned());
-                            } else {
-                                rules.extend(
-                                    RULES
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .cloned(),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.clear();
-                            } else {
-                                rules.retain(|rule| rule.name() == name_or_category);
-                            }
-                        }
-                    };
+    /// Files changed according to git for `--changed-since` and `--staged`, `None` to lint all files.
+    fn changed_files(&self) -> Result<Option<FxHashSet<PathBuf>>, DiagnosticError> {
+        if let Some(revision) = &self.options.changed_since {
+            return Git::discover(&self.options.paths)?.changed_since(revision).map(Some);
+        }
+        if self.options.staged {
+            return Git::discover(&self.options.paths)?.staged().map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Lint the source text from stdin with the path of `--stdin-filename`.
+    fn lint_stdin(&self) -> CliRunResult {
+        let mut source_text = String::new();
+        if let Err(error) = io::stdin().read_to_string(&mut source_text) {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to read stdin: {error}"),
+            };
+        }
+
+        let linter = match self.create_linter() {
+            Ok(linter) => Arc::new(linter),
+            Err(error) => return CliRunResult::InvalidOptions { message: error.to_string() },
+        };
+
+        let baseline = match self.baseline() {
+            Ok(baseline) => baseline,
+            Err(error) => return CliRunResult::InvalidOptions { message: error.to_string() },
+        };
+
+        // Without a file name the source text is linted as JavaScript
+        let path =
+            self.options.stdin_filename.clone().unwrap_or_else(|| PathBuf::from("<stdin>.js"));
+        IsolatedLintHandler::new(Arc::clone(&self.options), linter)
+            .with_baseline(baseline)
+            .run_stdin(&path, &source_text)
+    }
+
+    fn baseline(&self) -> Result<Option<Baseline>, DiagnosticError> {
+        if self.options.write_baseline.is_some() {
+            return Ok(Some(Baseline::new_for_write()));
+        }
+        self.options.baseline.as_deref().map(Baseline::load).transpose()
+    }
+
+    /// The rules selected by `-A`, `-W` and `-D` in order, `-D correctness` when none are given.
+    fn derive_rules(options: &LintOptions) -> Vec<RuleWithSeverity> {
+        let mut rules: FxHashMap<&'static str, RuleWithSeverity> = FxHashMap::default();
+
+        let default_rules = [(AllowWarnDeny::Deny, "correctness".to_string())];
+        let selection = if options.rules.is_empty() { &default_rules[..] } else { &options.rules };
+        for (allow_warn_deny, name_or_category) in selection {
+            let is_selected = |rule: &RuleEnum| Self::is_selected(name_or_category, rule);
+            if allow_warn_deny.is_enabled() {
+                // A later `-W` or `-D` overrides the severity of an earlier one.
+                for rule in RULES.iter().filter(|rule| is_selected(rule)) {
+                    rules
+                        .insert(rule.name(), RuleWithSeverity::new(rule.clone(), *allow_warn_deny));
                 }
+            } else {
+                rules.retain(|_, rule| !is_selected(&rule.rule));
             }
         }
 
-        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        let mut rules = rules.into_values().collect::<Vec<_>>();
         // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(|rule| rule.name());
+        rules.sort_unstable_by_key(|rule| rule.rule.name());
+        rules
+    }
+
+    /// The rules turned on or off by `-A`, `-W` and `-D`, the last one selecting a rule wins.
+    /// Rules which are not selected are left to the configuration files.
+    fn derive_cli_rules(options: &LintOptions) -> Vec<ESLintRuleConfig> {
+        let mut rules: FxHashMap<(&'static str, &'static str), AllowWarnDeny> =
+            FxHashMap::default();
+        for (allow_warn_deny, name_or_category) in &options.rules {
+            for rule in RULES.iter().filter(|rule| Self::is_selected(name_or_category, rule)) {
+                rules.insert((rule.plugin_name(), rule.name()), *allow_warn_deny);
+            }
+        }
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|((plugin_name, name), _)| (*name, *plugin_name));
         rules
+            .into_iter()
+            .map(|((plugin_name, name), severity)| {
+                ESLintRuleConfig::new(plugin_name, name, severity)
+            })
+            .collect()
+    }
+
+    /// Whether `rule` is selected by a rule name, also its ESLint name, a category or `all`.
+    fn is_selected(name_or_category: &str, rule: &RuleEnum) -> bool {
+        RuleCategory::from(name_or_category).map_or_else(
+            || name_or_category == "all" || rule.name() == rule_name_of_alias(name_or_category),
+            |category| rule.category() == category,
+        )
     }
 
-    fn print_execution_times(&self) {
-        let mut timings = self
-            .linter
+    fn print_execution_times(linter: &Linter) {
+        let mut timings = linter
             .rules()
             .iter()
-            .map(|rule| (rule.name(), rule.execute_time()))
+            .map(|RuleWithSeverity { rule, .. }| (rule.name(), rule.execute_time()))
             .collect::<Vec<_>>();
 
         timings.sort_by_key(|x| x.1);
@@ -1,8 +1,10 @@
 use std::{collections::BTreeMap, env, path::PathBuf};
 
 use clap::ArgMatches;
+pub use oxc_linter::AllowWarnDeny;
+use oxc_linter::WalkOptions;
 
-use super::command::lint_command;
+use super::{command::lint_command, reporter::OutputFormat};
 pub use super::{error::Error, isolated_handler::IsolatedLintHandler};
 use crate::runner::RunnerOptions;
 
@@ -10,17 +12,48 @@ use crate::runner::RunnerOptions;
 #[allow(clippy::struct_excessive_bools)]
 pub struct LintOptions {
     pub paths: Vec<PathBuf>,
-    /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
-    /// Defaults to [("deny", "correctness")]
+    /// Allow / Warn / Deny rules in order. [("allow" / "warn" / "deny", rule name)]
+    /// Empty when none are given, the rules then default to `-D correctness`
     pub rules: Vec<(AllowWarnDeny, String)>,
     pub list_rules: bool,
+    /// Path to a configuration file, disables discovery of configuration files.
+    pub config: Option<PathBuf>,
     pub fix: bool,
+    /// Also apply fixes which may change the behavior of the code
+    pub fix_suggestions: bool,
+    /// Print the fixes as a diff instead of writing them
+    pub fix_dry_run: bool,
+    pub format: OutputFormat,
+    /// Report `eslint-disable` directives which did not suppress any diagnostic
+    pub report_unused_disable_directives: bool,
+    /// Ignore configuration comments and `eslint-disable` directives
+    pub no_inline_config: bool,
+    /// Resolve imports into a module graph for the rules of the import plugin
+    pub import_plugin: bool,
     pub quiet: bool,
     pub ignore_path: PathBuf,
     pub no_ignore: bool,
     pub ignore_pattern: Vec<String>,
     pub max_warnings: Option<usize>,
     pub print_execution_times: bool,
+    /// Only lint files which changed since the last run
+    pub cache: bool,
+    /// File or directory of the cache, defaults to `.oxlintcache`
+    pub cache_location: Option<PathBuf>,
+    /// Only lint files changed since this git revision
+    pub changed_since: Option<String>,
+    /// Only lint files staged in git
+    pub staged: bool,
+    /// Suppress the violations recorded in this file
+    pub baseline: Option<PathBuf>,
+    /// Record the current violations in this file
+    pub write_baseline: Option<PathBuf>,
+    /// Lint files again when they change
+    pub watch: bool,
+    /// Lint the source text from stdin instead of files
+    pub stdin: bool,
+    /// Path of the source text from stdin, for its source type and configuration
+    pub stdin_filename: Option<PathBuf>,
 }
 
 impl Default for LintOptions {
@@ -30,26 +63,11 @@ impl Default for LintOptions {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum AllowWarnDeny {
-    Allow,
-    // Warn,
-    Deny,
-}
-
-impl From<&'static str> for AllowWarnDeny {
-    fn from(s: &'static str) -> Self {
-        match s {
-            "allow" => Self::Allow,
-            "deny" => Self::Deny,
-            _ => unreachable!(),
-        }
-    }
-}
-
 impl<'a> From<&'a ArgMatches> for LintOptions {
     fn from(matches: &'a ArgMatches) -> Self {
         let list_rules = matches.get_flag("rules");
+        let fix_suggestions = matches.get_flag("fix-suggestions");
+        let fix_dry_run = matches.get_flag("fix-dry-run");
 
         Self {
             paths: matches.get_many("path").map_or_else(
@@ -57,7 +75,18 @@ impl<'a> From<&'a ArgMatches> for LintOptions {
                 |paths| paths.into_iter().cloned().collect(),
             ),
             rules: Self::get_rules(matches),
-            fix: matches.get_flag("fix"),
+            config: matches.get_one::<PathBuf>("config").cloned(),
+            // `--fix-suggestions` implies `--fix`, unless the fixes are only printed
+            fix: matches.get_flag("fix") || (fix_suggestions && !fix_dry_run),
+            fix_suggestions,
+            fix_dry_run,
+            format: matches
+                .get_one::<String>("format")
+                .map(|format| OutputFormat::try_from(format.as_str()).unwrap())
+                .unwrap_or_default(),
+            report_unused_disable_directives: matches.get_flag("report-unused-disable-directives"),
+            no_inline_config: matches.get_flag("no-inline-config"),
+            import_plugin: matches.get_flag("import-plugin"),
             quiet: matches.get_flag("quiet"),
             ignore_path: matches
                 .get_one::<PathBuf>("ignore-path")
@@ -70,18 +99,48 @@ impl<'a> From<&'a ArgMatches> for LintOptions {
             max_warnings: matches.get_one("max-warnings").copied(),
             list_rules,
             print_execution_times: matches!(env::var("TIMING"), Ok(x) if x == "true" || x == "1"),
+            cache: matches.get_flag("cache"),
+            cache_location: matches.get_one::<PathBuf>("cache-location").cloned(),
+            changed_since: matches.get_one::<String>("changed-since").cloned(),
+            staged: matches.get_flag("staged"),
+            baseline: matches.get_one::<PathBuf>("baseline").cloned(),
+            write_baseline: matches.get_one::<PathBuf>("write-baseline").cloned(),
+            watch: matches.get_flag("watch"),
+            stdin: matches.get_flag("stdin"),
+            stdin_filename: matches.get_one::<PathBuf>("stdin-filename").cloned(),
         }
     }
 }
 
 impl LintOptions {
+    pub fn walk_options(&self) -> WalkOptions {
+        WalkOptions {
+            paths: self.paths.clone(),
+            ignore_path: self.ignore_path.clone(),
+            no_ignore: self.no_ignore,
+            ignore_pattern: self.ignore_pattern.clone(),
+        }
+    }
+
+    /// Warnings are not counted with `--quiet`
+    pub fn reported_warnings(&self, number_of_warnings: usize) -> usize {
+        if self.quiet {
+            0
+        } else {
+            number_of_warnings
+        }
+    }
+
+    pub fn max_warnings_exceeded(&self, number_of_warnings: usize) -> bool {
+        self.max_warnings.map_or(false, |max_warnings| number_of_warnings > max_warnings)
+    }
+
     /// Get all rules in order, e.g.
-    /// `-A all -D no-var -D -eqeqeq` => [("allow", "all"), ("deny", "no-var"), ("deny", "eqeqeq")]
-    /// Defaults to [("deny", "correctness")];
+    /// `-A all -D no-var -W eqeqeq` => [("allow", "all"), ("deny", "no-var"), ("warn", "eqeqeq")]
     fn get_rules(matches: &ArgMatches) -> Vec<(AllowWarnDeny, String)> {
         let mut map: BTreeMap<usize, (AllowWarnDeny, String)> = BTreeMap::new();
-        for key in ["allow", "deny"] {
-            let allow_warn_deny = AllowWarnDeny::from(key);
+        for key in ["allow", "warn", "deny"] {
+            let allow_warn_deny = AllowWarnDeny::try_from(key).unwrap();
             if let Some(values) = matches.get_many::<String>(key) {
                 let indices = matches.indices_of(key).unwrap();
                 let zipped =
@@ -89,11 +148,7 @@ impl LintOptions {
                 map.extend(zipped);
             }
         }
-        if map.is_empty() {
-            vec![(AllowWarnDeny::Deny, "correctness".into())]
-        } else {
-            map.into_values().collect()
-        }
+        map.into_values().collect()
     }
 }
 
@@ -110,7 +165,7 @@ mod test {
 
     use clap::Command;
 
-    use super::{AllowWarnDeny, LintOptions};
+    use super::{AllowWarnDeny, LintOptions, OutputFormat};
     use crate::runner::RunnerOptions;
 
     #[test]
@@ -130,11 +185,35 @@ mod test {
         let options = get_lint_options("lint .");
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix);
+        assert!(!options.fix_suggestions);
+        assert!(!options.fix_dry_run);
+        assert_eq!(options.format, OutputFormat::Default);
+        assert!(!options.report_unused_disable_directives);
+        assert!(!options.no_inline_config);
+        assert!(!options.import_plugin);
         assert!(!options.quiet);
         assert_eq!(options.ignore_path, PathBuf::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
         assert_eq!(options.max_warnings, None);
+        assert_eq!(options.config, None);
+        assert!(!options.cache);
+        assert_eq!(options.cache_location, None);
+        assert_eq!(options.changed_since, None);
+        assert!(!options.staged);
+        assert_eq!(options.baseline, None);
+        assert_eq!(options.write_baseline, None);
+        assert!(!options.watch);
+        assert!(!options.stdin);
+        assert_eq!(options.stdin_filename, None);
+    }
+
+    #[test]
+    fn config() {
+        let options = get_lint_options("lint --config .oxlintrc.json foo.js");
+        assert_eq!(options.config, Some(PathBuf::from(".oxlintrc.json")));
+        let options = get_lint_options("lint -c .eslintrc.json foo.js");
+        assert_eq!(options.config, Some(PathBuf::from(".eslintrc.json")));
     }
 
     #[test]
@@ -162,6 +241,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn rules_with_warn() {
+        let options = get_lint_options("lint src -D correctness -W no-debugger --warn suspicious");
+        assert_eq!(
+            options.rules,
+            vec![
+                (AllowWarnDeny::Deny, "correctness".into()),
+                (AllowWarnDeny::Warn, "no-debugger".into()),
+                (AllowWarnDeny::Warn, "suspicious".into())
+            ]
+        );
+    }
+
     #[test]
     fn quiet_true() {
         let options = get_lint_options("lint foo.js --quiet");
@@ -174,6 +266,98 @@ mod test {
         assert!(options.fix);
     }
 
+    #[test]
+    fn fix_suggestions_implies_fix() {
+        let options = get_lint_options("lint foo.js --fix-suggestions");
+        assert!(options.fix);
+        assert!(options.fix_suggestions);
+        assert!(!options.fix_dry_run);
+    }
+
+    #[test]
+    fn fix_suggestions_and_dry_run() {
+        let options = get_lint_options("lint foo.js --fix-suggestions --fix-dry-run");
+        assert!(!options.fix);
+        assert!(options.fix_suggestions);
+        assert!(options.fix_dry_run);
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_lint_options("lint foo.js --report-unused-disable-directives");
+        assert!(options.report_unused_disable_directives);
+    }
+
+    #[test]
+    fn no_inline_config() {
+        let options = get_lint_options("lint foo.js --no-inline-config");
+        assert!(options.no_inline_config);
+    }
+
+    #[test]
+    fn import_plugin() {
+        let options = get_lint_options("lint foo.js --import-plugin");
+        assert!(options.import_plugin);
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_lint_options("lint foo.js --cache --cache-location tmp/cache");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from("tmp/cache")));
+    }
+
+    #[test]
+    fn changed_files() {
+        let options = get_lint_options("lint --changed-since main");
+        assert_eq!(options.changed_since, Some("main".to_string()));
+        let options = get_lint_options("lint --staged src");
+        assert!(options.staged);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+        assert!(LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--staged", "--changed-since", "main"])
+            .is_err());
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_lint_options("lint --baseline baseline.json src");
+        assert_eq!(options.baseline, Some(PathBuf::from("baseline.json")));
+        let options = get_lint_options("lint --write-baseline baseline.json src");
+        assert_eq!(options.write_baseline, Some(PathBuf::from("baseline.json")));
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_lint_options("lint --watch src");
+        assert!(options.watch);
+    }
+
+    #[test]
+    fn stdin() {
+        let options = get_lint_options("lint --stdin --stdin-filename src/foo.tsx");
+        assert!(options.stdin);
+        assert_eq!(options.stdin_filename, Some(PathBuf::from("src/foo.tsx")));
+        assert!(LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--stdin-filename", "foo.js", "src"])
+            .is_err());
+    }
+
+    #[test]
+    fn format() {
+        let options = get_lint_options("lint --format json foo.js");
+        assert_eq!(options.format, OutputFormat::Json);
+        let options = get_lint_options("lint -f sarif foo.js");
+        assert_eq!(options.format, OutputFormat::Sarif);
+    }
+
+    #[test]
+    fn unknown_format() {
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--format", "xml", "foo.js"]);
+        assert!(matches.is_err());
+    }
+
     #[test]
     fn max_warnings() {
         let options = get_lint_options("lint --max-warnings 10 foo.js");
@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use super::{severity_name, xml_escape, FileDiagnostics};
+
+/// The output of ESLint's `checkstyle` formatter
+pub fn write<W: Write>(writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+    write!(writer, r#"<?xml version="1.0" encoding="utf-8"?><checkstyle version="4.3">"#)?;
+    for file in files {
+        write!(writer, r#"<file name="{}">"#, xml_escape(&file.path.to_string_lossy()))?;
+        for diagnostic in &file.diagnostics {
+            let (message, source) = diagnostic.rule_id.as_ref().map_or_else(
+                || (diagnostic.message.clone(), String::new()),
+                |rule_id| {
+                    (
+                        format!("{} ({rule_id})", diagnostic.message),
+                        format!("eslint.rules.{rule_id}"),
+                    )
+                },
+            );
+            write!(
+                writer,
+                r#"<error line="{}" column="{}" severity="{}" message="{}" source="{}" />"#,
+                diagnostic.start.line,
+                diagnostic.start.column,
+                severity_name(diagnostic.severity),
+                xml_escape(&message),
+                xml_escape(&source),
+            )?;
+        }
+        write!(writer, "</file>")?;
+    }
+    writeln!(writer, "</checkstyle>")
+}
@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use super::{severity_name, FileDiagnostics};
+
+/// GitHub Actions workflow commands, which are shown as annotations in pull requests.
+/// See <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions>
+pub fn write<W: Write>(writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+    for file in files {
+        let path = escape_property(&file.path.to_string_lossy());
+        for diagnostic in &file.diagnostics {
+            writeln!(
+                writer,
+                "::{} file={path},line={},endLine={},col={},endColumn={},title={}::{}",
+                severity_name(diagnostic.severity),
+                diagnostic.start.line,
+                diagnostic.end.line,
+                diagnostic.start.column,
+                diagnostic.end.column,
+                escape_property(diagnostic.rule_id.as_deref().unwrap_or("oxlint")),
+                escape_data(&diagnostic.message),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
@@ -0,0 +1,78 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+use super::{FileDiagnostics, ReportedDiagnostic};
+
+/// The output of ESLint's `json` formatter
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResult<'a> {
+    file_path: String,
+    messages: Vec<JsonMessage<'a>>,
+    error_count: usize,
+    warning_count: usize,
+    fixable_error_count: usize,
+    fixable_warning_count: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMessage<'a> {
+    rule_id: Option<&'a str>,
+    /// Parse and semantic errors are fatal
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    fatal: bool,
+    /// 1 for warnings, 2 for errors
+    severity: u8,
+    message: &'a str,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<JsonFix<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFix<'a> {
+    range: (usize, usize),
+    text: &'a str,
+}
+
+pub fn write<W: Write>(writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+    let results = files.iter().map(json_result).collect::<Vec<_>>();
+    serde_json::to_writer(&mut *writer, &results)?;
+    writeln!(writer)
+}
+
+fn json_result(file: &FileDiagnostics) -> JsonResult {
+    let count = |warning: bool, fixable: bool| {
+        file.diagnostics
+            .iter()
+            .filter(|d| d.is_warning() == warning && (!fixable || d.fix.is_some()))
+            .count()
+    };
+    JsonResult {
+        file_path: file.path.to_string_lossy().to_string(),
+        messages: file.diagnostics.iter().map(json_message).collect(),
+        error_count: count(false, false),
+        warning_count: count(true, false),
+        fixable_error_count: count(false, true),
+        fixable_warning_count: count(true, true),
+    }
+}
+
+fn json_message(diagnostic: &ReportedDiagnostic) -> JsonMessage {
+    JsonMessage {
+        rule_id: diagnostic.rule_id.as_deref(),
+        fatal: diagnostic.rule_id.is_none(),
+        severity: if diagnostic.is_warning() { 1 } else { 2 },
+        message: &diagnostic.message,
+        line: diagnostic.start.line,
+        column: diagnostic.start.column,
+        end_line: diagnostic.end.line,
+        end_column: diagnostic.end.column,
+        fix: diagnostic.fix.as_ref().map(|fix| JsonFix { range: fix.range, text: &fix.text }),
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::Write;
+
+use super::{xml_escape, FileDiagnostics};
+
+/// The output of ESLint's `junit` formatter
+pub fn write<W: Write>(writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+    writeln!(writer, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(writer, "<testsuites>")?;
+    for file in files {
+        let path = file.path.to_string_lossy();
+        // The class name is the path without its extension
+        let classname = file.path.with_extension("");
+        let classname = xml_escape(&classname.to_string_lossy());
+        let count = file.diagnostics.len();
+        writeln!(
+            writer,
+            r#"<testsuite package="org.eslint" time="0" tests="{count}" errors="{count}" name="{}">"#,
+            xml_escape(&path)
+        )?;
+        for diagnostic in &file.diagnostics {
+            let rule_id = diagnostic.rule_id.as_deref().unwrap_or("unknown");
+            let severity = if diagnostic.is_warning() { "Warning" } else { "Error" };
+            let rule_suffix =
+                diagnostic.rule_id.as_ref().map_or_else(String::new, |id| format!(" ({id})"));
+            // `]]>` would end the CDATA section
+            let details = format!(
+                "line {}, col {}, {severity} - {}{rule_suffix}",
+                diagnostic.start.line, diagnostic.start.column, diagnostic.message
+            )
+            .replace("]]>", "]]]]><![CDATA[>");
+            writeln!(
+                writer,
+                r#"<testcase time="0" name="org.eslint.{}" classname="{classname}"><failure message="{}"><![CDATA[{details}]]></failure></testcase>"#,
+                xml_escape(rule_id),
+                xml_escape(&diagnostic.message),
+            )?;
+        }
+        writeln!(writer, "</testsuite>")?;
+    }
+    writeln!(writer, "</testsuites>")
+}
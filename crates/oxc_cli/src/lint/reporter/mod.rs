@@ -0,0 +1,346 @@
+//! Output formats of `oxlint --format`
+
+mod checkstyle;
+mod github;
+mod json;
+mod junit;
+mod sarif;
+
+use std::{io::Write, path::PathBuf};
+
+use oxc_diagnostics::{miette, Error, Severity};
+use oxc_linter::{Fix, LineIndex, Location, RuleInfo};
+
+/// The output format selected by `--format`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Graphical report for the terminal
+    #[default]
+    Default,
+    /// ESLint compatible JSON
+    Json,
+    Checkstyle,
+    Junit,
+    Sarif,
+    /// GitHub Actions workflow commands
+    Github,
+}
+
+impl OutputFormat {
+    pub const NAMES: [&'static str; 6] =
+        ["default", "json", "checkstyle", "junit", "sarif", "github"];
+
+    /// Formats other than the default one are parsed by machines,
+    /// so nothing else is written to stdout.
+    pub fn is_machine_readable(self) -> bool {
+        self != Self::Default
+    }
+
+    /// Formats which report the fix of each diagnostic, like the `json` formatter of ESLint.
+    /// The fixes are collected without applying them unless they are written by `--fix`.
+    pub fn reports_fixes(self) -> bool {
+        self == Self::Json
+    }
+
+    /// Sort the files by path and the diagnostics of each file by position, like ESLint,
+    /// so every format reports them in the same stable order.
+    pub fn sort(files: &mut [FileDiagnostics]) {
+        files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        for file in files {
+            file.diagnostics.sort_by_key(|diagnostic| diagnostic.start);
+        }
+    }
+
+    /// Write the diagnostics of all files in one go.
+    /// The graphical report is written per file by [`super::IsolatedLintHandler`].
+    pub fn write<W: Write>(self, writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+        match self {
+            Self::Default => Ok(()),
+            Self::Json => json::write(writer, files),
+            Self::Checkstyle => checkstyle::write(writer, files),
+            Self::Junit => junit::write(writer, files),
+            Self::Sarif => sarif::write(writer, files),
+            Self::Github => github::write(writer, files),
+        }
+    }
+}
+
+impl TryFrom<&str> for OutputFormat {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "junit" => Ok(Self::Junit),
+            "sarif" => Ok(Self::Sarif),
+            "github" => Ok(Self::Github),
+            _ => Err(format!("Unknown output format {s:?}")),
+        }
+    }
+}
+
+/// All diagnostics of a file.
+pub struct FileDiagnostics {
+    pub path: PathBuf,
+    pub diagnostics: Vec<ReportedDiagnostic>,
+    /// Unified diff of the fixes not written by `--fix-dry-run`
+    pub fix_diff: Option<String>,
+}
+
+/// A diagnostic resolved against the source text of its file.
+pub struct ReportedDiagnostic {
+    /// The diagnostic with the source code attached, for the graphical report
+    pub error: Error,
+    pub severity: Severity,
+    /// `None` for parse and semantic errors
+    pub rule: Option<RuleInfo>,
+    /// ESLint style rule id, see [`RuleInfo::rule_id`]
+    pub rule_id: Option<String>,
+    /// The message without the `eslint(no-debugger): ` prefix
+    pub message: String,
+    pub start: Location,
+    pub end: Location,
+    pub fix: Option<ReportedFix>,
+}
+
+pub struct ReportedFix {
+    /// UTF-16 code unit offsets into the source text, like ESLint
+    pub range: (usize, usize),
+    pub text: String,
+}
+
+impl ReportedDiagnostic {
+    pub fn new(
+        error: Error,
+        fix: Option<&Fix>,
+        rule: Option<RuleInfo>,
+        line_index: &LineIndex,
+    ) -> Self {
+        let labels = error.labels().map_or(vec![], Iterator::collect);
+        let start = labels.iter().map(miette::LabeledSpan::offset).min().unwrap_or(0);
+        let end = labels.iter().map(|span| span.offset() + span.len()).max().unwrap_or(0);
+        let message = error.to_string();
+        let message = rule
+            .and_then(|rule| message.strip_prefix(&format!("{rule}: ")).map(ToString::to_string))
+            .unwrap_or(message);
+        Self {
+            severity: error.severity().unwrap_or(Severity::Error),
+            rule,
+            rule_id: rule.map(|rule| rule.rule_id()),
+            message,
+            start: line_index.location(start),
+            end: line_index.location(end),
+            fix: fix.map(|fix| ReportedFix {
+                range: (
+                    line_index.utf16_offset(fix.span.start as usize),
+                    line_index.utf16_offset(fix.span.end as usize),
+                ),
+                text: fix.content.to_string(),
+            }),
+            error,
+        }
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.severity != Severity::Error
+    }
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    if severity == Severity::Error {
+        "error"
+    } else {
+        "warning"
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        miette::{self, Diagnostic},
+        thiserror::Error,
+        Severity,
+    };
+    use oxc_linter::{Fix, RuleCategory, RuleInfo};
+    use oxc_span::Span;
+
+    use super::{FileDiagnostics, LineIndex, Location, OutputFormat, ReportedDiagnostic};
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("eslint(no-debugger): `debugger` statement is not allowed")]
+    #[diagnostic(severity(warning))]
+    struct NoDebugger(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Unexpected token")]
+    struct ParseError(#[label] Span);
+
+    fn files() -> Vec<FileDiagnostics> {
+        let source_text = "let a = 1;\ndebugger;\nlet <";
+        let line_index = LineIndex::new(source_text);
+        let diagnostics = vec![
+            ReportedDiagnostic::new(
+                NoDebugger(Span::new(11, 20)).into(),
+                None,
+                Some(RuleInfo {
+                    plugin: "eslint",
+                    name: "no-debugger",
+                    category: RuleCategory::Correctness,
+                    documentation: Some("docs"),
+                    source_path: "crates/oxc_linter/src/rules/eslint/no_debugger.rs",
+                }),
+                &line_index,
+            ),
+            ReportedDiagnostic::new(ParseError(Span::new(25, 26)).into(), None, None, &line_index),
+        ];
+        vec![FileDiagnostics { path: "src/a.js".into(), diagnostics, fix_diff: None }]
+    }
+
+    #[test]
+    fn sort() {
+        let source_text = "debugger;\ndebugger; debugger;";
+        let line_index = LineIndex::new(source_text);
+        let diagnostic = |start| {
+            ReportedDiagnostic::new(
+                NoDebugger(Span::new(start, start + 9)).into(),
+                None,
+                None,
+                &line_index,
+            )
+        };
+        let mut files = vec![
+            FileDiagnostics {
+                path: "src/b.js".into(),
+                diagnostics: vec![diagnostic(20), diagnostic(0), diagnostic(10)],
+                fix_diff: None,
+            },
+            FileDiagnostics { path: "src/a.js".into(), diagnostics: vec![], fix_diff: None },
+        ];
+        OutputFormat::sort(&mut files);
+        assert_eq!(files[0].path.to_str(), Some("src/a.js"));
+        let starts = files[1].diagnostics.iter().map(|d| d.start).collect::<Vec<_>>();
+        assert_eq!(
+            starts,
+            [
+                Location { line: 1, column: 1 },
+                Location { line: 2, column: 1 },
+                Location { line: 2, column: 11 }
+            ]
+        );
+    }
+
+    fn output(format: OutputFormat) -> String {
+        let mut buf = vec![];
+        format.write(&mut buf, &files()).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn reported_diagnostic() {
+        let files = files();
+        let diagnostic = &files[0].diagnostics[0];
+        assert_eq!(diagnostic.rule_id.as_deref(), Some("no-debugger"));
+        assert_eq!(diagnostic.message, "`debugger` statement is not allowed");
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.start, Location { line: 2, column: 1 });
+        assert_eq!(diagnostic.end, Location { line: 2, column: 10 });
+        let diagnostic = &files[0].diagnostics[1];
+        assert_eq!(diagnostic.rule_id, None);
+        assert_eq!(diagnostic.severity, Severity::Error);
+    }
+
+    #[test]
+    fn json() {
+        let expected = r#"[{"filePath":"src/a.js","messages":[{"ruleId":"no-debugger","severity":1,"message":"`debugger` statement is not allowed","line":2,"column":1,"endLine":2,"endColumn":10},{"ruleId":null,"fatal":true,"severity":2,"message":"Unexpected token","line":3,"column":5,"endLine":3,"endColumn":6}],"errorCount":1,"warningCount":1,"fixableErrorCount":0,"fixableWarningCount":0}]"#;
+        assert_eq!(output(OutputFormat::Json), format!("{expected}\n"));
+    }
+
+    #[test]
+    fn json_fix_range() {
+        // The emoji is two UTF-16 code units and four bytes
+        let source_text = "let a = '😀'; debugger;";
+        let line_index = LineIndex::new(source_text);
+        let fix = Fix::new("", Span::new(16, 25));
+        let diagnostic = ReportedDiagnostic::new(
+            NoDebugger(Span::new(16, 25)).into(),
+            Some(&fix),
+            None,
+            &line_index,
+        );
+        let files = vec![FileDiagnostics {
+            path: "src/a.js".into(),
+            diagnostics: vec![diagnostic],
+            fix_diff: None,
+        }];
+        let mut buf = vec![];
+        OutputFormat::Json.write(&mut buf, &files).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        let fix = &output[0]["messages"][0]["fix"];
+        assert_eq!(fix["range"], serde_json::json!([14, 23]));
+        assert_eq!(fix["text"], "");
+    }
+
+    #[test]
+    fn checkstyle() {
+        let expected = r#"<?xml version="1.0" encoding="utf-8"?><checkstyle version="4.3"><file name="src/a.js"><error line="2" column="1" severity="warning" message="`debugger` statement is not allowed (no-debugger)" source="eslint.rules.no-debugger" /><error line="3" column="5" severity="error" message="Unexpected token" source="" /></file></checkstyle>"#;
+        assert_eq!(output(OutputFormat::Checkstyle), format!("{expected}\n"));
+    }
+
+    #[test]
+    fn junit() {
+        let expected = r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites>
+<testsuite package="org.eslint" time="0" tests="2" errors="2" name="src/a.js">
+<testcase time="0" name="org.eslint.no-debugger" classname="src/a"><failure message="`debugger` statement is not allowed"><![CDATA[line 2, col 1, Warning - `debugger` statement is not allowed (no-debugger)]]></failure></testcase>
+<testcase time="0" name="org.eslint.unknown" classname="src/a"><failure message="Unexpected token"><![CDATA[line 3, col 5, Error - Unexpected token]]></failure></testcase>
+</testsuite>
+</testsuites>
+"#;
+        assert_eq!(output(OutputFormat::Junit), expected);
+    }
+
+    #[test]
+    fn sarif() {
+        let output: serde_json::Value = serde_json::from_str(&output(OutputFormat::Sarif)).unwrap();
+        assert_eq!(output["version"], "2.1.0");
+        let run = &output["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "oxlint");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "no-debugger");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["properties"]["category"], "Correctness");
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "no-debugger");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/a.js");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["endColumn"], 10);
+        assert_eq!(run["results"][1]["level"], "error");
+        assert!(run["results"][1].get("ruleId").is_none());
+    }
+
+    #[test]
+    fn github() {
+        let expected = "::warning file=src/a.js,line=2,endLine=2,col=1,endColumn=10,title=no-debugger::`debugger` statement is not allowed
+::error file=src/a.js,line=3,endLine=3,col=5,endColumn=6,title=oxlint::Unexpected token
+";
+        assert_eq!(output(OutputFormat::Github), expected);
+    }
+}
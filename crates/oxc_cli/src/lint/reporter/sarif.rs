@@ -0,0 +1,151 @@
+use std::io::Write;
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use super::{severity_name, FileDiagnostics};
+
+/// Static Analysis Results Interchange Format 2.1.0, only the parts needed for reporting lint results.
+/// See <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+#[derive(Serialize)]
+struct Sarif<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Run<'a> {
+    tool: Tool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor<'a> {
+    id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    category: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: Message<'a>,
+    locations: [Location; 1],
+}
+
+#[derive(Serialize)]
+struct Message<'a> {
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+pub fn write<W: Write>(writer: &mut W, files: &[FileDiagnostics]) -> std::io::Result<()> {
+    let mut rules = vec![];
+    let mut rule_indices: FxHashMap<&str, usize> = FxHashMap::default();
+    let mut results = vec![];
+
+    for file in files {
+        // SARIF uses URIs with forward slashes
+        let uri = file.path.to_string_lossy().replace('\\', "/");
+        for diagnostic in &file.diagnostics {
+            let rule_index =
+                diagnostic.rule.zip(diagnostic.rule_id.as_deref()).map(|(rule, id)| {
+                    *rule_indices.entry(id).or_insert_with(|| {
+                        rules.push(ReportingDescriptor {
+                            id,
+                            help_uri: rule.documentation_url(),
+                            properties: RuleProperties { category: rule.category.to_string() },
+                        });
+                        rules.len() - 1
+                    })
+                });
+            results.push(SarifResult {
+                rule_id: diagnostic.rule_id.as_deref(),
+                rule_index,
+                level: severity_name(diagnostic.severity),
+                message: Message { text: &diagnostic.message },
+                locations: [Location {
+                    physical_location: PhysicalLocation {
+                        artifact_location: ArtifactLocation { uri: uri.clone() },
+                        region: Region {
+                            start_line: diagnostic.start.line,
+                            start_column: diagnostic.start.column,
+                            end_line: diagnostic.end.line,
+                            end_column: diagnostic.end.column,
+                        },
+                    },
+                }],
+            });
+        }
+    }
+
+    let sarif = Sarif {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "oxlint",
+                    information_uri: "https://github.com/web-infra-dev/oxc",
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+    serde_json::to_writer_pretty(&mut *writer, &sarif)?;
+    writeln!(writer)
+}
@@ -0,0 +1,236 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Termination,
+    sync::mpsc,
+    time::Duration,
+};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use oxc_linter::has_lintable_extension;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::LintRunner;
+use crate::CliRunResult;
+
+/// File system events within this duration are handled together, e.g. saving many files at once.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Files which change the result of all files when they change
+const CONFIG_FILES: [&str; 2] = [".oxlintrc.json", ".eslintrc.json"];
+
+/// What to lint after a batch of file system events.
+#[derive(Debug, PartialEq, Eq)]
+enum Rerun {
+    None,
+    /// A configuration or ignore file changed, or a file changed with the import plugin enabled
+    All,
+    Files(FxHashSet<PathBuf>),
+}
+
+/// The diagnostics of the whole tree, the files linted again replace their previous counts.
+#[derive(Debug, Default)]
+struct Summary {
+    /// The number of files of the last run of all files
+    number_of_files: usize,
+    /// Warnings and errors by the canonical path of each file with diagnostics
+    file_counts: FxHashMap<PathBuf, (usize, usize)>,
+}
+
+impl Summary {
+    /// `files` are the linted files of an incremental run, `None` when all files were linted.
+    fn update(
+        &mut self,
+        files: Option<&FxHashSet<PathBuf>>,
+        file_counts: FxHashMap<PathBuf, (usize, usize)>,
+    ) {
+        match files {
+            None => self.file_counts.clear(),
+            Some(files) => self.file_counts.retain(|path, _| !files.contains(path)),
+        }
+        self.file_counts.extend(
+            file_counts
+                .into_iter()
+                .map(|(path, counts)| (path.canonicalize().unwrap_or(path), counts)),
+        );
+    }
+
+    /// The number of warnings and errors of all files
+    fn totals(&self) -> (usize, usize) {
+        self.file_counts
+            .values()
+            .fold((0, 0), |(warnings, errors), counts| (warnings + counts.0, errors + counts.1))
+    }
+}
+
+impl LintRunner {
+    /// Lint all files, then lint the files which change until the process is stopped.
+    /// Only the changed files are linted again, unless a configuration file changes
+    /// or the import plugin is enabled, whose rules depend on the other files.
+    pub(super) fn watch(&self) -> CliRunResult {
+        // The working directory does not change, `--config` is compared with the changed files
+        let config = self.options.config.as_ref().and_then(|config| config.canonicalize().ok());
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(watcher) => watcher,
+            Err(error) => return CliRunResult::InvalidOptions { message: error.to_string() },
+        };
+        for path in &self.options.paths {
+            if let Err(error) = watcher.watch(path, RecursiveMode::Recursive) {
+                return CliRunResult::InvalidOptions { message: error.to_string() };
+            }
+        }
+
+        let mut summary = Summary::default();
+        self.lint_and_report(&mut summary, None);
+        while let Ok(event) = rx.recv() {
+            let mut events = vec![event];
+            while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+                events.push(event);
+            }
+            let paths = events
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|event| matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)))
+                .flat_map(|event| event.paths);
+            match self.rerun(paths, config.as_deref()) {
+                Rerun::None => {}
+                Rerun::All => self.lint_and_report(&mut summary, None),
+                Rerun::Files(files) => self.lint_and_report(&mut summary, Some(&files)),
+            }
+        }
+        CliRunResult::None
+    }
+
+    /// Lint all files or only `files`, and print the summary of the whole tree.
+    fn lint_and_report(&self, summary: &mut Summary, files: Option<&FxHashSet<PathBuf>>) {
+        let (mut result, file_counts) = self.lint_files(files.cloned());
+        if let CliRunResult::LintResult {
+            number_of_files,
+            number_of_warnings,
+            number_of_errors,
+            max_warnings_exceeded,
+            ..
+        } = &mut result
+        {
+            if files.is_none() {
+                summary.number_of_files = *number_of_files;
+            }
+            summary.update(files, file_counts);
+            let (warnings, errors) = summary.totals();
+            *number_of_files = summary.number_of_files;
+            *number_of_warnings = self.options.reported_warnings(warnings);
+            *number_of_errors = errors;
+            *max_warnings_exceeded = self.options.max_warnings_exceeded(warnings);
+        }
+        self.report(result);
+    }
+
+    fn report(&self, result: CliRunResult) {
+        let is_lint_result = matches!(result, CliRunResult::LintResult { .. });
+        // Prints the summary, the exit code only matters for a single run
+        let _ = result.report();
+        if is_lint_result && !self.options.format.is_machine_readable() {
+            println!("Watching for file changes...");
+        }
+    }
+
+    /// `config` is the canonical path of `--config`
+    fn rerun<I: IntoIterator<Item = PathBuf>>(&self, paths: I, config: Option<&Path>) -> Rerun {
+        let mut files = FxHashSet::default();
+        for path in paths {
+            if self.is_config_file(&path, config) {
+                return Rerun::All;
+            }
+            // Ignored files are skipped by the walk of the changed files,
+            // which compares canonical paths
+            if path.is_file() && has_lintable_extension(&path) {
+                if let Ok(path) = path.canonicalize() {
+                    files.insert(path);
+                }
+            }
+        }
+        if files.is_empty() {
+            Rerun::None
+        } else if self.options.import_plugin {
+            // Imports of other files may have changed
+            Rerun::All
+        } else {
+            Rerun::Files(files)
+        }
+    }
+
+    fn is_config_file(&self, path: &Path, config: Option<&Path>) -> bool {
+        let Some(file_name) = path.file_name() else { return false };
+        CONFIG_FILES.iter().any(|config| file_name == *config)
+            || self.options.ignore_path.file_name() == Some(file_name)
+            || config.is_some_and(|config| path.canonicalize().is_ok_and(|path| path == config))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::PathBuf};
+
+    use clap::Command;
+
+    use rustc_hash::FxHashMap;
+
+    use super::{LintRunner, Rerun, Summary};
+    use crate::{LintOptions, Runner, RunnerOptions};
+
+    #[test]
+    fn rerun() {
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--watch", "."])
+            .unwrap();
+        let runner = LintRunner::new(LintOptions::from(&matches));
+        let rerun = |paths: &[&str]| runner.rerun(paths.iter().map(PathBuf::from), None);
+        assert_eq!(rerun(&[]), Rerun::None);
+        assert_eq!(rerun(&["src/lib.rs", "does-not-exist.js"]), Rerun::None);
+        assert_eq!(rerun(&["fixtures/.eslintrc.json", "src/lib.rs"]), Rerun::All);
+        assert_eq!(rerun(&[".eslintignore"]), Rerun::All);
+
+        let file_name = format!("oxc_cli_watch_test_{}.js", std::process::id());
+        let file = env::temp_dir().join(&file_name);
+        fs::write(&file, "debugger;").unwrap();
+        let files = std::iter::once(file.canonicalize().unwrap()).collect();
+        assert_eq!(runner.rerun([file.clone()], None), Rerun::Files(files));
+
+        // `--config` is compared by its canonical path
+        let config = file.canonicalize().unwrap();
+        let temp_dir = env::temp_dir();
+        let relative = temp_dir.join("..").join(temp_dir.file_name().unwrap()).join(&file_name);
+        assert_eq!(runner.rerun([relative], Some(&config)), Rerun::All);
+
+        // Imports of the other files may have changed
+        let matches = LintOptions::build_args(Command::new("oxc"))
+            .try_get_matches_from(["lint", "--watch", "--import-plugin", "."])
+            .unwrap();
+        let runner = LintRunner::new(LintOptions::from(&matches));
+        assert_eq!(runner.rerun([file.clone()], None), Rerun::All);
+        fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn summary() {
+        let a = env::current_dir().unwrap().join("src/lib.rs");
+        let b = env::current_dir().unwrap().join("src/runner.rs");
+        let mut summary = Summary::default();
+        // The paths of a run of all files are relative
+        let counts = FxHashMap::from_iter([("src/lib.rs".into(), (1, 2)), (b.clone(), (3, 0))]);
+        summary.update(None, counts);
+        assert_eq!(summary.totals(), (4, 2));
+
+        // The diagnostics of `a` are fixed
+        let files = std::iter::once(a.clone()).collect();
+        summary.update(Some(&files), FxHashMap::default());
+        assert_eq!(summary.totals(), (3, 0));
+
+        let files = std::iter::once(b.clone()).collect();
+        summary.update(Some(&files), FxHashMap::from_iter([(b, (0, 1))]));
+        assert_eq!(summary.totals(), (0, 1));
+
+        summary.update(None, FxHashMap::from_iter([(a, (1, 0))]));
+        assert_eq!(summary.totals(), (1, 0));
+    }
+}
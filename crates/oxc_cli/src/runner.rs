@@ -32,6 +32,9 @@ pub trait Runner: Send + Sync {
 pub enum CliRunResult {
     None,
     IOError(crate::lint::Error),
+    InvalidOptions {
+        message: String,
+    },
     PathNotFound {
         paths: Vec<PathBuf>,
     },
@@ -39,9 +42,14 @@ pub enum CliRunResult {
         duration: std::time::Duration,
         number_of_rules: usize,
         number_of_files: usize,
+        /// Warnings are not counted with `--quiet`, except for `max_warnings_exceeded`
         number_of_warnings: usize,
         number_of_errors: usize,
         max_warnings_exceeded: bool,
+        /// The summary is not printed for machine readable output formats
+        print_summary: bool,
+        /// `path:line:column rule` of the fixes which were not applied because they overlap with other fixes
+        skipped_fixes: Vec<String>,
     },
     TypeCheckResult {
         duration: std::time::Duration,
@@ -61,6 +69,10 @@ impl Termination for CliRunResult {
                 println!("IO Error: {e}");
                 ExitCode::from(1)
             }
+            Self::InvalidOptions { message } => {
+                println!("Invalid Options: {message}");
+                ExitCode::from(1)
+            }
             Self::LintResult {
                 duration,
                 number_of_rules,
@@ -68,10 +80,30 @@ impl Termination for CliRunResult {
                 number_of_warnings,
                 number_of_errors,
                 max_warnings_exceeded,
+                print_summary,
+                skipped_fixes,
             } => {
+                // On stderr, to keep machine readable output and the fixed code of `--stdin` intact
+                if !skipped_fixes.is_empty() {
+                    let fixes = if skipped_fixes.len() == 1 { "fix" } else { "fixes" };
+                    eprintln!(
+                        "{} {fixes} skipped due to overlap, run again to apply them:",
+                        skipped_fixes.len()
+                    );
+                    for fix in &skipped_fixes {
+                        eprintln!("  {fix}");
+                    }
+                }
+
                 let ms = duration.as_millis();
                 let threads = rayon::current_num_threads();
                 let number_of_diagnostics = number_of_warnings + number_of_errors;
+                // Warnings only fail the run when there are more than `--max-warnings`
+                let failed = number_of_errors > 0 || max_warnings_exceeded;
+
+                if !print_summary {
+                    return ExitCode::from(u8::from(failed));
+                }
 
                 if number_of_diagnostics > 0 {
                     println!();
@@ -82,7 +114,14 @@ impl Termination for CliRunResult {
                 );
 
                 if max_warnings_exceeded {
-                    println!("Exceeded maximum number of warnings. Found {number_of_warnings}.");
+                    // The warnings are not counted with `--quiet`
+                    if number_of_warnings > 0 {
+                        println!(
+                            "Exceeded maximum number of warnings. Found {number_of_warnings}."
+                        );
+                    } else {
+                        println!("Exceeded maximum number of warnings.");
+                    }
                     return ExitCode::from(1);
                 }
 
@@ -92,12 +131,11 @@ impl Termination for CliRunResult {
                     println!(
                         "Found {number_of_warnings} {warnings} and {number_of_errors} {errors}."
                     );
-                    return ExitCode::from(1);
                 }
 
                 // eslint does not print anything after success, so we do the same.
                 // It is also standard to not print anything after success in the *nix world.
-                ExitCode::from(0)
+                ExitCode::from(u8::from(failed))
             }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
                 let ms = duration.as_millis();
@@ -158,6 +196,39 @@ mod tests {
         }
     }
 
+    /// The exit code of a lint result, `ExitCode` does not implement `PartialEq`
+    fn lint_exit_code(
+        number_of_warnings: usize,
+        number_of_errors: usize,
+        max_warnings_exceeded: bool,
+        print_summary: bool,
+    ) -> String {
+        let result = CliRunResult::LintResult {
+            duration: std::time::Duration::default(),
+            number_of_rules: 1,
+            number_of_files: 1,
+            number_of_warnings,
+            number_of_errors,
+            max_warnings_exceeded,
+            print_summary,
+            skipped_fixes: vec![],
+        };
+        format!("{:?}", result.report())
+    }
+
+    #[test]
+    fn lint_result_exit_code() {
+        let success = format!("{:?}", ExitCode::SUCCESS);
+        let failure = format!("{:?}", ExitCode::from(1));
+        for print_summary in [true, false] {
+            assert_eq!(lint_exit_code(0, 0, false, print_summary), success);
+            // Warnings only fail with `--max-warnings`
+            assert_eq!(lint_exit_code(1, 0, false, print_summary), success);
+            assert_eq!(lint_exit_code(6, 0, true, print_summary), failure);
+            assert_eq!(lint_exit_code(1, 1, false, print_summary), failure);
+        }
+    }
+
     #[test]
     fn check_cmd_validity() {
         TestRunner::command().debug_assert();
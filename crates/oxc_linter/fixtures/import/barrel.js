@@ -0,0 +1,2 @@
+export * from './index';
+export { viaBarrel as renamed } from './index';
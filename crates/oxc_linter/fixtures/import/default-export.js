@@ -0,0 +1,2 @@
+export default function () {}
+export const d = 1;
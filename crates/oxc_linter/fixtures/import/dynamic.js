@@ -0,0 +1,2 @@
+const a = require('./named-exports');
+import('./default-export');
@@ -0,0 +1,3 @@
+export * from './named-exports';
+export * from './default-export';
+export default 1;
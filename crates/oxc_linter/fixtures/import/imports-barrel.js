@@ -0,0 +1,2 @@
+import { fromBarrel, renamed } from './barrel';
+import './imports-index';
@@ -0,0 +1 @@
+import main, { used } from './index';
@@ -0,0 +1,2 @@
+export const a = 2,
+  e = 3;
@@ -0,0 +1,3 @@
+export const a = 1;
+export function b() {}
+export { a as c };
@@ -0,0 +1,2 @@
+export const fromComponent = 1;
+export const unused = 2;
@@ -265,3 +265,20 @@ pub fn get_name_from_property_key(key: &PropertyKey<'_>) -> Option<Atom> {
         },
     }
 }
+
+/// The source of `import a from "source"`, `export * from "source"` and `export { a } from "source"`,
+/// `None` for other declarations and for type-only imports and exports.
+pub fn get_module_source<'a>(decl: &'a ModuleDeclaration<'a>) -> Option<&'a StringLiteral> {
+    match decl {
+        ModuleDeclaration::ImportDeclaration(decl) if decl.import_kind.is_value() => {
+            Some(&decl.source)
+        }
+        ModuleDeclaration::ExportAllDeclaration(decl) if decl.export_kind.is_value() => {
+            Some(&decl.source)
+        }
+        ModuleDeclaration::ExportNamedDeclaration(decl) if decl.export_kind.is_value() => {
+            decl.source.as_ref()
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToParseConfigError(pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to open config {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToOpenFileError(pub PathBuf, pub std::io::Error);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config property `{0}`: {1}")]
+#[diagnostic()]
+pub struct FailedToParseConfigPropertyError(pub &'static str, pub String);
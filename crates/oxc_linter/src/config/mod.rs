@@ -0,0 +1,932 @@
+//! Linter configuration files.
+//!
+//! Supports a subset of [ESLint's legacy configuration](https://eslint.org/docs/latest/use/configure/configuration-files),
+//! read from `.oxlintrc.json` or `.eslintrc.json`:
+//!
+//! ```json
+//! {
+//!   "root": true,
+//!   "rules": { "no-debugger": "warn", "no-empty": ["error", { "allowEmptyCatch": true }] },
+//!   "overrides": [{ "files": ["**/*.test.ts"], "rules": { "no-debugger": "off" } }],
+//!   "ignorePatterns": ["dist"],
+//!   "env": { "browser": true },
+//!   "globals": { "jQuery": "readonly" },
+//!   "settings": { "react": { "pragma": "React", "version": "18.2" } }
+//! }
+//! ```
+//!
+//! Configuration files are discovered per directory. A file is linted with the configuration
+//! closest to the working directory, followed by the configurations of each nested directory
+//! down to the file itself, each followed by its `overrides` matching the file.
+
+mod errors;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use oxc_diagnostics::Error;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+pub use self::errors::{
+    FailedToOpenFileError, FailedToParseConfigError, FailedToParseConfigPropertyError,
+};
+use crate::{
+    globals::ENVIRONMENTS,
+    rule::{plugin_name_of_prefix, rule_name_of_alias},
+    rules::RULES,
+    AllowWarnDeny, RuleWithSeverity,
+};
+
+/// Configuration file names, in order of precedence within a single directory.
+pub const CONFIG_FILE_NAMES: [&str; 2] = [".oxlintrc.json", ".eslintrc.json"];
+
+/// A parsed configuration file.
+#[derive(Debug)]
+pub struct ESLintConfig {
+    /// Stop looking for configuration files in parent directories.
+    root: bool,
+    rules: Vec<ESLintRuleConfig>,
+    overrides: Vec<ESLintOverride>,
+    ignore_patterns: Gitignore,
+    settings: LintSettings,
+    /// The directory globs in `overrides` and `ignorePatterns` are relative to.
+    dir: PathBuf,
+}
+
+/// A single entry of `rules`, e.g. `"no-empty": ["error", { "allowEmptyCatch": true }]`.
+#[derive(Debug, Clone)]
+pub struct ESLintRuleConfig {
+    plugin_name: Option<String>,
+    rule_name: String,
+    pub severity: AllowWarnDeny,
+    /// Rule options following the severity, passed to `Rule::from_configuration`.
+    pub config: Option<Value>,
+}
+
+/// An entry of `overrides`, applied to files matching `files` but not `excludedFiles`.
+#[derive(Debug)]
+struct ESLintOverride {
+    files: GlobSet,
+    excluded_files: GlobSet,
+    rules: Vec<ESLintRuleConfig>,
+    settings: LintSettings,
+}
+
+/// Everything besides rules a file is linted with, e.g. `env` and `globals`.
+#[derive(Debug, Default, Clone)]
+pub struct LintSettings {
+    /// `env`, e.g. `{ "browser": true }`
+    pub env: FxHashMap<String, bool>,
+    /// `globals`, e.g. `{ "jQuery": "readonly" }`
+    pub globals: FxHashMap<String, GlobalValue>,
+    /// `settings.react`, e.g. `{ "pragma": "h", "version": "16.8" }`
+    pub react: ReactSettings,
+}
+
+/// Settings of the React rules, shared with `eslint-plugin-react`.
+#[derive(Debug, Default, Clone, Hash)]
+pub struct ReactSettings {
+    /// `pragma`, the object JSX is compiled to calls of, `React` unless set
+    pub pragma: Option<String>,
+    /// `fragment`, the property of the pragma fragments are compiled to, `Fragment` unless set
+    pub fragment: Option<String>,
+    /// `version`, `None` for `"detect"` or when not set, which is the latest version
+    pub version: Option<(u32, u32, u32)>,
+}
+
+impl ReactSettings {
+    pub fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React")
+    }
+
+    pub fn fragment(&self) -> &str {
+        self.fragment.as_deref().unwrap_or("Fragment")
+    }
+
+    /// Whether the configured React version is at least `major.minor.patch`
+    pub fn is_version_at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.version.map_or(true, |version| version >= (major, minor, patch))
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.pragma.is_some() {
+            self.pragma = other.pragma.clone();
+        }
+        if other.fragment.is_some() {
+            self.fragment = other.fragment.clone();
+        }
+        if other.version.is_some() {
+            self.version = other.version;
+        }
+    }
+}
+
+/// Value of a declared global variable.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GlobalValue {
+    Readonly,
+    Writeable,
+    Off,
+}
+
+/// Globals of environments are `true` when they are writable.
+impl From<bool> for GlobalValue {
+    fn from(writable: bool) -> Self {
+        if writable {
+            Self::Writeable
+        } else {
+            Self::Readonly
+        }
+    }
+}
+
+/// Entries are hashed in a stable order, so the hash does not depend on the insertion order.
+impl Hash for LintSettings {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.env.iter().collect::<BTreeMap<_, _>>().hash(state);
+        self.globals.iter().collect::<BTreeMap<_, _>>().hash(state);
+        self.react.hash(state);
+    }
+}
+
+impl LintSettings {
+    fn merge(&mut self, other: &Self) {
+        self.env.extend(other.env.iter().map(|(k, v)| (k.clone(), *v)));
+        self.globals.extend(other.globals.iter().map(|(k, v)| (k.clone(), *v)));
+        self.react.merge(&other.react);
+    }
+
+    /// Whether `name` is declared by `globals`, and not turned off.
+    pub fn has_global(&self, name: &str) -> bool {
+        self.globals.get(name).is_some_and(|value| *value != GlobalValue::Off)
+    }
+}
+
+impl ESLintConfig {
+    /// # Errors
+    ///
+    /// * The file cannot be read
+    /// * The file is not valid JSON, or contains invalid properties
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let s =
+            fs::read_to_string(path).map_err(|e| FailedToOpenFileError(path.to_path_buf(), e))?;
+        let json = serde_json::from_str::<Value>(&s)
+            .map_err(|err| FailedToParseConfigError(path.to_path_buf(), err.to_string()))?;
+        let dir = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        Self::from_value(&json, dir)
+            .map_err(|err| FailedToParseConfigError(path.to_path_buf(), err.to_string()).into())
+    }
+
+    /// # Errors
+    ///
+    /// * Any of the supported properties is invalid
+    pub fn from_value(json: &Value, dir: PathBuf) -> Result<Self, Error> {
+        let root = json.get("root").and_then(Value::as_bool).unwrap_or_default();
+        let rules = parse_rules(json)?;
+        let settings = parse_settings(json)?;
+
+        let mut ignore_builder = GitignoreBuilder::new(&dir);
+        if let Some(patterns) = json.get("ignorePatterns") {
+            for pattern in string_or_array("ignorePatterns", patterns)? {
+                ignore_builder.add_line(None, pattern).map_err(|err| {
+                    FailedToParseConfigPropertyError("ignorePatterns", err.to_string())
+                })?;
+            }
+        }
+        let ignore_patterns = ignore_builder
+            .build()
+            .map_err(|err| FailedToParseConfigPropertyError("ignorePatterns", err.to_string()))?;
+
+        let overrides = match json.get("overrides") {
+            None => vec![],
+            Some(Value::Array(overrides)) => {
+                overrides.iter().map(parse_override).collect::<Result<Vec<_>, _>>()?
+            }
+            Some(_) => {
+                return Err(FailedToParseConfigPropertyError(
+                    "overrides",
+                    "expected an array".into(),
+                )
+                .into())
+            }
+        };
+
+        Ok(Self { root, rules, overrides, ignore_patterns, settings, dir })
+    }
+
+    pub fn rules(&self) -> &[ESLintRuleConfig] {
+        &self.rules
+    }
+
+    pub fn settings(&self) -> &LintSettings {
+        &self.settings
+    }
+
+    /// Find the configuration file in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// * The configuration file cannot be read or parsed
+    pub fn find_in_dir(dir: &Path) -> Result<Option<Self>, Error> {
+        CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+            .map(|path| Self::from_file(&path))
+            .transpose()
+    }
+
+    fn relative_path<'p>(&self, path: &'p Path) -> Option<&'p Path> {
+        if self.dir.as_os_str().is_empty() {
+            return Some(path);
+        }
+        path.strip_prefix(&self.dir).ok()
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        self.relative_path(path).is_some_and(|path| {
+            self.ignore_patterns.matched_path_or_any_parents(path, /* is_dir */ false).is_ignore()
+        })
+    }
+
+    fn matching_overrides<'c>(&'c self, path: &Path) -> impl Iterator<Item = &'c ESLintOverride> {
+        let path = self.relative_path(path).map(Path::to_path_buf);
+        self.overrides.iter().filter(move |o| {
+            path.as_ref()
+                .is_some_and(|path| o.files.is_match(path) && !o.excluded_files.is_match(path))
+        })
+    }
+}
+
+impl ESLintRuleConfig {
+    /// An entry turning `rule_name` of `plugin_name` on or off without options.
+    pub fn new(plugin_name: &str, rule_name: &str, severity: AllowWarnDeny) -> Self {
+        Self {
+            plugin_name: Some(plugin_name.to_string()),
+            rule_name: rule_name.to_string(),
+            severity,
+            config: None,
+        }
+    }
+
+    /// Parse an entry of `rules`, the value is a severity or an array of the severity followed by options.
+    ///
+    /// # Errors
+    ///
+    /// * The severity is invalid
+    /// * No rule has this name
+    pub(crate) fn parse(key: &str, value: &Value) -> Result<Self, String> {
+        let (plugin_name, rule_name) = parse_rule_key(key);
+        let (severity, config) = match value {
+            Value::Array(values) => {
+                let severity =
+                    values.first().map_or(Ok(AllowWarnDeny::Deny), AllowWarnDeny::try_from);
+                let config = (values.len() > 1).then(|| Value::Array(values[1..].to_vec()));
+                (severity, config)
+            }
+            value => (AllowWarnDeny::try_from(value), None),
+        };
+        let config = Self { plugin_name, rule_name, severity: severity?, config };
+        if !RULES.iter().any(|rule| config.matches(rule.plugin_name(), rule.name())) {
+            return Err(format!("unknown rule {key:?}"));
+        }
+        Ok(config)
+    }
+
+    /// Whether this entry configures `rule_name` of `plugin_name`.
+    /// Entries without a plugin prefix match any rule with the same name.
+    pub fn matches(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.rule_name == rule_name
+            && self.plugin_name.as_deref().map_or(true, |name| name == plugin_name)
+    }
+}
+
+/// Apply `configs` on top of `rules` in order, later entries taking precedence.
+///
+/// A configuration without options only changes the severity of an already enabled rule.
+pub fn override_rules<'c, I: IntoIterator<Item = &'c ESLintRuleConfig>>(
+    rules: &mut Vec<RuleWithSeverity>,
+    configs: I,
+) {
+    for config in configs {
+        for rule in RULES.iter().filter(|rule| config.matches(rule.plugin_name(), rule.name())) {
+            let existing = rules.iter().position(|r| {
+                (r.rule.plugin_name(), r.rule.name()) == (rule.plugin_name(), rule.name())
+            });
+            if !config.severity.is_enabled() {
+                if let Some(index) = existing {
+                    rules.remove(index);
+                }
+                continue;
+            }
+            match (existing, &config.config) {
+                (Some(index), None) => rules[index].severity = config.severity,
+                (Some(index), Some(options)) => {
+                    rules[index] = RuleWithSeverity::new(
+                        rule.read_json(Some(options.clone())),
+                        config.severity,
+                    )
+                    .with_options(Some(options.clone()));
+                }
+                (None, options) => rules.push(
+                    RuleWithSeverity::new(rule.read_json(options.clone()), config.severity)
+                        .with_options(options.clone()),
+                ),
+            }
+        }
+    }
+}
+
+/// Configuration resolved for a single file, on top of the root configuration.
+#[derive(Debug, Default)]
+pub struct ResolvedConfig {
+    pub rules: Vec<ESLintRuleConfig>,
+    pub settings: Arc<LintSettings>,
+    /// A nested configuration file with `"root": true` applies,
+    /// `rules` and `settings` are not on top of the root configuration.
+    pub is_root: bool,
+}
+
+/// Discovers, caches and resolves configuration files for the files being linted.
+#[derive(Debug)]
+pub struct ConfigStore {
+    /// The configuration given on the command line, or discovered from the working directory.
+    root: Option<Arc<ESLintConfig>>,
+    /// Relative paths are resolved against the working directory.
+    cwd: PathBuf,
+    /// Only directories below this one are searched for nested configuration files.
+    root_dir: PathBuf,
+    discover_nested: bool,
+    /// The `env` and `globals` of the root configuration.
+    root_settings: Arc<LintSettings>,
+    /// Configuration file found in each visited directory.
+    nested: DashMap<PathBuf, Option<Arc<ESLintConfig>>>,
+}
+
+impl ConfigStore {
+    /// Discover the root configuration from `cwd` or its closest ancestor which contains one,
+    /// and nested configurations from the directories below it.
+    ///
+    /// # Errors
+    ///
+    /// * The root configuration file cannot be read or parsed
+    pub fn discover(cwd: &Path) -> Result<Self, Error> {
+        let cwd = normalize_path(cwd, cwd);
+        let mut root = None;
+        for dir in cwd.ancestors() {
+            if let Some(config) = ESLintConfig::find_in_dir(dir)? {
+                root = Some(config);
+                break;
+            }
+        }
+        let root_dir = root.as_ref().map_or_else(|| cwd.clone(), |config| config.dir.clone());
+        Ok(Self::new(root, cwd, root_dir, true))
+    }
+
+    /// Use the configuration file at `path` only, without looking for nested configurations.
+    ///
+    /// # Errors
+    ///
+    /// * The configuration file cannot be read or parsed
+    pub fn from_config_file(path: &Path, cwd: &Path) -> Result<Self, Error> {
+        let cwd = normalize_path(cwd, cwd);
+        let config = ESLintConfig::from_file(&normalize_path(path, &cwd))?;
+        let root_dir = config.dir.clone();
+        Ok(Self::new(Some(config), cwd, root_dir, false))
+    }
+
+    fn new(
+        root: Option<ESLintConfig>,
+        cwd: PathBuf,
+        root_dir: PathBuf,
+        discover_nested: bool,
+    ) -> Self {
+        let root_settings =
+            Arc::new(root.as_ref().map(|config| config.settings.clone()).unwrap_or_default());
+        Self {
+            root: root.map(Arc::new),
+            cwd,
+            root_dir,
+            discover_nested,
+            root_settings,
+            nested: DashMap::default(),
+        }
+    }
+
+    pub fn root(&self) -> Option<&ESLintConfig> {
+        self.root.as_deref()
+    }
+
+    pub fn root_settings(&self) -> &Arc<LintSettings> {
+        &self.root_settings
+    }
+
+    /// Whether `path` is ignored by `ignorePatterns`.
+    ///
+    /// # Errors
+    ///
+    /// * A nested configuration file cannot be read or parsed
+    pub fn is_ignored(&self, path: &Path) -> Result<bool, Error> {
+        let path = normalize_path(path, &self.cwd);
+        if self.root.as_ref().is_some_and(|config| config.is_ignored(&path)) {
+            return Ok(true);
+        }
+        Ok(self.nested_configs(&path)?.iter().any(|config| config.is_ignored(&path)))
+    }
+
+    /// Resolve the configuration of `path` beyond the rules of the root configuration,
+    /// returns `None` when the root configuration applies as is.
+    /// Below a nested configuration file with `"root": true` the root configuration does not apply.
+    ///
+    /// # Errors
+    ///
+    /// * A nested configuration file cannot be read or parsed
+    pub fn resolve(&self, path: &Path) -> Result<Option<ResolvedConfig>, Error> {
+        let path = normalize_path(path, &self.cwd);
+        let nested = self.nested_configs(&path)?;
+        // The outermost nested configuration is the one with `"root": true`, if any
+        let is_root = nested.first().is_some_and(|config| config.root);
+
+        let root_overrides = self
+            .root
+            .iter()
+            .filter(|_| !is_root)
+            .flat_map(|config| config.matching_overrides(&path));
+        let layers = root_overrides
+            .map(|o| (&o.rules, &o.settings))
+            .chain(nested.iter().flat_map(|config| {
+                std::iter::once((&config.rules, &config.settings))
+                    .chain(config.matching_overrides(&path).map(|o| (&o.rules, &o.settings)))
+            }))
+            .collect::<Vec<_>>();
+
+        if layers.is_empty() {
+            return Ok(None);
+        }
+
+        let mut settings =
+            if is_root { LintSettings::default() } else { (*self.root_settings).clone() };
+        let mut rules = vec![];
+        for (layer_rules, layer_settings) in layers {
+            rules.extend(layer_rules.iter().cloned());
+            settings.merge(layer_settings);
+        }
+        Ok(Some(ResolvedConfig { rules, settings: Arc::new(settings), is_root }))
+    }
+
+    /// Configuration files in the directories between the root directory and `path`,
+    /// from the outermost to the innermost.
+    fn nested_configs(&self, path: &Path) -> Result<Vec<Arc<ESLintConfig>>, Error> {
+        if !self.discover_nested {
+            return Ok(vec![]);
+        }
+        let mut configs = vec![];
+        for dir in path.ancestors().skip(1) {
+            if dir == self.root_dir || !dir.starts_with(&self.root_dir) {
+                break;
+            }
+            let config = if let Some(config) = self.nested.get(dir) {
+                config.clone()
+            } else {
+                let config = ESLintConfig::find_in_dir(dir)?.map(Arc::new);
+                self.nested.insert(dir.to_path_buf(), config.clone());
+                config
+            };
+            if let Some(config) = config {
+                let is_root = config.root;
+                configs.push(config);
+                if is_root {
+                    break;
+                }
+            }
+        }
+        configs.reverse();
+        Ok(configs)
+    }
+}
+
+/// Make `path` absolute and remove `.` and `..` components without touching the file system.
+fn normalize_path(path: &Path, cwd: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    let path = if path.is_absolute() { path.to_path_buf() } else { cwd.join(path) };
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+fn parse_rules(json: &Value) -> Result<Vec<ESLintRuleConfig>, Error> {
+    let Some(rules) = json.get("rules") else { return Ok(vec![]) };
+    let Value::Object(rules) = rules else {
+        return Err(FailedToParseConfigPropertyError("rules", "expected an object".into()).into());
+    };
+    rules
+        .iter()
+        .map(|(key, value)| {
+            ESLintRuleConfig::parse(key, value)
+                .map_err(|err| FailedToParseConfigPropertyError("rules", err).into())
+        })
+        .collect()
+}
+
+/// Split a rule key into the plugin and rule name, with ESLint rule names mapped to oxc rule names,
+/// e.g. `@typescript-eslint/no-namespace` => (Some("typescript"), "no-namespace"), `eqeqeq` => (None, "eq-eq-eq")
+fn parse_rule_key(key: &str) -> (Option<String>, String) {
+    let Some((plugin_name, rule_name)) = key.rsplit_once('/') else {
+        return (None, rule_name_of_alias(key).to_string());
+    };
+    (
+        Some(plugin_name_of_prefix(plugin_name).to_string()),
+        rule_name_of_alias(rule_name).to_string(),
+    )
+}
+
+fn parse_settings(json: &Value) -> Result<LintSettings, Error> {
+    let mut settings = LintSettings::default();
+    match json.get("env") {
+        None => {}
+        Some(Value::Object(env)) => {
+            for (name, value) in env {
+                let Value::Bool(enabled) = value else {
+                    return Err(FailedToParseConfigPropertyError(
+                        "env",
+                        format!("expected a boolean for {name:?}"),
+                    )
+                    .into());
+                };
+                if !ENVIRONMENTS.contains_key(name) {
+                    return Err(FailedToParseConfigPropertyError(
+                        "env",
+                        format!("unknown environment {name:?}"),
+                    )
+                    .into());
+                }
+                settings.env.insert(name.clone(), *enabled);
+            }
+        }
+        Some(_) => {
+            return Err(FailedToParseConfigPropertyError("env", "expected an object".into()).into())
+        }
+    }
+    match json.get("globals") {
+        None => {}
+        Some(Value::Object(globals)) => {
+            for (name, value) in globals {
+                let value = match value {
+                    Value::Bool(true) => GlobalValue::Writeable,
+                    Value::Bool(false) => GlobalValue::Readonly,
+                    Value::String(s) => match s.as_str() {
+                        "readonly" | "readable" => GlobalValue::Readonly,
+                        "writable" | "writeable" => GlobalValue::Writeable,
+                        "off" => GlobalValue::Off,
+                        _ => {
+                            return Err(FailedToParseConfigPropertyError(
+                                "globals",
+                                format!(r#"expected "readonly", "writable" or "off" for {name:?}"#),
+                            )
+                            .into())
+                        }
+                    },
+                    _ => {
+                        return Err(FailedToParseConfigPropertyError(
+                            "globals",
+                            format!("expected a string or a boolean for {name:?}"),
+                        )
+                        .into())
+                    }
+                };
+                settings.globals.insert(name.clone(), value);
+            }
+        }
+        Some(_) => {
+            return Err(
+                FailedToParseConfigPropertyError("globals", "expected an object".into()).into()
+            )
+        }
+    }
+    if let Some(react) = json.get("settings").and_then(|settings| settings.get("react")) {
+        settings.react = parse_react_settings(react)?;
+    }
+    Ok(settings)
+}
+
+fn parse_react_settings(json: &Value) -> Result<ReactSettings, Error> {
+    let error = |message: &str| FailedToParseConfigPropertyError("settings.react", message.into());
+    let Value::Object(react) = json else { return Err(error("expected an object").into()) };
+    let string = |key: &str| match react.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(error(&format!("expected a string for {key:?}"))),
+    };
+    let version = string("version")?
+        .filter(|version| version != "detect")
+        .map(|version| {
+            parse_version(&version).ok_or_else(|| {
+                error(&format!("expected a version like \"18.2.0\", found {version:?}"))
+            })
+        })
+        .transpose()?;
+    Ok(ReactSettings { pragma: string("pragma")?, fragment: string("fragment")?, version })
+}
+
+/// `major[.minor[.patch]]`, missing parts are `0`
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.').map(str::parse::<u32>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().transpose().ok()?.unwrap_or(0);
+    let patch = parts.next().transpose().ok()?.unwrap_or(0);
+    parts.next().is_none().then_some((major, minor, patch))
+}
+
+fn parse_override(json: &Value) -> Result<ESLintOverride, Error> {
+    let files = json.get("files").map_or(Ok(vec![]), |v| string_or_array("files", v))?;
+    if files.is_empty() {
+        return Err(FailedToParseConfigPropertyError(
+            "overrides",
+            "each override requires a non-empty `files`".into(),
+        )
+        .into());
+    }
+    let excluded_files =
+        json.get("excludedFiles").map_or(Ok(vec![]), |v| string_or_array("excludedFiles", v))?;
+    Ok(ESLintOverride {
+        files: build_glob_set("files", &files)?,
+        excluded_files: build_glob_set("excludedFiles", &excluded_files)?,
+        rules: parse_rules(json)?,
+        settings: parse_settings(json)?,
+    })
+}
+
+fn string_or_array<'v>(property: &'static str, value: &'v Value) -> Result<Vec<&'v str>, Error> {
+    match value {
+        Value::String(s) => Ok(vec![s.as_str()]),
+        Value::Array(values) => values
+            .iter()
+            .map(|v| {
+                v.as_str().ok_or_else(|| {
+                    FailedToParseConfigPropertyError(property, "expected a string".into()).into()
+                })
+            })
+            .collect(),
+        _ => {
+            Err(FailedToParseConfigPropertyError(property, "expected a string or an array".into())
+                .into())
+        }
+    }
+}
+
+/// Globs are relative to the configuration file,
+/// and a glob without a slash matches a file name in any directory (`*.test.ts`).
+fn build_glob_set(property: &'static str, patterns: &[&str]) -> Result<GlobSet, Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_start_matches("./");
+        let pattern = if pattern.contains('/') {
+            pattern.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{pattern}")
+        };
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|err| FailedToParseConfigPropertyError(property, err.to_string()))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|err| FailedToParseConfigPropertyError(property, err.to_string()).into())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use serde_json::json;
+
+    use super::{
+        normalize_path, override_rules, parse_version, ConfigStore, ESLintConfig, GlobalValue,
+    };
+    use crate::{AllowWarnDeny, RuleWithSeverity, RULES};
+
+    fn config(json: &serde_json::Value) -> ESLintConfig {
+        ESLintConfig::from_value(json, PathBuf::from("/project")).unwrap()
+    }
+
+    fn rule(name: &str) -> RuleWithSeverity {
+        let rule = RULES.iter().find(|rule| rule.name() == name).unwrap().clone();
+        RuleWithSeverity::new(rule, AllowWarnDeny::Deny)
+    }
+
+    #[test]
+    fn parse_rules() {
+        let config = config(&json!({
+            "rules": {
+                "no-debugger": "warn",
+                "eqeqeq": ["error", "always"],
+                "no-empty": 0,
+                "@typescript-eslint/no-namespace": [1]
+            }
+        }));
+        let rules = config.rules();
+        assert_eq!(rules.len(), 4);
+        let debugger = rules.iter().find(|r| r.matches("eslint", "no-debugger")).unwrap();
+        assert_eq!(debugger.severity, AllowWarnDeny::Warn);
+        assert!(debugger.config.is_none());
+        // Configured by the ESLint name of the rule
+        let eqeqeq = rules.iter().find(|r| r.matches("eslint", "eq-eq-eq")).unwrap();
+        assert_eq!(eqeqeq.severity, AllowWarnDeny::Deny);
+        assert_eq!(eqeqeq.config, Some(json!(["always"])));
+        let no_empty = rules.iter().find(|r| r.matches("eslint", "no-empty")).unwrap();
+        assert_eq!(no_empty.severity, AllowWarnDeny::Allow);
+        let no_namespace = rules.iter().find(|r| r.matches("typescript", "no-namespace")).unwrap();
+        assert_eq!(no_namespace.severity, AllowWarnDeny::Warn);
+        assert!(!no_namespace.matches("eslint", "no-namespace"));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let dir = PathBuf::from("/project");
+        assert!(ESLintConfig::from_value(&json!({ "rules": [] }), dir.clone()).is_err());
+        assert!(ESLintConfig::from_value(
+            &json!({ "rules": { "no-debugger": "on" } }),
+            dir.clone()
+        )
+        .is_err());
+        assert!(ESLintConfig::from_value(
+            &json!({ "rules": { "no-bogus-rule": "error" } }),
+            dir.clone()
+        )
+        .is_err());
+        assert!(ESLintConfig::from_value(&json!({ "overrides": [{}] }), dir.clone()).is_err());
+        assert!(
+            ESLintConfig::from_value(&json!({ "globals": { "a": "yes" } }), dir.clone()).is_err()
+        );
+        assert!(ESLintConfig::from_value(
+            &json!({ "settings": { "react": { "version": "latest" } } }),
+            dir.clone()
+        )
+        .is_err());
+        assert!(ESLintConfig::from_value(&json!({ "env": { "browsers": true } }), dir).is_err());
+    }
+
+    #[test]
+    fn parse_settings() {
+        let config = config(&json!({
+            "env": { "browser": true, "node": false },
+            "globals": { "a": "readonly", "b": "writable", "c": "off", "d": true }
+        }));
+        let settings = config.settings();
+        assert_eq!(settings.env.get("browser"), Some(&true));
+        assert_eq!(settings.env.get("node"), Some(&false));
+        assert_eq!(settings.globals.get("a"), Some(&GlobalValue::Readonly));
+        assert_eq!(settings.globals.get("b"), Some(&GlobalValue::Writeable));
+        assert!(settings.has_global("d"));
+        assert!(!settings.has_global("c"));
+        assert!(!settings.has_global("e"));
+    }
+
+    #[test]
+    fn parse_react_settings() {
+        let react = config(&json!({})).settings().react.clone();
+        assert_eq!(react.pragma(), "React");
+        assert_eq!(react.fragment(), "Fragment");
+        assert!(react.is_version_at_least(18, 0, 0));
+
+        let config = config(&json!({
+            "settings": { "react": { "pragma": "h", "fragment": "Frag", "version": "16.2" } }
+        }));
+        let react = &config.settings().react;
+        assert_eq!(react.pragma(), "h");
+        assert_eq!(react.fragment(), "Frag");
+        assert!(react.is_version_at_least(16, 2, 0));
+        assert!(!react.is_version_at_least(16, 3, 0));
+    }
+
+    #[test]
+    fn parse_react_version() {
+        assert_eq!(parse_version("18"), Some((18, 0, 0)));
+        assert_eq!(parse_version("16.14.0"), Some((16, 14, 0)));
+        assert_eq!(parse_version("16.x"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+        let detect = config(&json!({ "settings": { "react": { "version": "detect" } } }));
+        assert_eq!(detect.settings().react.version, None);
+    }
+
+    #[test]
+    fn override_rules_by_eslint_name() {
+        let config = config(&json!({ "rules": { "eqeqeq": "error" } }));
+        let mut rules = vec![];
+        override_rules(&mut rules, config.rules());
+        let names = rules.iter().map(|r| (r.rule.name(), r.severity)).collect::<Vec<_>>();
+        assert_eq!(names, [("eq-eq-eq", AllowWarnDeny::Deny)]);
+    }
+
+    #[test]
+    fn override_rules_in_order() {
+        let config = config(&json!({
+            "rules": {
+                "no-debugger": "warn",
+                "no-empty": "off",
+                "no-bitwise": ["error", { "allow": ["~"] }]
+            }
+        }));
+        let mut rules = vec![rule("no-debugger"), rule("no-empty")];
+        override_rules(&mut rules, config.rules());
+        let mut names = rules.iter().map(|r| (r.rule.name(), r.severity)).collect::<Vec<_>>();
+        names.sort_unstable_by_key(|(name, _)| *name);
+        assert_eq!(
+            names,
+            vec![("no-bitwise", AllowWarnDeny::Deny), ("no-debugger", AllowWarnDeny::Warn)]
+        );
+    }
+
+    #[test]
+    fn ignore_patterns() {
+        let config = config(&json!({ "ignorePatterns": ["dist", "*.generated.js"] }));
+        assert!(config.is_ignored(Path::new("/project/dist/index.js")));
+        assert!(config.is_ignored(Path::new("/project/src/foo.generated.js")));
+        assert!(!config.is_ignored(Path::new("/project/src/index.js")));
+        assert!(!config.is_ignored(Path::new("/other/dist/index.js")));
+    }
+
+    #[test]
+    fn overrides() {
+        let config = config(&json!({
+            "overrides": [
+                { "files": ["*.test.ts"], "excludedFiles": "src/keep/**", "rules": { "no-debugger": "off" } },
+                { "files": "src/**/*.js", "globals": { "foo": "readonly" } }
+            ]
+        }));
+        let matching = |path: &str| config.matching_overrides(Path::new(path)).count();
+        assert_eq!(matching("/project/a.test.ts"), 1);
+        assert_eq!(matching("/project/src/deep/a.test.ts"), 1);
+        assert_eq!(matching("/project/src/keep/a.test.ts"), 0);
+        assert_eq!(matching("/project/src/deep/a.js"), 1);
+        assert_eq!(matching("/project/a.js"), 0);
+    }
+
+    #[test]
+    fn normalize() {
+        let cwd = Path::new("/project");
+        assert_eq!(
+            normalize_path(Path::new("./src/a.js"), cwd),
+            PathBuf::from("/project/src/a.js")
+        );
+        assert_eq!(normalize_path(Path::new("../b/a.js"), cwd), PathBuf::from("/b/a.js"));
+        assert_eq!(normalize_path(Path::new("/x/./y"), cwd), PathBuf::from("/x/y"));
+    }
+
+    #[test]
+    fn nested_configs() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/config");
+        let store = ConfigStore::discover(&fixtures).unwrap();
+        assert!(store.root().is_some());
+
+        assert!(store.is_ignored(&fixtures.join("dist/index.js")).unwrap());
+        assert!(store.is_ignored(&fixtures.join("packages/legacy/vendor/index.js")).unwrap());
+        assert!(!store.is_ignored(&fixtures.join("packages/legacy/index.js")).unwrap());
+
+        // Only the root configuration applies.
+        assert!(store.resolve(&fixtures.join("index.js")).unwrap().is_none());
+
+        // Root overrides
+        let resolved = store.resolve(&fixtures.join("index.test.js")).unwrap().unwrap();
+        assert_eq!(resolved.rules.len(), 1);
+        assert_eq!(resolved.rules[0].severity, AllowWarnDeny::Allow);
+
+        // Nested configuration followed by its overrides
+        let resolved =
+            store.resolve(&fixtures.join("packages/legacy/src/index.test.js")).unwrap().unwrap();
+        let severities = resolved.rules.iter().map(|r| r.severity).collect::<Vec<_>>();
+        assert_eq!(
+            severities,
+            vec![AllowWarnDeny::Allow, AllowWarnDeny::Warn, AllowWarnDeny::Deny]
+        );
+        assert!(resolved.settings.has_global("legacyGlobal"));
+        assert!(resolved.settings.has_global("rootGlobal"));
+        assert!(!resolved.is_root);
+
+        // `"root": true` stops at the nested configuration, without the root rules and settings
+        let resolved =
+            store.resolve(&fixtures.join("packages/standalone/index.test.js")).unwrap().unwrap();
+        assert!(resolved.is_root);
+        assert_eq!(resolved.rules.len(), 1);
+        assert_eq!(resolved.rules[0].rule_name, "no-empty");
+        assert!(!resolved.settings.has_global("rootGlobal"));
+    }
+}
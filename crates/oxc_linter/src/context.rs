@@ -1,14 +1,29 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{OnceCell, RefCell},
+    fmt::{self, Display},
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 
-use oxc_diagnostics::Error;
+use oxc_ast::Trivias;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, SourceCode},
+    Error, Severity,
+};
 use oxc_formatter::{Formatter, FormatterOptions};
 use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
 use oxc_span::SourceType;
 
 use crate::{
-    disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
-    AstNode,
+    config::{ESLintRuleConfig, GlobalValue, LintSettings},
+    disable_directives::{DisableDirectives, DisableDirectivesBuilder, UnusedDisableDirective},
+    fixer::{Fix, FixKind, Message},
+    globals::{BUILTINS, ENVIRONMENTS},
+    inline_config::InlineConfig,
+    module_graph::ModuleGraph,
+    react_ast_util::JsxPragma,
+    AllowWarnDeny, AstNode, RuleEnum, RuleInfo,
 };
 
 pub struct LintContext<'a> {
@@ -18,22 +33,51 @@ pub struct LintContext<'a> {
 
     disable_directives: DisableDirectives<'a>,
 
+    /// `/* global */`, `/* eslint-env */` and `/* eslint */` comments
+    inline_config: InlineConfig,
+
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
-    current_rule_name: &'static str,
+    /// Whether or not to also apply suggestions, which may change the behavior of the code.
+    fix_suggestions: bool,
+
+    file_path: Box<Path>,
+
+    /// `env` and `globals` resolved from configuration files for this file.
+    settings: Arc<LintSettings>,
+
+    /// Modules of the project, `None` unless the import plugin is enabled
+    module_graph: Option<Arc<ModuleGraph>>,
+
+    /// Computed on first use, from the comments of the file and `settings`
+    jsx_pragma: OnceCell<JsxPragma>,
+
+    current_rule: Option<RuleInfo>,
+
+    /// Severity of the diagnostics reported by the current rule,
+    /// overriding the severity declared by the rule's diagnostic when set.
+    current_rule_severity: Option<Severity>,
 }
 
 impl<'a> LintContext<'a> {
-    pub fn new(semantic: &Rc<Semantic<'a>>) -> Self {
+    pub fn new(file_path: Box<Path>, semantic: &Rc<Semantic<'a>>) -> Self {
         let disable_directives =
             DisableDirectivesBuilder::new(semantic.source_text(), semantic.trivias()).build();
+        let inline_config = InlineConfig::parse(semantic.source_text(), semantic.trivias());
         Self {
             semantic: Rc::clone(semantic),
             diagnostics: RefCell::new(vec![]),
             disable_directives,
+            inline_config,
             fix: false,
-            current_rule_name: "",
+            fix_suggestions: false,
+            file_path,
+            settings: Arc::default(),
+            module_graph: None,
+            jsx_pragma: OnceCell::new(),
+            current_rule: None,
+            current_rule_severity: None,
         }
     }
 
@@ -43,6 +87,35 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, fix_suggestions: bool) -> Self {
+        self.fix_suggestions = fix_suggestions;
+        self
+    }
+
+    /// Ignore the configuration comments and `eslint-disable` directives of the file when `false`.
+    #[must_use]
+    pub fn with_inline_config(mut self, yes: bool) -> Self {
+        if !yes {
+            self.disable_directives =
+                DisableDirectivesBuilder::new("", &Trivias::default()).build();
+            self.inline_config = InlineConfig::default();
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn with_settings(mut self, settings: Arc<LintSettings>) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    #[must_use]
+    pub fn with_module_graph(mut self, module_graph: Option<Arc<ModuleGraph>>) -> Self {
+        self.module_graph = module_graph;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -55,8 +128,60 @@ impl<'a> LintContext<'a> {
         self.semantic().source_type()
     }
 
-    pub fn with_rule_name(&mut self, name: &'static str) {
-        self.current_rule_name = name;
+    pub fn file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    /// The names JSX is compiled to references of, e.g. `React.createElement`
+    pub fn jsx_pragma(&self) -> &JsxPragma {
+        self.jsx_pragma.get_or_init(|| JsxPragma::from_context(self))
+    }
+
+    pub fn settings(&self) -> &LintSettings {
+        &self.settings
+    }
+
+    /// Modules of the project to look up the imports of this file,
+    /// `None` unless the import plugin is enabled.
+    pub fn module_graph(&self) -> Option<&ModuleGraph> {
+        self.module_graph.as_deref()
+    }
+
+    /// Rules configured by `/* eslint */` comments.
+    pub fn inline_rules(&self) -> &[ESLintRuleConfig] {
+        &self.inline_config.rules
+    }
+
+    /// The global variable `name` declared by a `/* global */` comment, `globals`,
+    /// the environments of `env` and `/* eslint-env */` comments, or the builtins, in that order.
+    /// `None` when it is not declared or turned off.
+    pub fn global(&self, name: &str) -> Option<GlobalValue> {
+        let enabled_env =
+            self.settings.env.iter().filter(|(_, enabled)| **enabled).map(|(env, _)| env);
+        let value = self
+            .inline_config
+            .globals
+            .get(name)
+            .or_else(|| self.settings.globals.get(name))
+            .copied()
+            .or_else(|| {
+                enabled_env
+                    .chain(&self.inline_config.env)
+                    .find_map(|env| ENVIRONMENTS.get(env.as_str())?.get(name))
+                    .or_else(|| BUILTINS.get(name))
+                    .map(|writable| GlobalValue::from(*writable))
+            });
+        value.filter(|value| *value != GlobalValue::Off)
+    }
+
+    pub fn with_rule(&mut self, rule: &RuleEnum) {
+        self.current_rule = Some(RuleInfo::new(rule));
+    }
+
+    pub fn with_severity(&mut self, severity: AllowWarnDeny) {
+        if let Some(severity) = severity.severity() {
+            self.current_rule_severity = Some(severity);
+        }
     }
 
     /* Diagnostics */
@@ -66,13 +191,43 @@ impl<'a> LintContext<'a> {
     }
 
     fn add_diagnostic(&self, message: Message<'a>) {
-        if !self.disable_directives.contains(self.current_rule_name, message.start()) {
+        if !self.disable_directives.contains(self.current_rule.as_ref(), message.start()) {
             self.diagnostics.borrow_mut().push(message);
         }
     }
 
+    /// Report `eslint-disable` directives which did not suppress any diagnostic,
+    /// must be called after all rules have run.
+    pub fn report_unused_disable_directives(&self) {
+        for unused in self.disable_directives.unused() {
+            let error =
+                UnusedDisableDirective(unused.rule_name.map(ToString::to_string), unused.span);
+            let fix = self.fix.then(|| Fix::delete(unused.fix_span));
+            self.diagnostics.borrow_mut().push(Message::new(error.into(), fix));
+        }
+    }
+
+    /// Report `/* eslint */` comments which could not be parsed.
+    pub fn report_inline_config_errors(&self) {
+        for error in &self.inline_config.errors {
+            self.diagnostics.borrow_mut().push(Message::new(error.clone().into(), None));
+        }
+    }
+
+    fn rule_message<T: Into<Error>>(&self, diagnostic: T, fix: Option<Fix<'a>>) -> Message<'a> {
+        let Some(rule) = self.current_rule else {
+            return Message::new(diagnostic.into(), fix);
+        };
+        let error = Error::new(RuleDiagnostic {
+            error: diagnostic.into(),
+            rule,
+            severity: self.current_rule_severity,
+        });
+        Message::new(error, fix).with_rule(rule)
+    }
+
     pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
-        self.add_diagnostic(Message::new(diagnostic.into(), None));
+        self.add_diagnostic(self.rule_message(diagnostic, None));
     }
 
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
@@ -81,7 +236,22 @@ impl<'a> LintContext<'a> {
         F: FnOnce() -> Fix<'a>,
     {
         if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+            self.add_diagnostic(self.rule_message(diagnostic, Some(fix())));
+        } else {
+            self.diagnostic(diagnostic);
+        }
+    }
+
+    /// Report a diagnostic with a fix which may change the behavior of the code,
+    /// it is only applied when suggestions are enabled.
+    pub fn diagnostic_with_suggestion<T, F>(&self, diagnostic: T, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        if self.fix_suggestions {
+            let fix = fix().with_kind(FixKind::Suggestion);
+            self.add_diagnostic(self.rule_message(diagnostic, Some(fix)));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -109,3 +279,59 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc().get_by_node(node)
     }
 }
+
+/// A diagnostic reported by a rule, with the severity the rule is configured with.
+/// The message is prefixed by the rule, e.g. `eslint(no-debugger): message`.
+#[derive(Debug)]
+struct RuleDiagnostic {
+    error: Error,
+    rule: RuleInfo,
+    /// `None` for the severity declared by `error`
+    severity: Option<Severity>,
+}
+
+impl Display for RuleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.rule, self.error)
+    }
+}
+
+impl std::error::Error for RuleDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for RuleDiagnostic {
+    fn code<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity.or_else(|| self.error.severity())
+    }
+
+    fn help<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.error.help()
+    }
+
+    fn url<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'b>(&'b self) -> Option<Box<dyn Iterator<Item = &'b dyn Diagnostic> + 'b>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
@@ -1,40 +1,163 @@
+use std::cell::Cell;
+
 use oxc_ast::Trivias;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
+use crate::{
+    inline_config,
+    rule::{plugin_name_of_prefix, rule_name_of_alias},
+    RuleInfo,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused eslint-disable directive (no problems were reported{})", .0.as_ref().map_or_else(String::new, |name| format!(" from '{name}'")))]
+pub struct UnusedDisableDirective(pub Option<String>, #[label] pub Span);
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum DisabledRule<'a> {
     All,
     Single(&'a str),
 }
 
+/// A rule of an `eslint-disable` comment, or all rules when no rule is given.
+#[derive(Debug)]
+struct DisableDirective<'a> {
+    rule: DisabledRule<'a>,
+    /// Span of the whole comment, including `//` or `/* */`
+    comment_span: Span,
+    /// Span of the rule name inside the comment, the same as `comment_span` for `DisabledRule::All`
+    rule_span: Span,
+    /// `eslint-disable` up to `eslint-enable`, as opposed to `eslint-disable-line` and `eslint-disable-next-line`
+    is_block: bool,
+    /// Whether this directive suppressed a diagnostic
+    used: Cell<bool>,
+}
+
+/// An unused rule of an `eslint-disable` comment, see [`DisableDirectives::unused`].
+pub struct UnusedDirective<'a> {
+    /// `None` for `eslint-disable` without rules
+    pub rule_name: Option<&'a str>,
+    pub span: Span,
+    /// Removes the rule name, or the whole comment when none of its rules are used
+    pub fix_span: Span,
+}
+
 pub struct DisableDirectives<'a> {
-    /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    directives: Vec<DisableDirective<'a>>,
+    /// Indices into `directives` with their corresponding covering spans
+    intervals: Lapper<u32, usize>,
+    /// Rules enabled by `eslint-enable rule-name` inside an `eslint-disable` block
+    enabled_intervals: Lapper<u32, &'a str>,
 }
 
 impl<'a> DisableDirectives<'a> {
-    pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
-                // Our rule name currently does not contain the prefix.
-                // For example, this will match `@typescript-eslint/no-var-requires` given
-                // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+    /// Whether a diagnostic of `rule` at `start` is suppressed.
+    /// All directives covering the diagnostic are marked as used.
+    pub fn contains(&self, rule: Option<&RuleInfo>, start: u32) -> bool {
+        let mut suppressed = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let directive = &self.directives[interval.val];
+            let is_match = match directive.rule {
+                DisabledRule::All => {
+                    !directive.is_block
+                        || rule.map_or(true, |rule| !self.is_enabled_in_block(rule, start))
+                }
+                DisabledRule::Single(name) => rule.is_some_and(|rule| matches_rule(name, rule)),
+            };
+            if is_match {
+                directive.used.set(true);
+                suppressed = true;
+            }
+        }
+        suppressed
+    }
+
+    fn is_enabled_in_block(&self, rule: &RuleInfo, start: u32) -> bool {
+        self.enabled_intervals
+            .find(start, start + 1)
+            .any(|interval| matches_rule(interval.val, rule))
     }
+
+    /// Directives which did not suppress any diagnostic.
+    pub fn unused(&self) -> Vec<UnusedDirective<'a>> {
+        let mut unused = vec![];
+        for group in group_by_comment(&self.directives) {
+            let all_unused = group.iter().all(|directive| !directive.used.get());
+            for (i, directive) in group.iter().enumerate() {
+                if directive.used.get() {
+                    continue;
+                }
+                let rule_name = match directive.rule {
+                    DisabledRule::All => None,
+                    DisabledRule::Single(name) => Some(name),
+                };
+                let fix_span = if all_unused {
+                    directive.comment_span
+                } else if let Some(next) = group.get(i + 1) {
+                    // `a, b` => `b`
+                    Span::new(directive.rule_span.start, next.rule_span.start)
+                } else {
+                    // `a, b` => `a`, the last one has a previous one because not all are unused
+                    Span::new(group[i - 1].rule_span.end, directive.rule_span.end)
+                };
+                unused.push(UnusedDirective { rule_name, span: directive.comment_span, fix_span });
+            }
+        }
+        unused
+    }
+}
+
+/// Split directives into those of the same comment, which are adjacent.
+fn group_by_comment<'a, 'b>(
+    directives: &'b [DisableDirective<'a>],
+) -> Vec<&'b [DisableDirective<'a>]> {
+    let mut groups = vec![];
+    let mut start = 0;
+    for i in 1..=directives.len() {
+        if i == directives.len() || directives[i].comment_span != directives[start].comment_span {
+            groups.push(&directives[start..i]);
+            start = i;
+        }
+    }
+    groups
+}
+
+/// Rules are matched exactly, plugin rules need to be qualified by their plugin.
+/// e.g. `no-debugger` for `eslint(no-debugger)`, `@typescript-eslint/no-namespace` for `typescript-eslint(no-namespace)`
+/// and `eqeqeq`, the ESLint name, for `eslint(eq-eq-eq)`
+fn matches_rule(name: &str, rule: &RuleInfo) -> bool {
+    let Some((plugin, rule_name)) = name.rsplit_once('/') else {
+        return rule.plugin == "eslint" && rule.name == rule_name_of_alias(name);
+    };
+    rule.plugin == plugin_name_of_prefix(plugin) && rule.name == rule_name_of_alias(rule_name)
 }
 
 pub struct DisableDirectivesBuilder<'a, 'b> {
     source_text: &'a str,
     trivias: &'b Trivias,
-    /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    directives: Vec<DisableDirective<'a>>,
+    /// Indices into `directives` with their corresponding covering spans
+    intervals: Lapper<u32, usize>,
+    enabled_intervals: Lapper<u32, &'a str>,
+    /// Start of `eslint-disable` with its directive
+    disable_all_start: Option<(u32, usize)>,
+    /// Start of `eslint-disable rule_name` with its directive
+    disable_start_map: FxHashMap<&'a str, (u32, usize)>,
+    /// Start of `eslint-enable rule_name` inside an `eslint-disable` block
+    enable_start_map: FxHashMap<&'a str, u32>,
+}
+
+/// The parts of a comment needed to create its directives
+#[derive(Clone, Copy)]
+struct Comment {
+    span: Span,
+    is_block: bool,
 }
 
 impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
@@ -42,19 +165,48 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         Self {
             source_text,
             trivias,
+            directives: vec![],
             intervals: Lapper::new(vec![]),
+            enabled_intervals: Lapper::new(vec![]),
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
+            enable_start_map: FxHashMap::default(),
         }
     }
 
     pub fn build(mut self) -> DisableDirectives<'a> {
         self.build_impl();
-        DisableDirectives { intervals: self.intervals }
+        DisableDirectives {
+            directives: self.directives,
+            intervals: self.intervals,
+            enabled_intervals: self.enabled_intervals,
+        }
+    }
+
+    fn add_directive(&mut self, comment: Comment, rule: DisabledRule<'a>) -> usize {
+        let rule_span = match rule {
+            DisabledRule::All => comment.span,
+            DisabledRule::Single(name) => self.span_of(name),
+        };
+        self.directives.push(DisableDirective {
+            rule,
+            comment_span: comment.span,
+            rule_span,
+            is_block: comment.is_block,
+            used: Cell::new(false),
+        });
+        self.directives.len() - 1
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_interval(&mut self, start: u32, stop: u32, directive: usize) {
+        self.intervals.insert(Interval { start, stop, val: directive });
+    }
+
+    /// Span of a rule name, which is a slice of the source text
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn span_of(&self, name: &'a str) -> Span {
+        let start = name.as_ptr() as usize - self.source_text.as_ptr() as usize;
+        Span::new(start as u32, (start + name.len()) as u32)
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -66,36 +218,58 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         for (start, comment) in self.trivias.comments() {
             let span = Span::new(*start, comment.end());
             let text = span.source_text(self.source_text);
-            let text = text.trim_start();
+            // The comment span excludes `//`, `/*` and `*/`, but includes the line terminator of `//`
+            let comment_span = Span::new(
+                span.start - 2,
+                if comment.is_single_line() {
+                    span.start
+                        + text.trim_end_matches(['\n', '\r', '\u{2028}', '\u{2029}']).len() as u32
+                } else {
+                    span.end + 2
+                },
+            );
+            let text = inline_config::strip_description(text).trim_start();
 
-            if let Some(text) = text.strip_prefix("eslint-disable") {
+            if let Some(text) = strip_directive(text, "eslint-disable") {
                 // `eslint-disable`
+                let block = Comment { span: comment_span, is_block: true };
                 if text.trim().is_empty() {
-                    self.disable_all_start = Some(span.end);
+                    // A repeated `eslint-disable` covers nothing and is reported as unused
+                    let directive = self.add_directive(block, DisabledRule::All);
+                    if self.disable_all_start.is_none() {
+                        self.disable_all_start = Some((span.end, directive));
+                    }
                     continue;
                 }
 
                 // `eslint-disable-next-line`
-                if let Some(text) = text.strip_prefix("-next-line") {
-                    // Get the span up to the next new line
-                    let stop = self.source_text[span.end as usize..]
-                        .lines()
-                        .take(if comment.is_single_line() { 1 } else { 2 })
-                        .map(|line| span.end + line.len() as u32)
-                        .sum();
+                let line = Comment { span: comment_span, is_block: false };
+                if let Some(text) = strip_directive(text, "-next-line") {
+                    // Up to the end of the next line. The span of `//` comments includes
+                    // their line terminator, block comments end on the line of `*/`.
+                    let rest = &self.source_text[span.end as usize..];
+                    let line_breaks = if comment.is_single_line() { 1 } else { 2 };
+                    let stop = rest
+                        .match_indices('\n')
+                        .nth(line_breaks - 1)
+                        .map_or(rest.len(), |(offset, _)| offset);
+                    let stop = span.end + stop as u32;
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        let directive = self.add_directive(line, DisabledRule::All);
+                        self.add_interval(span.end, stop, directive);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
-                        });
+                        for rule_name in get_rule_names(text) {
+                            let directive =
+                                self.add_directive(line, DisabledRule::Single(rule_name));
+                            self.add_interval(span.end, stop, directive);
+                        }
                     }
                     continue;
                 }
 
                 // `eslint-disable-line`
-                if let Some(text) = text.strip_prefix("-line") {
+                if let Some(text) = strip_directive(text, "-line") {
                     // Get the span between the preceding newline to this comment
                     let start = self.source_text[..=span.start as usize]
                         .lines()
@@ -105,61 +279,84 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        let directive = self.add_directive(line, DisabledRule::All);
+                        self.add_interval(start, stop, directive);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
-                        });
+                        for rule_name in get_rule_names(text) {
+                            let directive =
+                                self.add_directive(line, DisabledRule::Single(rule_name));
+                            self.add_interval(start, stop, directive);
+                        }
                     }
                     continue;
                 }
 
                 // `eslint-disable rule-name1, rule-name2`
-                Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.insert(rule_name, span.end);
-                });
-
+                if text.starts_with(char::is_whitespace) {
+                    for rule_name in get_rule_names(text) {
+                        let directive = self.add_directive(block, DisabledRule::Single(rule_name));
+                        self.disable_start_map.entry(rule_name).or_insert((span.end, directive));
+                    }
+                }
                 continue;
             }
 
-            if let Some(text) = text.strip_prefix("eslint-enable") {
+            if let Some(text) = strip_directive(text, "eslint-enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    self.close_disable_all(span.start);
+                    let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
+                    for (_, (start, directive)) in disable_start_map {
+                        self.add_interval(start, span.start, directive);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
-                    Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                    for rule_name in get_rule_names(text) {
+                        if let Some((start, directive)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(start, span.start, directive);
+                        }
+                        // The rule is enabled again while all other rules stay disabled
+                        if self.disable_all_start.is_some() {
+                            self.enable_start_map.entry(rule_name).or_insert(span.end);
                         }
-                    });
+                    }
                 }
                 continue;
             }
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
-        }
+        self.close_disable_all(source_len);
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (_, (start, directive)) in disable_start_map {
+            self.add_interval(start, source_len, directive);
         }
     }
 
-    fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
-        if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
+    fn close_disable_all(&mut self, stop: u32) {
+        if let Some((start, directive)) = self.disable_all_start.take() {
+            self.add_interval(start, stop, directive);
+        }
+        for (rule_name, start) in self.enable_start_map.drain() {
+            self.enabled_intervals.insert(Interval { start, stop, val: rule_name });
         }
     }
 }
 
+/// Strip `directive` from the start of `text`, which must not be followed by other characters of a rule name,
+/// e.g. `eslint-disable-line` is not `eslint-disable` followed by rule `-line`.
+fn strip_directive<'a>(text: &'a str, directive: &str) -> Option<&'a str> {
+    text.strip_prefix(directive)
+        .filter(|rest| rest.is_empty() || rest.starts_with(|c: char| c.is_whitespace() || c == '-'))
+}
+
+fn get_rule_names(text: &str) -> impl Iterator<Item = &str> {
+    text.split(',').map(str::trim).filter(|name| !name.is_empty())
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -248,7 +445,22 @@ fn test() {
              * along with some additional information
             **/
             debugger;
+        ",
+        // Description without rules
+        "
+            debugger; // eslint-disable-line -- Here's a description
+        ",
+        // Re-enabled rules other than the reported one
+        "
+            /* eslint-disable */
+            /* eslint-enable no-alert */
+            debugger;
+        ",
+        // Plugin rules are qualified by their plugin
         "
+            /* eslint-disable no-debugger, @typescript-eslint/no-explicit-any, jest/no-disabled-tests */
+            debugger;
+        ",
     ];
 
     let fail = vec![
@@ -282,7 +494,97 @@ fn test() {
             */
             debugger;
         ",
+        // A rule re-enabled inside `eslint-disable`
+        "
+            /* eslint-disable */
+            /* eslint-enable no-debugger */
+            debugger;
+        ",
+        // Rule names are matched exactly
+        "
+            // eslint-disable-next-line no-debugger-foo
+            debugger;
+
+            // eslint-disable-next-line no-debug
+            debugger;
+        ",
+        "
+            // eslint-disable-next-line @typescript-eslint/no-debugger
+            debugger;
+        ",
+        // Not a directive
+        "
+            // eslint-disabled
+            debugger;
+        ",
+        // Only the next line is disabled
+        "// eslint-disable-next-line\nlet x = 1;\n\ndebugger;",
+        "/* eslint-disable-next-line */\nlet x = 1;\n\ndebugger;",
     ];
 
     Tester::new_without_config("no-debugger", pass, fail).test();
 }
+
+#[test]
+fn test_unused() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "debugger; // eslint-disable-line",
+        "debugger; // eslint-disable-line no-debugger",
+        "
+            /* eslint-disable no-debugger */
+            debugger;
+            /* eslint-enable no-debugger */
+        ",
+        "
+            // eslint-disable-next-line no-debugger -- Here's a description
+            debugger;
+        ",
+    ];
+
+    let fail = vec![
+        "// eslint-disable-line",
+        "// eslint-disable-line no-debugger",
+        "
+            /* eslint-disable no-debugger */
+            /* eslint-enable no-debugger */
+            debugger;
+        ",
+        // The second `eslint-disable` is redundant
+        "
+            /* eslint-disable */
+            /* eslint-disable */
+            debugger;
+        ",
+        "debugger; // eslint-disable-line no-alert, no-debugger",
+        // `debugger` is not on the next line
+        "/* eslint-disable-next-line no-debugger */\nlet x = 1;\n\ndebugger;",
+    ];
+
+    let fix = vec![
+        ("foo(); // eslint-disable-line", "foo(); ", None),
+        ("foo(); // eslint-disable-line\nbar();", "foo(); \nbar();", None),
+        ("foo(); /* eslint-disable-line no-debugger */", "foo(); ", None),
+        (
+            "debugger; // eslint-disable-line no-alert, no-debugger",
+            "debugger; // eslint-disable-line no-debugger",
+            None,
+        ),
+        (
+            "debugger; // eslint-disable-line no-debugger, no-alert",
+            "debugger; // eslint-disable-line no-debugger",
+            None,
+        ),
+        (
+            "debugger; // eslint-disable-line no-debugger, no-alert -- Here's a description",
+            "debugger; // eslint-disable-line no-debugger -- Here's a description",
+            None,
+        ),
+    ];
+
+    let mut tester =
+        Tester::new_without_config("no-debugger", pass, fail).with_report_unused_directives();
+    tester.test();
+    tester.test_fix(fix);
+}
@@ -1,21 +1,47 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, Severity},
+    Error,
+};
 use oxc_span::Span;
 
+use crate::RuleInfo;
+
+/// Whether a fix can be applied without the user reviewing it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FixKind {
+    /// Keeps the behavior of the code, applied by `--fix`
+    #[default]
+    Safe,
+    /// May change the behavior of the code, only applied when opted in by `--fix-suggestions`
+    Suggestion,
+}
+
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
+    pub kind: FixKind,
 }
 
 impl<'a> Fix<'a> {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), span }
+        Self { content: Cow::Borrowed(""), span, kind: FixKind::Safe }
     }
 
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), span }
+        Self { content: content.into(), span, kind: FixKind::Safe }
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn into_owned(self) -> Fix<'static> {
+        Fix { content: Cow::Owned(self.content.into_owned()), span: self.span, kind: self.kind }
     }
 }
 
@@ -23,15 +49,30 @@ impl<'a> Fix<'a> {
 pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
+    /// The messages which were not fixed, sorted by position.
+    /// Messages which still have a fix were skipped because they overlap with an applied fix.
     pub messages: Vec<Message<'a>>,
 }
 
+impl<'a> FixResult<'a> {
+    /// Messages whose fix was not applied because it overlaps with another fix.
+    pub fn skipped_fixes(&self) -> impl Iterator<Item = &Message<'a>> {
+        self.messages.iter().filter(|message| message.fix.is_some())
+    }
+}
+
+/// Maximum number of passes of [`Fixer::fix_until_stable`], the same as ESLint.
+/// Fixes of a pass can produce new problems with fixes, which may never end.
+pub const MAX_FIX_PASSES: usize = 10;
+
 #[derive(Debug)]
 pub struct Message<'a> {
     pub error: Error,
     start: u32,
     end: u32,
     pub fix: Option<Fix<'a>>,
+    /// The rule which reported this message, `None` for configuration errors.
+    pub rule: Option<RuleInfo>,
     fixed: bool,
 }
 
@@ -45,7 +86,13 @@ impl<'a> Message<'a> {
             .iter()
             .max_by_key(|span| span.offset() + span.len())
             .map_or(0, |span| (span.offset() + span.len()) as u32);
-        Self { error, start, end, fix, fixed: false }
+        Self { error, start, end, fix, rule: None, fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_rule(mut self, rule: RuleInfo) -> Self {
+        self.rule = Some(rule);
+        self
     }
 
     pub fn start(&self) -> u32 {
@@ -55,6 +102,71 @@ impl<'a> Message<'a> {
     pub fn end(&self) -> u32 {
         self.end
     }
+
+    /// Move the message by `offset`, for a script embedded at `offset` of a file.
+    #[must_use]
+    pub fn with_offset(self, offset: u32) -> Self {
+        if offset == 0 {
+            return self;
+        }
+        let Self { error, start, end, fix, rule, fixed } = self;
+        let error = Error::new(OffsetDiagnostic { error, offset: offset as usize });
+        let fix = fix.map(|fix| Fix {
+            span: Span::new(fix.span.start + offset, fix.span.end + offset),
+            ..fix
+        });
+        Self { error, start: start + offset, end: end + offset, fix, rule, fixed }
+    }
+
+    /// Detach the message from the source text and AST its fix may borrow from.
+    pub fn into_owned(self) -> Message<'static> {
+        let Self { error, start, end, fix, rule, fixed } = self;
+        Message { error, start, end, fix: fix.map(Fix::into_owned), rule, fixed }
+    }
+}
+
+/// A diagnostic whose labels are moved by `offset`, see [`Message::with_offset`].
+#[derive(Debug)]
+struct OffsetDiagnostic {
+    error: Error,
+    offset: usize,
+}
+
+impl fmt::Display for OffsetDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for OffsetDiagnostic {}
+
+impl Diagnostic for OffsetDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.error.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self.error.labels()?;
+        Some(Box::new(labels.map(|label| {
+            LabeledSpan::new(
+                label.label().map(ToString::to_string),
+                label.offset() + self.offset,
+                label.len(),
+            )
+        })))
+    }
 }
 
 /// The fixer of the code.
@@ -85,7 +197,7 @@ impl<'a> Fixer<'a> {
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
+            let Fix { content, span, .. } = m.fix.as_ref().unwrap();
             let start = span.start;
             let end = span.end;
             if start > end {
@@ -110,6 +222,31 @@ impl<'a> Fixer<'a> {
         messages.sort_by_key(|m| (m.start, m.end));
         return FixResult { fixed, fixed_code: Cow::Owned(output), messages };
     }
+
+    /// Fix `source_text` like ESLint: after each pass of [`Fixer::fix`] the fixed code is linted again by `lint`,
+    /// until no more fixes can be applied or [`MAX_FIX_PASSES`] is reached.
+    /// Fixes skipped due to overlap in one pass are retried against the code of the next pass.
+    ///
+    /// The returned messages are the ones of the last lint, relative to the returned code.
+    pub fn fix_until_stable<F>(source_text: &str, mut lint: F) -> FixResult<'static>
+    where
+        F: FnMut(&str) -> Vec<Message<'static>>,
+    {
+        let mut fixed = false;
+        let mut code = source_text.to_string();
+        let mut messages = lint(&code);
+        for _ in 0..MAX_FIX_PASSES {
+            let result = Fixer::new(&code, messages).fix();
+            if !result.fixed {
+                messages = result.messages.into_iter().map(Message::into_owned).collect();
+                break;
+            }
+            fixed = true;
+            code = result.fixed_code.into_owned();
+            messages = lint(&code);
+        }
+        FixResult { fixed, fixed_code: Cow::Owned(code), messages }
+    }
 }
 
 #[cfg(test)]
@@ -120,38 +257,44 @@ mod test {
     use oxc_diagnostics::{thiserror::Error, Error};
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixKind, FixResult, Fixer, Message, MAX_FIX_PASSES};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("End")]
     struct InsertAtEnd;
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
+    const INSERT_AT_END: Fix =
+        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Start")]
     struct InsertAtStart;
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
+    const INSERT_AT_START: Fix =
+        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiply")]
     struct InsertAtMiddle;
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
+    const INSERT_AT_MIDDLE: Fix =
+        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("foo")]
     struct ReplaceId;
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
+    const REPLACE_ID: Fix =
+        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), kind: FixKind::Safe };
     #[derive(Debug, Error, Diagnostic)]
     #[error("let")]
     struct ReplaceVar;
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
+    const REPLACE_VAR: Fix =
+        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("5")]
     struct ReplaceNum;
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const REPLACE_NUM: Fix =
+        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removestart")]
@@ -171,7 +314,8 @@ mod test {
     #[derive(Debug, Error, Diagnostic)]
     #[error("reversed range")]
     struct ReverseRange;
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Fix =
+        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("nofix")]
@@ -328,6 +472,7 @@ mod test {
         assert_eq!(result.fixed_code, TEST_CODE.replace("answer", "foo"));
         assert_eq!(result.messages.len(), 1);
         assert_eq!(result.messages[0].error.to_string(), "removemiddle");
+        assert_eq!(result.skipped_fixes().count(), 1);
         assert!(result.fixed);
     }
 
@@ -392,4 +537,61 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn fix_until_stable_applies_overlapping_fixes_in_later_passes() {
+        // Every `var` is fixed by rewriting the rest of the code, so only one fix applies per pass.
+        let lint = |source_text: &str| {
+            source_text
+                .match_indices("var")
+                .map(|(start, _)| {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let span = Span::new(start as u32, source_text.len() as u32);
+                    let content = source_text[start..].replacen("var", "let", 1);
+                    create_message(NoFix(span), Some(Fix::new(content, span)))
+                })
+                .collect()
+        };
+        let result = Fixer::fix_until_stable("var a; var b; var c;", lint);
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "let a; let b; let c;");
+        assert!(result.messages.is_empty());
+    }
+
+    #[test]
+    fn fix_until_stable_stops_after_max_passes() {
+        let mut passes = 0;
+        let lint = |source_text: &str| {
+            passes += 1;
+            #[allow(clippy::cast_possible_truncation)]
+            let end = Span::new(source_text.len() as u32, source_text.len() as u32);
+            vec![create_message(NoFix(end), Some(Fix::new("x", end)))]
+        };
+        let result = Fixer::fix_until_stable(TEST_CODE, lint);
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, format!("{TEST_CODE}{}", "x".repeat(MAX_FIX_PASSES)));
+        assert_eq!(passes, MAX_FIX_PASSES + 1);
+        assert_eq!(result.skipped_fixes().count(), 1);
+    }
+
+    #[test]
+    fn fix_until_stable_without_fixes() {
+        let result = Fixer::fix_until_stable(TEST_CODE, |_| {
+            vec![create_message(NoFix(Span::new(0, 3)), None)]
+        });
+        assert!(!result.fixed);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert_eq!(result.messages.len(), 1);
+    }
+
+    #[test]
+    fn with_offset() {
+        let message = create_message(RemoveMiddle(Span::new(5, 10)), Some(REMOVE_MIDDLE));
+        let message = message.with_offset(100);
+        assert_eq!((message.start(), message.end()), (105, 110));
+        assert_eq!(message.fix.as_ref().unwrap().span, Span::new(105, 110));
+        assert_eq!(message.error.to_string(), "removemiddle");
+        let label = message.error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (105, 5));
+    }
 }
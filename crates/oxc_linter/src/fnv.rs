@@ -0,0 +1,67 @@
+use std::hash::Hasher;
+
+/// 64-bit FNV-1a, which is stable across platforms and Rust versions unlike `DefaultHasher`,
+/// for hashes written to files, e.g. the configuration hash of the cache of the CLI.
+///
+/// Integers are hashed as little-endian 64-bit values, so the hash does not depend on the platform.
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write_u64(u64::from(n));
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write_u64(u64::from(n));
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::hash::{Hash, Hasher};
+
+    use super::FnvHasher;
+
+    #[test]
+    fn stable() {
+        let hash = |value: &dyn Fn(&mut FnvHasher)| {
+            let mut hasher = FnvHasher::default();
+            value(&mut hasher);
+            hasher.finish()
+        };
+        // Test vectors of FNV-1a
+        assert_eq!(hash(&|h| h.write(b"")), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(&|h| h.write(b"a")), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash(&|h| 1usize.hash(h)), hash(&|h| 1u64.hash(h)));
+    }
+}
@@ -2,9 +2,12 @@
 //! Each global is given a value of true or false.
 //! A value of true indicates that the variable may be overwritten.
 //! A value of false indicates that the variable should be considered read-only.
+//!
+//! The environments are enabled by `env` in configuration files and `/* eslint-env */` comments.
 
 use phf::{phf_map, Map};
 
+/// ECMAScript builtins, always available
 pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "AggregateError" => false,
     "Array" => false,
@@ -57,7 +60,7 @@ pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "SharedArrayBuffer" => false,
     "String" => false,
     "Symbol" => false,
-    "Diagnostic" => false,
+    "SyntaxError" => false,
     "toLocaleString" => false,
     "toString" => false,
     "TypeError" => false,
@@ -73,3 +76,1222 @@ pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "WeakRef" => false,
     "WeakSet" => false
 };
+
+/// Environments by their name in `env`.
+/// The ECMAScript versions are accepted for compatibility, their globals are all part of [`BUILTINS`].
+pub const ENVIRONMENTS: Map<&'static str, Map<&'static str, bool>> = phf_map! {
+    "builtin" => BUILTINS,
+    "es6" => BUILTINS,
+    "es2015" => BUILTINS,
+    "es2016" => BUILTINS,
+    "es2017" => BUILTINS,
+    "es2018" => BUILTINS,
+    "es2019" => BUILTINS,
+    "es2020" => BUILTINS,
+    "es2021" => BUILTINS,
+    "es2022" => BUILTINS,
+    "browser" => BROWSER,
+    "node" => NODE,
+    "commonjs" => COMMONJS,
+    "shared-node-browser" => SHARED_NODE_BROWSER,
+    "worker" => WORKER,
+    "serviceworker" => SERVICEWORKER,
+    "jest" => JEST,
+    "mocha" => MOCHA,
+    "jasmine" => JASMINE,
+    "amd" => AMD
+};
+
+/// `browser` environment
+pub const BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "AnalyserNode" => false,
+    "Animation" => false,
+    "AnimationEffectReadOnly" => false,
+    "AnimationEffectTiming" => false,
+    "AnimationEffectTimingReadOnly" => false,
+    "AnimationEvent" => false,
+    "AnimationPlaybackEvent" => false,
+    "AnimationTimeline" => false,
+    "ApplicationCache" => false,
+    "applicationCache" => false,
+    "ApplicationCacheErrorEvent" => false,
+    "atob" => false,
+    "Attr" => false,
+    "Audio" => false,
+    "AudioBuffer" => false,
+    "AudioBufferSourceNode" => false,
+    "AudioContext" => false,
+    "AudioDestinationNode" => false,
+    "AudioListener" => false,
+    "AudioNode" => false,
+    "AudioParam" => false,
+    "AudioProcessingEvent" => false,
+    "AudioScheduledSourceNode" => false,
+    "AudioWorkletGlobalScope" => false,
+    "AudioWorkletNode" => false,
+    "AudioWorkletProcessor" => false,
+    "BarProp" => false,
+    "BaseAudioContext" => false,
+    "BatteryManager" => false,
+    "BeforeUnloadEvent" => false,
+    "BiquadFilterNode" => false,
+    "Blob" => false,
+    "BlobEvent" => false,
+    "blur" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "BudgetService" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "CacheStorage" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "CanvasCaptureMediaStreamTrack" => false,
+    "CanvasGradient" => false,
+    "CanvasPattern" => false,
+    "CanvasRenderingContext2D" => false,
+    "ChannelMergerNode" => false,
+    "ChannelSplitterNode" => false,
+    "CharacterData" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "clientInformation" => false,
+    "ClipboardEvent" => false,
+    "ClipboardItem" => false,
+    "close" => false,
+    "closed" => false,
+    "CloseEvent" => false,
+    "Comment" => false,
+    "CompositionEvent" => false,
+    "CompressionStream" => false,
+    "confirm" => false,
+    "console" => false,
+    "ConstantSourceNode" => false,
+    "ConvolverNode" => false,
+    "CountQueuingStrategy" => false,
+    "createImageBitmap" => false,
+    "Credential" => false,
+    "CredentialsContainer" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CSS" => false,
+    "CSSConditionRule" => false,
+    "CSSFontFaceRule" => false,
+    "CSSGroupingRule" => false,
+    "CSSImportRule" => false,
+    "CSSKeyframeRule" => false,
+    "CSSKeyframesRule" => false,
+    "CSSMatrixComponent" => false,
+    "CSSMediaRule" => false,
+    "CSSNamespaceRule" => false,
+    "CSSPageRule" => false,
+    "CSSPerspective" => false,
+    "CSSRotate" => false,
+    "CSSRule" => false,
+    "CSSRuleList" => false,
+    "CSSScale" => false,
+    "CSSSkew" => false,
+    "CSSSkewX" => false,
+    "CSSSkewY" => false,
+    "CSSStyleDeclaration" => false,
+    "CSSStyleRule" => false,
+    "CSSStyleSheet" => false,
+    "CSSSupportsRule" => false,
+    "CSSTransformValue" => false,
+    "CSSTranslate" => false,
+    "CustomElementRegistry" => false,
+    "customElements" => false,
+    "CustomEvent" => false,
+    "DataTransfer" => false,
+    "DataTransferItem" => false,
+    "DataTransferItemList" => false,
+    "DecompressionStream" => false,
+    "defaultStatus" => false,
+    "defaultstatus" => false,
+    "DelayNode" => false,
+    "DeviceMotionEvent" => false,
+    "DeviceOrientationEvent" => false,
+    "devicePixelRatio" => false,
+    "dispatchEvent" => false,
+    "Document" => false,
+    "document" => false,
+    "DocumentFragment" => false,
+    "DocumentType" => false,
+    "DOMError" => false,
+    "DOMException" => false,
+    "DOMImplementation" => false,
+    "DOMMatrix" => false,
+    "DOMMatrixReadOnly" => false,
+    "DOMParser" => false,
+    "DOMPoint" => false,
+    "DOMPointReadOnly" => false,
+    "DOMQuad" => false,
+    "DOMRect" => false,
+    "DOMRectList" => false,
+    "DOMRectReadOnly" => false,
+    "DOMStringList" => false,
+    "DOMStringMap" => false,
+    "DOMTokenList" => false,
+    "DragEvent" => false,
+    "DynamicsCompressorNode" => false,
+    "Element" => false,
+    "ErrorEvent" => false,
+    "Event" => false,
+    "event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "external" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "find" => false,
+    "focus" => false,
+    "FocusEvent" => false,
+    "FontFace" => false,
+    "FontFaceSetLoadEvent" => false,
+    "FormData" => false,
+    "FormDataEvent" => false,
+    "frameElement" => false,
+    "frames" => false,
+    "GainNode" => false,
+    "Gamepad" => false,
+    "GamepadButton" => false,
+    "GamepadEvent" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "HashChangeEvent" => false,
+    "Headers" => false,
+    "History" => false,
+    "history" => false,
+    "HTMLAllCollection" => false,
+    "HTMLAnchorElement" => false,
+    "HTMLAreaElement" => false,
+    "HTMLAudioElement" => false,
+    "HTMLBaseElement" => false,
+    "HTMLBodyElement" => false,
+    "HTMLBRElement" => false,
+    "HTMLButtonElement" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLCollection" => false,
+    "HTMLContentElement" => false,
+    "HTMLDataElement" => false,
+    "HTMLDataListElement" => false,
+    "HTMLDetailsElement" => false,
+    "HTMLDialogElement" => false,
+    "HTMLDirectoryElement" => false,
+    "HTMLDivElement" => false,
+    "HTMLDListElement" => false,
+    "HTMLDocument" => false,
+    "HTMLElement" => false,
+    "HTMLEmbedElement" => false,
+    "HTMLFieldSetElement" => false,
+    "HTMLFontElement" => false,
+    "HTMLFormControlsCollection" => false,
+    "HTMLFormElement" => false,
+    "HTMLFrameElement" => false,
+    "HTMLFrameSetElement" => false,
+    "HTMLHeadElement" => false,
+    "HTMLHeadingElement" => false,
+    "HTMLHRElement" => false,
+    "HTMLHtmlElement" => false,
+    "HTMLIFrameElement" => false,
+    "HTMLImageElement" => false,
+    "HTMLInputElement" => false,
+    "HTMLLabelElement" => false,
+    "HTMLLegendElement" => false,
+    "HTMLLIElement" => false,
+    "HTMLLinkElement" => false,
+    "HTMLMapElement" => false,
+    "HTMLMarqueeElement" => false,
+    "HTMLMediaElement" => false,
+    "HTMLMenuElement" => false,
+    "HTMLMetaElement" => false,
+    "HTMLMeterElement" => false,
+    "HTMLModElement" => false,
+    "HTMLObjectElement" => false,
+    "HTMLOListElement" => false,
+    "HTMLOptGroupElement" => false,
+    "HTMLOptionElement" => false,
+    "HTMLOptionsCollection" => false,
+    "HTMLOutputElement" => false,
+    "HTMLParagraphElement" => false,
+    "HTMLParamElement" => false,
+    "HTMLPictureElement" => false,
+    "HTMLPreElement" => false,
+    "HTMLProgressElement" => false,
+    "HTMLQuoteElement" => false,
+    "HTMLScriptElement" => false,
+    "HTMLSelectElement" => false,
+    "HTMLShadowElement" => false,
+    "HTMLSlotElement" => false,
+    "HTMLSourceElement" => false,
+    "HTMLSpanElement" => false,
+    "HTMLStyleElement" => false,
+    "HTMLTableCaptionElement" => false,
+    "HTMLTableCellElement" => false,
+    "HTMLTableColElement" => false,
+    "HTMLTableElement" => false,
+    "HTMLTableRowElement" => false,
+    "HTMLTableSectionElement" => false,
+    "HTMLTemplateElement" => false,
+    "HTMLTextAreaElement" => false,
+    "HTMLTimeElement" => false,
+    "HTMLTitleElement" => false,
+    "HTMLTrackElement" => false,
+    "HTMLUListElement" => false,
+    "HTMLUnknownElement" => false,
+    "HTMLVideoElement" => false,
+    "IDBCursor" => false,
+    "IDBCursorWithValue" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBOpenDBRequest" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "IDBVersionChangeEvent" => false,
+    "IdleDeadline" => false,
+    "IIRFilterNode" => false,
+    "Image" => false,
+    "ImageBitmap" => false,
+    "ImageBitmapRenderingContext" => false,
+    "ImageCapture" => false,
+    "ImageData" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "InputEvent" => false,
+    "IntersectionObserver" => false,
+    "IntersectionObserverEntry" => false,
+    "Intl" => false,
+    "isSecureContext" => false,
+    "KeyboardEvent" => false,
+    "KeyframeEffect" => false,
+    "KeyframeEffectReadOnly" => false,
+    "length" => false,
+    "localStorage" => false,
+    "Location" => false,
+    "location" => true,
+    "locationbar" => false,
+    "matchMedia" => false,
+    "MediaDeviceInfo" => false,
+    "MediaDevices" => false,
+    "MediaElementAudioSourceNode" => false,
+    "MediaEncryptedEvent" => false,
+    "MediaError" => false,
+    "MediaKeyMessageEvent" => false,
+    "MediaKeySession" => false,
+    "MediaKeyStatusMap" => false,
+    "MediaKeySystemAccess" => false,
+    "MediaList" => false,
+    "MediaMetadata" => false,
+    "MediaQueryList" => false,
+    "MediaQueryListEvent" => false,
+    "MediaRecorder" => false,
+    "MediaSettingsRange" => false,
+    "MediaSource" => false,
+    "MediaStream" => false,
+    "MediaStreamAudioDestinationNode" => false,
+    "MediaStreamAudioSourceNode" => false,
+    "MediaStreamEvent" => false,
+    "MediaStreamTrack" => false,
+    "MediaStreamTrackEvent" => false,
+    "menubar" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "MIDIAccess" => false,
+    "MIDIConnectionEvent" => false,
+    "MIDIInput" => false,
+    "MIDIInputMap" => false,
+    "MIDIMessageEvent" => false,
+    "MIDIOutput" => false,
+    "MIDIOutputMap" => false,
+    "MIDIPort" => false,
+    "MimeType" => false,
+    "MimeTypeArray" => false,
+    "MouseEvent" => false,
+    "moveBy" => false,
+    "moveTo" => false,
+    "MutationEvent" => false,
+    "MutationObserver" => false,
+    "MutationRecord" => false,
+    "name" => false,
+    "NamedNodeMap" => false,
+    "NavigationPreloadManager" => false,
+    "Navigator" => false,
+    "navigator" => false,
+    "NavigatorUAData" => false,
+    "NetworkInformation" => false,
+    "Node" => false,
+    "NodeFilter" => false,
+    "NodeIterator" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "OfflineAudioCompletionEvent" => false,
+    "OfflineAudioContext" => false,
+    "offscreenBuffering" => false,
+    "OffscreenCanvas" => false,
+    "OffscreenCanvasRenderingContext2D" => false,
+    "onabort" => true,
+    "onafterprint" => true,
+    "onanimationend" => true,
+    "onanimationiteration" => true,
+    "onanimationstart" => true,
+    "onappinstalled" => true,
+    "onauxclick" => true,
+    "onbeforeinstallprompt" => true,
+    "onbeforeprint" => true,
+    "onbeforeunload" => true,
+    "onblur" => true,
+    "oncancel" => true,
+    "oncanplay" => true,
+    "oncanplaythrough" => true,
+    "onchange" => true,
+    "onclick" => true,
+    "onclose" => true,
+    "oncontextmenu" => true,
+    "oncuechange" => true,
+    "ondblclick" => true,
+    "ondevicemotion" => true,
+    "ondeviceorientation" => true,
+    "ondeviceorientationabsolute" => true,
+    "ondrag" => true,
+    "ondragend" => true,
+    "ondragenter" => true,
+    "ondragleave" => true,
+    "ondragover" => true,
+    "ondragstart" => true,
+    "ondrop" => true,
+    "ondurationchange" => true,
+    "onemptied" => true,
+    "onended" => true,
+    "onerror" => true,
+    "onfocus" => true,
+    "ongotpointercapture" => true,
+    "onhashchange" => true,
+    "oninput" => true,
+    "oninvalid" => true,
+    "onkeydown" => true,
+    "onkeypress" => true,
+    "onkeyup" => true,
+    "onlanguagechange" => true,
+    "onload" => true,
+    "onloadeddata" => true,
+    "onloadedmetadata" => true,
+    "onloadstart" => true,
+    "onlostpointercapture" => true,
+    "onmessage" => true,
+    "onmessageerror" => true,
+    "onmousedown" => true,
+    "onmouseenter" => true,
+    "onmouseleave" => true,
+    "onmousemove" => true,
+    "onmouseout" => true,
+    "onmouseover" => true,
+    "onmouseup" => true,
+    "onmousewheel" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onpagehide" => true,
+    "onpageshow" => true,
+    "onpause" => true,
+    "onplay" => true,
+    "onplaying" => true,
+    "onpointercancel" => true,
+    "onpointerdown" => true,
+    "onpointerenter" => true,
+    "onpointerleave" => true,
+    "onpointermove" => true,
+    "onpointerout" => true,
+    "onpointerover" => true,
+    "onpointerup" => true,
+    "onpopstate" => true,
+    "onprogress" => true,
+    "onratechange" => true,
+    "onrejectionhandled" => true,
+    "onreset" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "onsearch" => true,
+    "onseeked" => true,
+    "onseeking" => true,
+    "onselect" => true,
+    "onstalled" => true,
+    "onstorage" => true,
+    "onsubmit" => true,
+    "onsuspend" => true,
+    "ontimeupdate" => true,
+    "ontoggle" => true,
+    "ontransitionend" => true,
+    "onunhandledrejection" => true,
+    "onunload" => true,
+    "onvolumechange" => true,
+    "onwaiting" => true,
+    "onwheel" => true,
+    "open" => false,
+    "openDatabase" => false,
+    "opener" => false,
+    "Option" => false,
+    "origin" => false,
+    "OscillatorNode" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "OverconstrainedError" => false,
+    "PageTransitionEvent" => false,
+    "pageXOffset" => false,
+    "pageYOffset" => false,
+    "PannerNode" => false,
+    "parent" => false,
+    "Path2D" => false,
+    "PaymentAddress" => false,
+    "PaymentRequest" => false,
+    "PaymentRequestUpdateEvent" => false,
+    "PaymentResponse" => false,
+    "Performance" => false,
+    "performance" => false,
+    "PerformanceEntry" => false,
+    "PerformanceLongTaskTiming" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceNavigation" => false,
+    "PerformanceNavigationTiming" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "PerformancePaintTiming" => false,
+    "PerformanceResourceTiming" => false,
+    "PerformanceTiming" => false,
+    "PeriodicWave" => false,
+    "Permissions" => false,
+    "PermissionStatus" => false,
+    "personalbar" => false,
+    "PhotoCapabilities" => false,
+    "Plugin" => false,
+    "PluginArray" => false,
+    "PointerEvent" => false,
+    "PopStateEvent" => false,
+    "postMessage" => false,
+    "Presentation" => false,
+    "PresentationAvailability" => false,
+    "PresentationConnection" => false,
+    "PresentationConnectionAvailableEvent" => false,
+    "PresentationConnectionCloseEvent" => false,
+    "PresentationConnectionList" => false,
+    "PresentationReceiver" => false,
+    "PresentationRequest" => false,
+    "print" => false,
+    "ProcessingInstruction" => false,
+    "ProgressEvent" => false,
+    "PromiseRejectionEvent" => false,
+    "prompt" => false,
+    "PushManager" => false,
+    "PushSubscription" => false,
+    "PushSubscriptionOptions" => false,
+    "queueMicrotask" => false,
+    "RadioNodeList" => false,
+    "Range" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "registerProcessor" => false,
+    "RemotePlayback" => false,
+    "removeEventListener" => false,
+    "reportError" => false,
+    "Request" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "resizeBy" => false,
+    "ResizeObserver" => false,
+    "ResizeObserverEntry" => false,
+    "resizeTo" => false,
+    "Response" => false,
+    "RTCCertificate" => false,
+    "RTCDataChannel" => false,
+    "RTCDataChannelEvent" => false,
+    "RTCDtlsTransport" => false,
+    "RTCIceCandidate" => false,
+    "RTCIceGatherer" => false,
+    "RTCIceTransport" => false,
+    "RTCPeerConnection" => false,
+    "RTCPeerConnectionIceEvent" => false,
+    "RTCRtpContributingSource" => false,
+    "RTCRtpReceiver" => false,
+    "RTCRtpSender" => false,
+    "RTCSctpTransport" => false,
+    "RTCSessionDescription" => false,
+    "RTCStatsReport" => false,
+    "RTCTrackEvent" => false,
+    "Screen" => false,
+    "screen" => false,
+    "screenLeft" => false,
+    "ScreenOrientation" => false,
+    "screenTop" => false,
+    "screenX" => false,
+    "screenY" => false,
+    "ScriptProcessorNode" => false,
+    "scroll" => false,
+    "scrollbars" => false,
+    "scrollBy" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "SecurityPolicyViolationEvent" => false,
+    "Selection" => false,
+    "self" => false,
+    "ServiceWorker" => false,
+    "ServiceWorkerContainer" => false,
+    "ServiceWorkerRegistration" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "ShadowRoot" => false,
+    "SharedWorker" => false,
+    "SourceBuffer" => false,
+    "SourceBufferList" => false,
+    "speechSynthesis" => false,
+    "SpeechSynthesisEvent" => false,
+    "SpeechSynthesisUtterance" => false,
+    "StaticRange" => false,
+    "status" => false,
+    "statusbar" => false,
+    "StereoPannerNode" => false,
+    "stop" => false,
+    "Storage" => false,
+    "StorageEvent" => false,
+    "StorageManager" => false,
+    "structuredClone" => false,
+    "styleMedia" => false,
+    "StyleSheet" => false,
+    "StyleSheetList" => false,
+    "SubmitEvent" => false,
+    "SubtleCrypto" => false,
+    "SVGAElement" => false,
+    "SVGAngle" => false,
+    "SVGAnimatedAngle" => false,
+    "SVGAnimatedBoolean" => false,
+    "SVGAnimatedEnumeration" => false,
+    "SVGAnimatedInteger" => false,
+    "SVGAnimatedLength" => false,
+    "SVGAnimatedLengthList" => false,
+    "SVGAnimatedNumber" => false,
+    "SVGAnimatedNumberList" => false,
+    "SVGAnimatedPreserveAspectRatio" => false,
+    "SVGAnimatedRect" => false,
+    "SVGAnimatedString" => false,
+    "SVGAnimatedTransformList" => false,
+    "SVGAnimateElement" => false,
+    "SVGAnimateMotionElement" => false,
+    "SVGAnimateTransformElement" => false,
+    "SVGAnimationElement" => false,
+    "SVGCircleElement" => false,
+    "SVGClipPathElement" => false,
+    "SVGComponentTransferFunctionElement" => false,
+    "SVGDefsElement" => false,
+    "SVGDescElement" => false,
+    "SVGDiscardElement" => false,
+    "SVGElement" => false,
+    "SVGEllipseElement" => false,
+    "SVGFEBlendElement" => false,
+    "SVGFEColorMatrixElement" => false,
+    "SVGFEComponentTransferElement" => false,
+    "SVGFECompositeElement" => false,
+    "SVGFEConvolveMatrixElement" => false,
+    "SVGFEDiffuseLightingElement" => false,
+    "SVGFEDisplacementMapElement" => false,
+    "SVGFEDistantLightElement" => false,
+    "SVGFEDropShadowElement" => false,
+    "SVGFEFloodElement" => false,
+    "SVGFEFuncAElement" => false,
+    "SVGFEFuncBElement" => false,
+    "SVGFEFuncGElement" => false,
+    "SVGFEFuncRElement" => false,
+    "SVGFEGaussianBlurElement" => false,
+    "SVGFEImageElement" => false,
+    "SVGFEMergeElement" => false,
+    "SVGFEMergeNodeElement" => false,
+    "SVGFEMorphologyElement" => false,
+    "SVGFEOffsetElement" => false,
+    "SVGFEPointLightElement" => false,
+    "SVGFESpecularLightingElement" => false,
+    "SVGFESpotLightElement" => false,
+    "SVGFETileElement" => false,
+    "SVGFETurbulenceElement" => false,
+    "SVGFilterElement" => false,
+    "SVGForeignObjectElement" => false,
+    "SVGGElement" => false,
+    "SVGGeometryElement" => false,
+    "SVGGradientElement" => false,
+    "SVGGraphicsElement" => false,
+    "SVGImageElement" => false,
+    "SVGLength" => false,
+    "SVGLengthList" => false,
+    "SVGLinearGradientElement" => false,
+    "SVGLineElement" => false,
+    "SVGMarkerElement" => false,
+    "SVGMaskElement" => false,
+    "SVGMatrix" => false,
+    "SVGMetadataElement" => false,
+    "SVGMPathElement" => false,
+    "SVGNumber" => false,
+    "SVGNumberList" => false,
+    "SVGPathElement" => false,
+    "SVGPatternElement" => false,
+    "SVGPoint" => false,
+    "SVGPointList" => false,
+    "SVGPolygonElement" => false,
+    "SVGPolylineElement" => false,
+    "SVGPreserveAspectRatio" => false,
+    "SVGRadialGradientElement" => false,
+    "SVGRect" => false,
+    "SVGRectElement" => false,
+    "SVGScriptElement" => false,
+    "SVGSetElement" => false,
+    "SVGStopElement" => false,
+    "SVGStringList" => false,
+    "SVGStyleElement" => false,
+    "SVGSVGElement" => false,
+    "SVGSwitchElement" => false,
+    "SVGSymbolElement" => false,
+    "SVGTextContentElement" => false,
+    "SVGTextElement" => false,
+    "SVGTextPathElement" => false,
+    "SVGTextPositioningElement" => false,
+    "SVGTitleElement" => false,
+    "SVGTransform" => false,
+    "SVGTransformList" => false,
+    "SVGTSpanElement" => false,
+    "SVGUnitTypes" => false,
+    "SVGUseElement" => false,
+    "SVGViewElement" => false,
+    "TaskAttributionTiming" => false,
+    "Text" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TextEvent" => false,
+    "TextMetrics" => false,
+    "TextTrack" => false,
+    "TextTrackCue" => false,
+    "TextTrackCueList" => false,
+    "TextTrackList" => false,
+    "TimeRanges" => false,
+    "toolbar" => false,
+    "top" => false,
+    "Touch" => false,
+    "TouchEvent" => false,
+    "TouchList" => false,
+    "TrackEvent" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "TransitionEvent" => false,
+    "TreeWalker" => false,
+    "UIEvent" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "ValidityState" => false,
+    "VisualViewport" => false,
+    "visualViewport" => false,
+    "VTTCue" => false,
+    "WaveShaperNode" => false,
+    "WebAssembly" => false,
+    "WebGL2RenderingContext" => false,
+    "WebGLActiveInfo" => false,
+    "WebGLBuffer" => false,
+    "WebGLContextEvent" => false,
+    "WebGLFramebuffer" => false,
+    "WebGLProgram" => false,
+    "WebGLQuery" => false,
+    "WebGLRenderbuffer" => false,
+    "WebGLRenderingContext" => false,
+    "WebGLSampler" => false,
+    "WebGLShader" => false,
+    "WebGLShaderPrecisionFormat" => false,
+    "WebGLSync" => false,
+    "WebGLTexture" => false,
+    "WebGLTransformFeedback" => false,
+    "WebGLUniformLocation" => false,
+    "WebGLVertexArrayObject" => false,
+    "WebSocket" => false,
+    "WheelEvent" => false,
+    "Window" => false,
+    "window" => false,
+    "Worker" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false,
+    "XMLDocument" => false,
+    "XMLHttpRequest" => false,
+    "XMLHttpRequestEventTarget" => false,
+    "XMLHttpRequestUpload" => false,
+    "XMLSerializer" => false,
+    "XPathEvaluator" => false,
+    "XPathExpression" => false,
+    "XPathResult" => false,
+    "XSLTProcessor" => false
+};
+
+/// `node` environment
+pub const NODE: Map<&'static str, bool> = phf_map! {
+    "__dirname" => false,
+    "__filename" => false,
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "Buffer" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "exports" => true,
+    "fetch" => false,
+    "File" => false,
+    "FormData" => false,
+    "global" => false,
+    "Headers" => false,
+    "Intl" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "module" => false,
+    "performance" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "PerformanceResourceTiming" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "Request" => false,
+    "require" => false,
+    "Response" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false
+};
+
+/// `commonjs` environment, the module scope of CommonJS modules
+pub const COMMONJS: Map<&'static str, bool> = phf_map! {
+    "exports" => true,
+    "global" => false,
+    "module" => false,
+    "require" => false
+};
+
+/// `shared-node-browser` environment, the globals of both Node.js and browsers
+pub const SHARED_NODE_BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "Intl" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "performance" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "PerformanceResourceTiming" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "Request" => false,
+    "Response" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false
+};
+
+/// `worker` environment, web workers
+pub const WORKER: Map<&'static str, bool> = phf_map! {
+    "addEventListener" => false,
+    "applicationCache" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => true,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileReaderSync" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "IDBCursor" => false,
+    "IDBCursorWithValue" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBOpenDBRequest" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "IDBVersionChangeEvent" => false,
+    "ImageData" => false,
+    "importScripts" => true,
+    "indexedDB" => false,
+    "location" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "name" => false,
+    "navigator" => false,
+    "Notification" => false,
+    "onclose" => true,
+    "onconnect" => true,
+    "onerror" => true,
+    "onlanguagechange" => true,
+    "onmessage" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onrejectionhandled" => true,
+    "onunhandledrejection" => true,
+    "Performance" => false,
+    "performance" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceNavigation" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "PerformanceResourceTiming" => false,
+    "PerformanceTiming" => false,
+    "postMessage" => true,
+    "Promise" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "removeEventListener" => false,
+    "reportError" => false,
+    "Request" => false,
+    "Response" => false,
+    "self" => true,
+    "ServiceWorkerRegistration" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "Worker" => false,
+    "WorkerGlobalScope" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false,
+    "XMLHttpRequest" => false
+};
+
+/// `serviceworker` environment
+pub const SERVICEWORKER: Map<&'static str, bool> = phf_map! {
+    "addEventListener" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "CacheStorage" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "Client" => false,
+    "Clients" => false,
+    "clients" => false,
+    "close" => true,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "ExtendableEvent" => false,
+    "ExtendableMessageEvent" => false,
+    "fetch" => false,
+    "FetchEvent" => false,
+    "File" => false,
+    "FileReaderSync" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "IDBCursor" => false,
+    "IDBCursorWithValue" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBOpenDBRequest" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "IDBVersionChangeEvent" => false,
+    "ImageData" => false,
+    "importScripts" => true,
+    "indexedDB" => false,
+    "location" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "name" => false,
+    "navigator" => false,
+    "Notification" => false,
+    "onclose" => true,
+    "onconnect" => true,
+    "onerror" => true,
+    "onfetch" => true,
+    "oninstall" => true,
+    "onlanguagechange" => true,
+    "onmessage" => true,
+    "onmessageerror" => true,
+    "onnotificationclick" => true,
+    "onnotificationclose" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onpush" => true,
+    "onpushsubscriptionchange" => true,
+    "onrejectionhandled" => true,
+    "onsync" => true,
+    "onunhandledrejection" => true,
+    "Performance" => false,
+    "performance" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceNavigation" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "PerformanceResourceTiming" => false,
+    "PerformanceTiming" => false,
+    "postMessage" => true,
+    "Promise" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "registration" => false,
+    "removeEventListener" => false,
+    "Request" => false,
+    "Response" => false,
+    "self" => true,
+    "ServiceWorker" => false,
+    "ServiceWorkerContainer" => false,
+    "ServiceWorkerGlobalScope" => false,
+    "ServiceWorkerMessageEvent" => false,
+    "ServiceWorkerRegistration" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "skipWaiting" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebSocket" => false,
+    "WindowClient" => false,
+    "Worker" => false,
+    "WorkerGlobalScope" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false,
+    "XMLHttpRequest" => false
+};
+
+/// `jest` environment
+pub const JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fdescribe" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false
+};
+
+/// `mocha` environment
+pub const MOCHA: Map<&'static str, bool> = phf_map! {
+    "after" => false,
+    "afterEach" => false,
+    "before" => false,
+    "beforeEach" => false,
+    "context" => false,
+    "describe" => false,
+    "it" => false,
+    "mocha" => false,
+    "run" => false,
+    "setup" => false,
+    "specify" => false,
+    "suite" => false,
+    "suiteSetup" => false,
+    "suiteTeardown" => false,
+    "teardown" => false,
+    "test" => false,
+    "xcontext" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xspecify" => false
+};
+
+/// `jasmine` environment
+pub const JASMINE: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "expectAsync" => false,
+    "fail" => false,
+    "fdescribe" => false,
+    "fit" => false,
+    "it" => false,
+    "jasmine" => false,
+    "pending" => false,
+    "runs" => false,
+    "spyOn" => false,
+    "spyOnAllFunctions" => false,
+    "spyOnProperty" => false,
+    "waits" => false,
+    "waitsFor" => false,
+    "xdescribe" => false,
+    "xit" => false
+};
+
+/// `amd` environment, `define` and `require` of AMD modules
+pub const AMD: Map<&'static str, bool> = phf_map! {
+    "define" => false,
+    "require" => false
+};
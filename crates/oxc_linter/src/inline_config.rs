@@ -0,0 +1,223 @@
+//! Configuration comments of a file: `/* global foo, bar:writable */`, `/* eslint-env browser, node */`
+//! and `/* eslint no-console: ["error", { allow: ["warn"] }] */`.
+
+use lazy_static::lazy_static;
+use oxc_ast::Trivias;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_span::Span;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde_json::{Map, Value};
+
+use crate::config::{ESLintRuleConfig, GlobalValue};
+
+#[derive(Debug, Clone, Error, Diagnostic)]
+#[error("Failed to parse the configuration comment: {0}")]
+#[diagnostic()]
+pub struct FailedToParseInlineConfig(pub String, #[label] pub Span);
+
+/// Configuration from the block comments of a file, applied on top of the configuration files.
+#[derive(Debug, Default)]
+pub struct InlineConfig {
+    /// Environments enabled by `/* eslint-env */`
+    pub env: Vec<String>,
+    /// Global variables declared by `/* global */` or `/* globals */`
+    pub globals: FxHashMap<String, GlobalValue>,
+    /// Rules configured by `/* eslint */`, applied on top of the rules of the configuration files
+    pub rules: Vec<ESLintRuleConfig>,
+    /// `/* eslint */` comments which are not valid
+    pub errors: Vec<FailedToParseInlineConfig>,
+}
+
+impl InlineConfig {
+    pub fn parse(source_text: &str, trivias: &Trivias) -> Self {
+        let mut config = Self::default();
+        for (start, comment) in trivias.comments() {
+            // Configuration comments are block comments only, like ESLint
+            if comment.is_single_line() {
+                continue;
+            }
+            let text = Span::new(*start, comment.end()).source_text(source_text);
+            let text = strip_description(text).trim_start();
+            if let Some(text) = strip_directive(text, "eslint-env") {
+                config.env.extend(split_list(text));
+            } else if let Some(text) =
+                strip_directive(text, "globals").or_else(|| strip_directive(text, "global"))
+            {
+                config.globals.extend(split_list(text).into_iter().map(parse_global));
+            } else if let Some(text) = strip_directive(text, "eslint") {
+                match parse_rules(text) {
+                    Ok(rules) => config.rules.extend(rules),
+                    Err(error) => config
+                        .errors
+                        .push(FailedToParseInlineConfig(error, Span::new(*start, comment.end()))),
+                }
+            }
+        }
+        config
+    }
+}
+
+/// Remove the `-- description` of a directive comment. Like ESLint, the dashes need to be
+/// surrounded by whitespace, so `--` within the configuration, e.g. in a string, is kept.
+pub fn strip_description(text: &str) -> &str {
+    lazy_static! {
+        static ref DESCRIPTION: Regex = Regex::new(r"\s-{2,}\s").unwrap();
+    }
+    DESCRIPTION.find(text).map_or(text, |m| &text[..m.start()])
+}
+
+fn strip_directive<'a>(text: &'a str, directive: &str) -> Option<&'a str> {
+    text.strip_prefix(directive)
+        .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+}
+
+/// Names separated by commas or whitespace, e.g. `a, b:readonly c : writable`.
+fn split_list(text: &str) -> Vec<String> {
+    // `a : b` is `a:b`
+    let text = text.split(':').map(str::trim).collect::<Vec<_>>().join(":");
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// `name`, `name:readonly`, `name:writable`, `name:off` or the legacy `name:false` and `name:true`.
+fn parse_global(item: String) -> (String, GlobalValue) {
+    let Some((name, value)) = item.split_once(':') else { return (item, GlobalValue::Readonly) };
+    let value = match value {
+        "true" | "writable" | "writeable" => GlobalValue::Writeable,
+        "off" => GlobalValue::Off,
+        _ => GlobalValue::Readonly,
+    };
+    (name.to_string(), value)
+}
+
+/// `no-console: ["error", { allow: ["warn"] }], eqeqeq: off`, the content of an object
+/// whose keys and string values do not need to be quoted, like ESLint.
+fn parse_rules(text: &str) -> Result<Vec<ESLintRuleConfig>, String> {
+    let json = format!("{{{}}}", quote_identifiers(text));
+    let rules = serde_json::from_str::<Map<String, Value>>(&json).map_err(|err| err.to_string())?;
+    rules.iter().map(|(key, value)| ESLintRuleConfig::parse(key, value)).collect()
+}
+
+/// Quote the unquoted keys and values and replace single quotes by double quotes to get JSON,
+/// e.g. `eqeqeq: [error, 'always']` => `"eqeqeq": ["error", "always"]`.
+fn quote_identifiers(text: &str) -> String {
+    let is_identifier =
+        |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '@' | '/' | '-' | '.' | '+');
+    let mut json = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '"' || c == '\'' {
+            json.push('"');
+            while let Some(next) = chars.next() {
+                match next {
+                    '\\' => {
+                        let Some(escaped) = chars.next() else { break };
+                        // `\'` is not a valid JSON escape
+                        if escaped != '\'' {
+                            json.push('\\');
+                        }
+                        json.push(escaped);
+                    }
+                    _ if next == c => break,
+                    '"' => json.push_str("\\\""),
+                    _ => json.push(next),
+                }
+            }
+            json.push('"');
+        } else if is_identifier(c) {
+            let mut identifier = String::from(c);
+            while let Some(next) = chars.next_if(|c| is_identifier(*c)) {
+                identifier.push(next);
+            }
+            let is_literal = matches!(identifier.as_str(), "true" | "false" | "null")
+                || identifier.trim_start_matches('-').starts_with(|c: char| c.is_ascii_digit());
+            if is_literal {
+                json.push_str(&identifier);
+            } else {
+                json.push('"');
+                json.push_str(&identifier);
+                json.push('"');
+            }
+        } else {
+            json.push(c);
+        }
+    }
+    json
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use serde_json::json;
+
+    use super::{quote_identifiers, InlineConfig};
+    use crate::{config::GlobalValue, AllowWarnDeny};
+
+    #[test]
+    fn parse() {
+        let source_text = "
+/* global a, b:writable c : false */
+/*globals d:off -- description */
+/* eslint-env browser, node */
+// global e
+/* globalThis */
+";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let config = InlineConfig::parse(source_text, &ret.trivias);
+        assert_eq!(config.env, ["browser", "node"]);
+        assert_eq!(config.globals.len(), 4);
+        assert_eq!(config.globals["a"], GlobalValue::Readonly);
+        assert_eq!(config.globals["b"], GlobalValue::Writeable);
+        assert_eq!(config.globals["c"], GlobalValue::Readonly);
+        assert_eq!(config.globals["d"], GlobalValue::Off);
+    }
+
+    #[test]
+    fn parse_rules() {
+        let source_text = r#"
+/* eslint no-bitwise: ["error", { allow: ['~'] }], eqeqeq: 1 */
+/*eslint @typescript-eslint/no-namespace: off -- description */
+/* eslint-disable no-debugger */
+// eslint no-empty: error
+/* eslint no-alert: [ */
+/* eslint no-alert: fatal */
+/* eslint no-bogus-rule: error */
+/* eslint no-unused-vars: ["error", { varsIgnorePattern: "a--b" }] --- description */
+"#;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let config = InlineConfig::parse(source_text, &ret.trivias);
+        assert_eq!(config.rules.len(), 4);
+        let no_bitwise = config.rules.iter().find(|r| r.matches("eslint", "no-bitwise")).unwrap();
+        assert_eq!(no_bitwise.severity, AllowWarnDeny::Deny);
+        assert_eq!(no_bitwise.config, Some(json!([{ "allow": ["~"] }])));
+        let eqeqeq = config.rules.iter().find(|r| r.matches("eslint", "eq-eq-eq")).unwrap();
+        assert_eq!(eqeqeq.severity, AllowWarnDeny::Warn);
+        assert!(eqeqeq.config.is_none());
+        let no_namespace =
+            config.rules.iter().find(|r| r.matches("typescript", "no-namespace")).unwrap();
+        assert_eq!(no_namespace.severity, AllowWarnDeny::Allow);
+        let no_unused_vars =
+            config.rules.iter().find(|r| r.matches("eslint", "no-unused-vars")).unwrap();
+        assert_eq!(no_unused_vars.config, Some(json!([{ "varsIgnorePattern": "a--b" }])));
+        assert_eq!(config.errors.len(), 3);
+    }
+
+    #[test]
+    fn quote() {
+        assert_eq!(
+            quote_identifiers(r#"a: [off, 'b"c', "d\'e", -1, true, { f: null }]"#),
+            r#""a": ["off", "b\"c", "d'e", -1, true, { "f": null }]"#
+        );
+    }
+}
@@ -4,32 +4,90 @@
 mod tester;
 
 mod ast_util;
+pub mod config;
 mod context;
 mod disable_directives;
 mod fixer;
+mod fnv;
 mod globals;
+mod inline_config;
 mod jest_ast_util;
+mod module_graph;
+mod options;
+mod partial_loader;
+mod react_ast_util;
 pub mod rule;
 mod rule_timer;
 mod rules;
+mod service;
 
-use std::{self, fs, io::Write, rc::Rc};
+use std::{
+    self,
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    io::Write,
+    path::Path,
+    rc::Rc,
+    sync::{Arc, OnceLock},
+};
 
-pub use fixer::{FixResult, Fixer, Message};
+use dashmap::DashMap;
+pub use fixer::{Fix, FixKind, FixResult, Fixer, Message, MAX_FIX_PASSES};
+use oxc_diagnostics::Error;
 pub(crate) use oxc_semantic::AstNode;
 use rustc_hash::FxHashMap;
 
+use crate::{
+    config::{ConfigStore, ESLintConfig, ESLintRuleConfig, LintSettings},
+    fnv::FnvHasher,
+    rule::RuleDispatch,
+};
 pub use crate::{
     context::LintContext,
-    rule::RuleCategory,
+    module_graph::{Exports, ImportPaths, Module, ModuleGraph, Project, ProjectOptions},
+    options::AllowWarnDeny,
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    rule::{RuleCategory, RuleInfo, RuleWithSeverity},
     rules::{RuleEnum, RULES},
+    service::{
+        has_lintable_extension, LineIndex, LintResult, LintService, Location, SkippedFix, Walk,
+        WalkOptions,
+    },
 };
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Linter {
-    rules: Vec<RuleEnum>,
+    rules: Vec<RuleWithSeverity>,
+    /// `rules` before the rules of the root configuration were applied,
+    /// for the files below a nested configuration file with `"root": true`
+    rules_without_root_config: Option<Vec<RuleWithSeverity>>,
+    /// Configuration files applied on top of `rules` for each file
+    config_store: Option<Arc<ConfigStore>>,
+    /// Rules selected on the command line, applied on top of the configuration files
+    cli_rules: Vec<ESLintRuleConfig>,
     fix: bool,
+    /// Also apply fixes which may change the behavior of the code
+    fix_suggestions: bool,
+    /// Report `eslint-disable` directives which did not suppress any diagnostic
+    report_unused_directives: bool,
+    /// Apply configuration comments and `eslint-disable` directives
+    inline_config: bool,
+    /// Resolve imports for the rules which check them against other files, e.g. `import/named`
+    import_plugin: bool,
+    /// The files walked for the projects of the module graph skip the files ignored by these options
+    walk_options: Option<WalkOptions>,
+    module_graph: OnceLock<Option<Arc<ModuleGraph>>>,
     print_execution_times: bool,
+    /// [`Linter::config_hash`] of the files without configuration beyond the root configuration
+    root_config_hash: OnceLock<u64>,
+    /// [`RuleDispatch`] of `rules`, for the files without configuration beyond the root configuration
+    root_dispatch: OnceLock<RuleDispatch>,
+    /// [`RuleDispatch`] of the other files by [`Linter::hash_config`] of their resolved configuration
+    dispatches: DashMap<u64, Arc<RuleDispatch>>,
+    /// Keep the severity declared by the rule's diagnostics, for the snapshots of rule tests
+    #[cfg(test)]
+    declared_severity: bool,
 }
 
 impl Linter {
@@ -38,92 +96,291 @@ impl Linter {
             .iter()
             .cloned()
             .filter(|rule| rule.category() == RuleCategory::Correctness)
+            .map(|rule| RuleWithSeverity::new(rule, AllowWarnDeny::Deny))
             .collect::<Vec<_>>();
         Self::from_rules(rules)
     }
 
-    pub fn from_rules(rules: Vec<RuleEnum>) -> Self {
-        Self { rules, fix: false, print_execution_times: false }
+    pub fn from_rules(mut rules: Vec<RuleWithSeverity>) -> Self {
+        rules.retain(|rule| rule.severity.is_enabled());
+        Self {
+            rules,
+            rules_without_root_config: None,
+            config_store: None,
+            cli_rules: vec![],
+            fix: false,
+            fix_suggestions: false,
+            report_unused_directives: false,
+            inline_config: true,
+            import_plugin: false,
+            walk_options: None,
+            module_graph: OnceLock::new(),
+            print_execution_times: false,
+            root_config_hash: OnceLock::new(),
+            root_dispatch: OnceLock::new(),
+            dispatches: DashMap::default(),
+            #[cfg(test)]
+            declared_severity: false,
+        }
     }
 
-    pub fn rules(&self) -> &Vec<RuleEnum> {
+    pub fn rules(&self) -> &Vec<RuleWithSeverity> {
         &self.rules
     }
 
     pub fn has_fix(&self) -> bool {
-        self.fix
+        self.fix || self.fix_suggestions
     }
 
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
 
+    pub fn config_store(&self) -> Option<&Arc<ConfigStore>> {
+        self.config_store.as_ref()
+    }
+
+    fn module_graph(&self) -> Option<&Arc<ModuleGraph>> {
+        self.module_graph
+            .get_or_init(|| {
+                self.import_plugin.then(|| {
+                    Arc::new(ModuleGraph::new(self.walk_options.clone(), self.config_store.clone()))
+                })
+            })
+            .as_ref()
+    }
+
+    /// Apply the rules of the root configuration of `config_store` on top of the rules,
+    /// and the nested configuration files on top of those for each file.
+    #[must_use]
+    pub fn with_config_store(mut self, config_store: Arc<ConfigStore>) -> Self {
+        if let Some(root) = config_store.root() {
+            self.rules_without_root_config = Some(self.rules.clone());
+            config::override_rules(&mut self.rules, root.rules().iter().chain(&self.cli_rules));
+            self.rules.sort_unstable_by_key(|rule| rule.rule.name());
+        }
+        self.config_store = Some(config_store);
+        self
+    }
+
+    /// Turn rules on or off as selected on the command line, which takes precedence
+    /// over the root and nested configuration files.
+    #[must_use]
+    pub fn with_cli_rules(mut self, cli_rules: Vec<ESLintRuleConfig>) -> Self {
+        for rules in std::iter::once(&mut self.rules).chain(&mut self.rules_without_root_config) {
+            config::override_rules(rules, &cli_rules);
+            rules.sort_unstable_by_key(|rule| rule.rule.name());
+        }
+        self.cli_rules = cli_rules;
+        self
+    }
+
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn with_declared_severity(mut self) -> Self {
+        self.declared_severity = true;
+        self
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, yes: bool) -> Self {
+        self.fix_suggestions = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_inline_config(mut self, yes: bool) -> Self {
+        self.inline_config = yes;
+        self
+    }
+
+    /// Resolve the imports of the linted files, the rules of the import plugin do nothing otherwise.
+    #[must_use]
+    pub fn with_import_plugin(mut self, yes: bool) -> Self {
+        self.import_plugin = yes;
+        self
+    }
+
+    /// The ignore files and patterns of the linted files, also applied to the files of the projects
+    /// of the import plugin, e.g. for `import/no-unused-modules`. The paths are not used.
+    #[must_use]
+    pub fn with_walk_options(mut self, options: WalkOptions) -> Self {
+        self.walk_options = Some(options);
+        self
+    }
+
     #[must_use]
     pub fn with_print_execution_times(mut self, yes: bool) -> Self {
         self.print_execution_times = yes;
         self
     }
 
+    /// Enable all rules, configured by the `rules` of an ESLint configuration.
     pub fn from_json_str(s: &str) -> Self {
-        let rules = serde_json::from_str(s)
+        let mut rules = RULES
+            .iter()
+            .cloned()
+            .map(|rule| RuleWithSeverity::new(rule, AllowWarnDeny::Deny))
+            .collect::<Vec<_>>();
+        if let Some(config) = serde_json::from_str(s)
             .ok()
-            .and_then(|v: serde_json::Value| v.get("rules").cloned())
-            .and_then(|v| v.as_object().cloned())
-            .map_or_else(
-                || RULES.to_vec(),
-                |rules_config| {
-                    RULES
-                        .iter()
-                        .map(|rule| {
-                            let value = rules_config.get(rule.name());
-                            rule.read_json(value.cloned())
-                        })
-                        .collect()
-                },
-            );
-
+            .and_then(|json| ESLintConfig::from_value(&json, std::path::PathBuf::new()).ok())
+        {
+            config::override_rules(&mut rules, config.rules());
+        }
         Self::from_rules(rules)
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx = ctx.with_fix(self.fix);
 
-        for rule in &self.rules {
-            ctx.with_rule_name(rule.name());
+        let (rules, settings) = match self.resolve_config(ctx.file_path()) {
+            Ok(resolved) => resolved,
+            Err(error) => return vec![Message::new(error, None)],
+        };
+        let mut ctx = ctx
+            .with_fix(self.fix)
+            .with_fix_suggestions(self.fix_suggestions)
+            .with_inline_config(self.inline_config)
+            .with_module_graph(self.module_graph().cloned())
+            .with_settings(settings);
+        let rules = if ctx.inline_rules().is_empty() {
+            rules
+        } else {
+            let mut rules = rules.into_owned();
+            config::override_rules(&mut rules, ctx.inline_rules());
+            Cow::Owned(rules)
+        };
+
+        for RuleWithSeverity { rule, severity, .. } in rules.iter() {
+            ctx.with_rule(rule);
+            self.apply_severity(&mut ctx, *severity);
             rule.run_once(&ctx, self.print_execution_times);
         }
 
+        let dispatch;
+        let dispatch = if matches!(rules, Cow::Borrowed(_)) {
+            self.root_dispatch.get_or_init(|| RuleDispatch::new(&self.rules))
+        } else {
+            // Files with the same overrides, nested configuration or inline configuration share one
+            let hash = self.hash_config(&rules, ctx.settings());
+            dispatch = Arc::clone(
+                &self.dispatches.entry(hash).or_insert_with(|| Arc::new(RuleDispatch::new(&rules))),
+            );
+            &dispatch
+        };
         for node in semantic.nodes().iter() {
-            for rule in &self.rules {
-                ctx.with_rule_name(rule.name());
+            for &index in dispatch.rules(node.kind().ty()) {
+                let RuleWithSeverity { rule, severity, .. } = &rules[index];
+                ctx.with_rule(rule);
+                self.apply_severity(&mut ctx, *severity);
                 rule.run(node, &ctx, self.print_execution_times);
             }
         }
 
         for symbol in semantic.symbols().iter() {
-            for rule in &self.rules {
-                ctx.with_rule_name(rule.name());
+            for RuleWithSeverity { rule, severity, .. } in rules.iter() {
+                ctx.with_rule(rule);
+                self.apply_severity(&mut ctx, *severity);
                 rule.run_on_symbol(symbol, &ctx, self.print_execution_times);
             }
         }
 
+        if self.report_unused_directives {
+            ctx.report_unused_disable_directives();
+        }
+        ctx.report_inline_config_errors();
+
         ctx.into_message()
     }
 
-    #[allow(unused)]
-    fn read_rules_configuration() -> Option<serde_json::Map<String, serde_json::Value>> {
-        fs::read_to_string(".eslintrc.json")
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .and_then(|v: serde_json::Value| v.get("rules").cloned())
-            .and_then(|v| v.as_object().cloned())
+    /// Report the diagnostics of the current rule with the configured severity,
+    /// except for rule tests which keep the severity declared by the rule.
+    #[cfg_attr(not(test), allow(clippy::unused_self))]
+    fn apply_severity(&self, ctx: &mut LintContext, severity: AllowWarnDeny) {
+        #[cfg(test)]
+        if self.declared_severity {
+            return;
+        }
+        ctx.with_severity(severity);
+    }
+
+    /// A hash of everything besides the source text which determines the diagnostics of `path`:
+    /// the enabled rules with their options, the settings and the fix and inline configuration options.
+    ///
+    /// # Errors
+    ///
+    /// * A nested configuration file cannot be read or parsed
+    pub fn config_hash(&self, path: &Path) -> Result<u64, Error> {
+        let (rules, settings) = self.resolve_config(path)?;
+        if matches!(rules, Cow::Borrowed(_)) {
+            return Ok(*self.root_config_hash.get_or_init(|| self.hash_config(&rules, &settings)));
+        }
+        Ok(self.hash_config(&rules, &settings))
+    }
+
+    fn hash_config(&self, rules: &[RuleWithSeverity], settings: &LintSettings) -> u64 {
+        // The hash is written to the cache file, so it needs to be stable across Rust versions
+        let mut hasher = FnvHasher::default();
+        for rule in rules {
+            (rule.rule.plugin_name(), rule.rule.name(), rule.severity).hash(&mut hasher);
+            // Rules are configured by JSON, which is a stable representation of their options
+            rule.options.as_ref().map(ToString::to_string).hash(&mut hasher);
+        }
+        settings.hash(&mut hasher);
+        (self.fix, self.fix_suggestions, self.report_unused_directives, self.inline_config)
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The rules and settings for `path`, from the configuration files applied on top of `self.rules`,
+    /// followed by the rules selected on the command line.
+    /// Code blocks of Markdown files start without [`partial_loader::MARKDOWN_RELAXED_RULES`].
+    fn resolve_config(
+        &self,
+        path: &Path,
+    ) -> Result<(Cow<'_, [RuleWithSeverity]>, Arc<LintSettings>), Error> {
+        let resolved = match &self.config_store {
+            Some(config_store) => config_store.resolve(path)?,
+            None => None,
+        };
+        let rules = match &resolved {
+            Some(resolved) if resolved.is_root => {
+                self.rules_without_root_config.as_deref().unwrap_or(&self.rules)
+            }
+            _ => &self.rules,
+        };
+        let rules = if partial_loader::is_markdown(path) {
+            let rules = rules
+                .iter()
+                .filter(|rule| !partial_loader::MARKDOWN_RELAXED_RULES.contains(&rule.rule.name()));
+            Cow::Owned(rules.cloned().collect())
+        } else {
+            Cow::Borrowed(rules)
+        };
+        let Some(resolved) = resolved else {
+            let settings = self
+                .config_store
+                .as_ref()
+                .map_or_else(Arc::default, |config_store| Arc::clone(config_store.root_settings()));
+            return Ok((rules, settings));
+        };
+        let mut rules = rules.into_owned();
+        config::override_rules(&mut rules, resolved.rules.iter().chain(&self.cli_rules));
+        rules.sort_unstable_by_key(|rule| rule.rule.name());
+        Ok((Cow::Owned(rules), resolved.settings))
     }
 
     pub fn print_rules<W: Write>(writer: &mut W) {
@@ -144,7 +401,34 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use oxc_diagnostics::Severity;
+
+    use super::{
+        config::{ConfigStore, ESLintRuleConfig},
+        AllowWarnDeny, LintContext, Linter, RuleWithSeverity, RULES,
+    };
+
+    fn lint(linter: &Linter, source_text: &str) -> Vec<String> {
+        lint_path(linter, Path::new("test.js"), source_text)
+    }
+
+    fn lint_path(linter: &Linter, path: &Path, source_text: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_trivias(ret.trivias).build(program);
+        let ctx = LintContext::new(path.into(), &Rc::new(semantic.semantic));
+        linter.run(ctx).into_iter().map(|message| message.error.to_string()).collect()
+    }
 
     #[test]
     fn print_rules() {
@@ -152,4 +436,109 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn severity() {
+        let rule = RULES.iter().find(|rule| rule.name() == "no-debugger").unwrap();
+        let severity = |allow_warn_deny| {
+            let linter =
+                Linter::from_rules(vec![RuleWithSeverity::new(rule.clone(), allow_warn_deny)]);
+            let allocator = Allocator::default();
+            let source_type = SourceType::default();
+            let ret = Parser::new(&allocator, "debugger;", source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = SemanticBuilder::new("debugger;", source_type).build(program);
+            let ctx = LintContext::new(Path::new("test.js").into(), &Rc::new(semantic.semantic));
+            linter.run(ctx).into_iter().map(|message| message.error.severity()).collect::<Vec<_>>()
+        };
+        // The configured severity overrides the severity declared by the rule
+        assert_eq!(severity(AllowWarnDeny::Warn), vec![Some(Severity::Warning)]);
+        assert_eq!(severity(AllowWarnDeny::Deny), vec![Some(Severity::Error)]);
+    }
+
+    #[test]
+    fn inline_config() {
+        let source_text = "
+/* eslint no-debugger: off, no-empty: [warn, { allowEmptyCatch: true }] */
+debugger;
+try {} catch {}
+/* eslint eqeqeq: [ */
+";
+        let messages = lint(&Linter::new(), source_text);
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].contains("no-empty"));
+        assert!(messages[1].starts_with("Failed to parse the configuration comment"));
+
+        let messages = lint(&Linter::new().with_inline_config(false), source_text);
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("no-debugger"));
+    }
+
+    #[test]
+    fn eslint_rule_names() {
+        // `eqeqeq` is `eq-eq-eq` in oxc
+        let linter = Linter::from_json_str(r#"{ "rules": { "eqeqeq": "off" } }"#);
+        assert!(!linter.rules().iter().any(|rule| rule.rule.name() == "eq-eq-eq"));
+
+        let messages = lint(&Linter::from_rules(vec![]), "/* eslint eqeqeq: error */ a == b");
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("=="), "{messages:?}");
+    }
+
+    #[test]
+    fn root_config() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/config");
+        let config_store = ConfigStore::discover(&fixtures).unwrap();
+        let linter = Linter::from_rules(vec![]).with_config_store(Arc::new(config_store));
+        let source_text = "debugger; if (a) {}";
+
+        let messages = lint_path(&linter, &fixtures.join("index.js"), source_text);
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("no-debugger"));
+
+        // The nested configuration has `"root": true`, the root configuration does not apply
+        let messages =
+            lint_path(&linter, &fixtures.join("packages/standalone/index.js"), source_text);
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(messages[0].contains("no-empty"));
+    }
+
+    #[test]
+    fn dispatch_cache() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/config");
+        let config_store = ConfigStore::discover(&fixtures).unwrap();
+        let linter = Linter::from_rules(vec![]).with_config_store(Arc::new(config_store));
+        let source_text = "debugger; if (a) {}";
+
+        // Files with the same resolved configuration share the dispatch of their rules
+        lint_path(&linter, &fixtures.join("packages/standalone/a.js"), source_text);
+        lint_path(&linter, &fixtures.join("packages/standalone/b.js"), source_text);
+        assert_eq!(linter.dispatches.len(), 1);
+        let source_text = "/* eslint no-debugger: 2 */ debugger;";
+        let messages = lint_path(&linter, &fixtures.join("packages/standalone/c.js"), source_text);
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert_eq!(linter.dispatches.len(), 2);
+    }
+
+    #[test]
+    fn cli_rules() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/config");
+        let config_store = Arc::new(ConfigStore::discover(&fixtures).unwrap());
+        let cli_rules =
+            || vec![ESLintRuleConfig::new("eslint", "no-debugger", AllowWarnDeny::Allow)];
+        let source_text = "debugger; if (a) {}";
+
+        // `-A no-debugger` takes precedence over `"no-debugger": "error"` of the configuration
+        let linter = Linter::from_rules(vec![])
+            .with_cli_rules(cli_rules())
+            .with_config_store(Arc::clone(&config_store));
+        assert!(lint_path(&linter, &fixtures.join("index.js"), source_text).is_empty());
+        let linter =
+            Linter::from_rules(vec![]).with_config_store(config_store).with_cli_rules(cli_rules());
+        assert!(lint_path(&linter, &fixtures.join("index.js"), source_text).is_empty());
+
+        // Also over the nested configuration files and their overrides
+        let path = fixtures.join("packages/legacy/src/index.test.js");
+        assert!(lint_path(&linter, &path, source_text).is_empty());
+    }
 }
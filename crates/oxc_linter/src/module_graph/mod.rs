@@ -0,0 +1,565 @@
+//! Modules of the project for rules which look at other files, e.g. whether `./foo` exports `bar`.
+//! Imports are resolved by `oxc_resolver` and the imported files are parsed on demand,
+//! once for all files linted by the same [`crate::Linter`].
+
+mod project;
+
+use std::{
+    collections::VecDeque,
+    env,
+    ffi::OsStr,
+    fmt, fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Argument, Expression, ImportExpression, Statement},
+    AstKind, Visit,
+};
+use oxc_parser::Parser;
+use oxc_resolver::{Resolution, ResolveOptions, Resolver};
+use oxc_semantic::{ModuleRecord, ModuleRecordBuilder};
+use oxc_span::{Atom, SourceType};
+use oxc_syntax::module_record::{ExportExportName, ExportImportName};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub use self::project::{Project, ProjectOptions};
+use crate::{
+    config::ConfigStore, JavaScriptSource, PartialLoader, WalkOptions, LINT_PARTIAL_LOADER_EXT,
+};
+
+/// Modules of Node.js which are not files, `node:` prefixed specifiers are always builtin.
+const NODE_BUILTINS: [&str; 41] = [
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "worker_threads",
+    "zlib",
+];
+
+/// A parsed file of the module graph.
+#[derive(Debug)]
+pub struct Module {
+    /// The resolved path, with symbolic links resolved
+    pub path: PathBuf,
+    pub record: ModuleRecord,
+    /// Whether the file has `import` or `export` declarations.
+    /// The exports of other files, e.g. CommonJS modules, are unknown.
+    pub has_module_syntax: bool,
+    /// Specifiers of `require("source")` calls and `import("source")` expressions anywhere in the file.
+    /// Which exports of these modules are used is unknown.
+    pub dynamic_requests: FxHashSet<Atom>,
+    /// Resolved paths of the module requests and dynamic requests,
+    /// `None` for builtin and unresolved modules
+    pub dependencies: FxHashMap<Atom, Option<PathBuf>>,
+}
+
+/// The names exported by a module, see [`ModuleGraph::exports`].
+#[derive(Debug, Default)]
+pub struct Exports {
+    pub names: FxHashSet<Atom>,
+    pub has_default: bool,
+    /// `export *` of a module whose exports are unknown, so any name may be exported
+    pub is_incomplete: bool,
+}
+
+impl Exports {
+    /// `None` when it is not known whether `name` is exported.
+    pub fn has(&self, name: &str) -> Option<bool> {
+        if self.names.contains(name) {
+            Some(true)
+        } else if self.is_incomplete {
+            None
+        } else {
+            Some(false)
+        }
+    }
+}
+
+/// The shortest chains of imports back to a file, see [`ModuleGraph::import_paths_to`].
+#[derive(Debug)]
+pub struct ImportPaths {
+    path: PathBuf,
+    /// The resolved imports of the file the chains were computed from
+    dependencies: Vec<PathBuf>,
+    /// For each module which imports `path` directly or not,
+    /// the next module of the shortest chain of imports to `path`
+    next: FxHashMap<PathBuf, PathBuf>,
+}
+
+impl ImportPaths {
+    fn new(
+        module_graph: &ModuleGraph,
+        path: &Path,
+        dependencies: Vec<PathBuf>,
+        skip_external: bool,
+    ) -> Self {
+        // The modules reachable from `path`, and the modules importing each of them.
+        // The imports of `path` itself are those of the linted source text, not of the file on disk.
+        let mut importers: FxHashMap<PathBuf, Vec<PathBuf>> = FxHashMap::default();
+        let mut visited = FxHashSet::from_iter([path.to_path_buf()]);
+        let mut stack = vec![];
+        for dependency in &dependencies {
+            importers.entry(dependency.clone()).or_default().push(path.to_path_buf());
+            if visited.insert(dependency.clone()) {
+                stack.push(dependency.clone());
+            }
+        }
+        while let Some(current) = stack.pop() {
+            let Some(module) = module_graph.module(&current) else { continue };
+            for dependency in module.dependencies.values().flatten() {
+                if dependency != path && skip_external && ModuleGraph::is_external(dependency) {
+                    continue;
+                }
+                importers.entry(dependency.clone()).or_default().push(current.clone());
+                if visited.insert(dependency.clone()) {
+                    stack.push(dependency.clone());
+                }
+            }
+        }
+
+        let mut next = FxHashMap::default();
+        let mut queue = VecDeque::from([path.to_path_buf()]);
+        while let Some(current) = queue.pop_front() {
+            for importer in importers.get(&current).into_iter().flatten() {
+                if importer != path && !next.contains_key(importer) {
+                    next.insert(importer.clone(), current.clone());
+                    queue.push_back(importer.clone());
+                }
+            }
+        }
+        Self { path: path.to_path_buf(), dependencies, next }
+    }
+
+    /// The shortest chain of imports from `from` back to the file, starting with `from`.
+    pub fn chain<'p>(&'p self, from: &'p Path) -> Option<Vec<&'p Path>> {
+        let mut chain = vec![from];
+        let mut current = self.next.get(from)?;
+        while *current != self.path {
+            chain.push(current);
+            current = self.next.get(current)?;
+        }
+        Some(chain)
+    }
+}
+
+pub struct ModuleGraph {
+    resolver: Resolver,
+    /// Loaded modules by their resolved path, `None` for files which cannot be parsed
+    modules: DashMap<PathBuf, Option<Arc<Module>>>,
+    projects: DashMap<ProjectOptions, Arc<Project>>,
+    /// By the path of the file and whether imports of `node_modules` are skipped
+    import_paths: DashMap<(PathBuf, bool), Arc<ImportPaths>>,
+    /// Ignore files and patterns of the files of the projects, see [`ModuleGraph::new`]
+    walk_options: WalkOptions,
+    config_store: Option<Arc<ConfigStore>>,
+}
+
+impl fmt::Debug for ModuleGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModuleGraph").field("modules", &self.modules.len()).finish_non_exhaustive()
+    }
+}
+
+impl Default for ModuleGraph {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl ModuleGraph {
+    /// The files of the projects skip those ignored by `walk_options`, `.eslintignore` by default,
+    /// and the `ignorePatterns` of `config_store`. The paths of `walk_options` are not used.
+    pub fn new(walk_options: Option<WalkOptions>, config_store: Option<Arc<ConfigStore>>) -> Self {
+        let walk_options = walk_options.unwrap_or_else(|| WalkOptions {
+            paths: vec![],
+            ignore_path: PathBuf::from(".eslintignore"),
+            no_ignore: false,
+            ignore_pattern: vec![],
+        });
+        let options = ResolveOptions {
+            extensions: [".js", ".mjs", ".cjs", ".jsx", ".ts", ".mts", ".cts", ".tsx", ".json"]
+                .map(String::from)
+                .to_vec(),
+            // `import "./a.js"` of `a.ts` in TypeScript
+            extension_alias: vec![
+                (".js".into(), vec![".js".into(), ".ts".into(), ".tsx".into()]),
+                (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
+                (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
+            ],
+            condition_names: vec!["node".into(), "import".into(), "require".into()],
+            main_fields: vec!["module".into(), "main".into()],
+            ..ResolveOptions::default()
+        };
+        Self {
+            resolver: Resolver::new(options),
+            modules: DashMap::default(),
+            projects: DashMap::default(),
+            import_paths: DashMap::default(),
+            walk_options,
+            config_store,
+        }
+    }
+
+    pub fn is_builtin(specifier: &str) -> bool {
+        specifier.starts_with("node:") || NODE_BUILTINS.contains(&specifier)
+    }
+
+    /// Whether the resolved `path` is in `node_modules`.
+    pub fn is_external(path: &Path) -> bool {
+        path.components().any(|component| component == Component::Normal("node_modules".as_ref()))
+    }
+
+    /// Resolve `specifier` imported by the file at `importer`,
+    /// `None` for builtin modules and modules which cannot be resolved.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        if Self::is_builtin(specifier) {
+            return None;
+        }
+        let directory = importer.parent().unwrap_or(importer);
+        let directory = env::current_dir().unwrap_or_default().join(directory);
+        self.resolver.resolve(directory, specifier).ok().map(Resolution::into_path_buf)
+    }
+
+    /// The module `specifier` imported by the file at `importer` resolves to.
+    pub fn import(&self, importer: &Path, specifier: &str) -> Option<Arc<Module>> {
+        self.module(&self.resolve(importer, specifier)?)
+    }
+
+    /// The module of the JavaScript or TypeScript file at the resolved `path`, or of the scripts
+    /// embedded in a [`LINT_PARTIAL_LOADER_EXT`] file. `None` for other files and files which cannot be read or parsed.
+    pub fn module(&self, path: &Path) -> Option<Arc<Module>> {
+        if let Some(module) = self.modules.get(path) {
+            return module.clone();
+        }
+        // Loaded without holding the lock, another thread may load the same module meanwhile
+        let module = self.load(path).map(Arc::new);
+        self.modules.entry(path.to_path_buf()).or_insert(module).clone()
+    }
+
+    /// The module `specifier` of `module` resolves to.
+    pub fn dependency(&self, module: &Module, specifier: &str) -> Option<Arc<Module>> {
+        let path = module.dependencies.get(specifier)?.as_deref()?;
+        self.module(path)
+    }
+
+    /// Vue, Svelte, Astro, HTML and Markdown files are loaded from their embedded scripts,
+    /// the records of the scripts are merged.
+    fn load(&self, path: &Path) -> Option<Module> {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let source_type = if LINT_PARTIAL_LOADER_EXT.contains(&ext) {
+            None
+        } else {
+            Some(SourceType::from_path(path).ok()?)
+        };
+        let source_text = fs::read_to_string(path).ok()?;
+        let sources = match source_type {
+            Some(source_type) => {
+                vec![JavaScriptSource { source_text: &source_text, source_type, start: 0 }]
+            }
+            None => PartialLoader::parse(ext, &source_text)?,
+        };
+
+        let mut record = ModuleRecord::default();
+        let mut has_module_syntax = false;
+        let mut dynamic_requests = DynamicRequests::default();
+        for source in sources {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source.source_text, source.source_type)
+                .allow_return_outside_function(true)
+                .parse();
+            if !ret.errors.is_empty() {
+                return None;
+            }
+            let program = allocator.alloc(ret.program);
+            has_module_syntax |=
+                program.body.iter().any(|stmt| matches!(stmt, Statement::ModuleDeclaration(_)));
+            let mut builder = ModuleRecordBuilder::default();
+            builder.visit(program);
+            merge_records(&mut record, builder.build());
+            dynamic_requests.visit_program(program);
+        }
+        let dynamic_requests = dynamic_requests.0;
+        let dependencies = record
+            .module_requests
+            .keys()
+            .chain(&dynamic_requests)
+            .map(|specifier| (specifier.clone(), self.resolve(path, specifier)))
+            .collect();
+        Some(Module {
+            path: path.to_path_buf(),
+            record,
+            has_module_syntax,
+            dynamic_requests,
+            dependencies,
+        })
+    }
+
+    /// The usage of the exports of the files of the project, computed once for each `options`.
+    pub fn project(&self, options: &ProjectOptions) -> Arc<Project> {
+        if let Some(project) = self.projects.get(options) {
+            return Arc::clone(&project);
+        }
+        // Computed while holding the lock, the files of a project are loaded only once
+        let project = self
+            .projects
+            .entry(options.clone())
+            .or_insert_with(|| Arc::new(Project::new(self, options)));
+        Arc::clone(&project)
+    }
+
+    /// The shortest chains of imports from the modules imported by the file at the resolved `path`,
+    /// directly or not, back to it. `dependencies` are the resolved imports of the file, sorted.
+    /// Imports of `node_modules` are not followed with `skip_external`.
+    /// Computed once for each file, again when its imports change.
+    pub fn import_paths_to(
+        &self,
+        path: &Path,
+        dependencies: Vec<PathBuf>,
+        skip_external: bool,
+    ) -> Arc<ImportPaths> {
+        let key = (path.to_path_buf(), skip_external);
+        if let Some(import_paths) = self.import_paths.get(&key) {
+            if import_paths.dependencies == dependencies {
+                return Arc::clone(&import_paths);
+            }
+        }
+        // Computed without holding the lock, like modules
+        let import_paths = Arc::new(ImportPaths::new(self, path, dependencies, skip_external));
+        self.import_paths.insert(key, Arc::clone(&import_paths));
+        import_paths
+    }
+
+    /// The names exported by `module`, including those of `export *` declarations.
+    /// `None` when the exports of `module` are unknown.
+    pub fn exports(&self, module: &Module) -> Option<Exports> {
+        if !module.has_module_syntax {
+            return None;
+        }
+        let mut exports = Exports::default();
+        let mut visited = FxHashSet::default();
+        self.collect_exports(module, &mut exports, &mut visited, true);
+        Some(exports)
+    }
+
+    fn collect_exports(
+        &self,
+        module: &Module,
+        exports: &mut Exports,
+        visited: &mut FxHashSet<PathBuf>,
+        with_default: bool,
+    ) {
+        if !visited.insert(module.path.clone()) {
+            return;
+        }
+        let record = &module.record;
+        let entries = record.local_export_entries.iter().chain(&record.indirect_export_entries);
+        for entry in entries {
+            match &entry.export_name {
+                ExportExportName::Name(name) if name.name() == &"default" => {
+                    exports.has_default |= with_default;
+                }
+                ExportExportName::Name(name) => {
+                    exports.names.insert(name.name().clone());
+                }
+                ExportExportName::Default(_) => exports.has_default |= with_default,
+                ExportExportName::Null => {}
+            }
+        }
+        exports.has_default |= with_default && record.export_default.is_some();
+        // `export *` does not export `default`
+        for entry in &record.star_export_entries {
+            debug_assert!(matches!(entry.import_name, ExportImportName::AllButDefault));
+            let Some(request) = &entry.module_request else { continue };
+            match self.dependency(module, request.name()) {
+                Some(dependency) if dependency.has_module_syntax => {
+                    self.collect_exports(&dependency, exports, visited, false);
+                }
+                _ => exports.is_incomplete = true,
+            }
+        }
+    }
+}
+
+/// Add the entries of the record of another script of the same file to `record`,
+/// the spans of the entries are relative to their script.
+fn merge_records(record: &mut ModuleRecord, other: ModuleRecord) {
+    for (specifier, spans) in other.module_requests {
+        record.module_requests.entry(specifier).or_default().extend(spans);
+    }
+    record.import_entries.extend(other.import_entries);
+    record.local_export_entries.extend(other.local_export_entries);
+    record.indirect_export_entries.extend(other.indirect_export_entries);
+    record.star_export_entries.extend(other.star_export_entries);
+    record.exported_bindings.extend(other.exported_bindings);
+    record.exported_bindings_duplicated.extend(other.exported_bindings_duplicated);
+    record.export_default = record.export_default.or(other.export_default);
+    record.export_default_duplicated.extend(other.export_default_duplicated);
+}
+
+/// Collects the string literal specifiers of `require` calls and `import()` expressions.
+#[derive(Default)]
+struct DynamicRequests(FxHashSet<Atom>);
+
+impl<'a> Visit<'a> for DynamicRequests {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let AstKind::CallExpression(call) = kind else { return };
+        if call.is_require_call() {
+            if let Some(Argument::Expression(Expression::StringLiteral(source))) =
+                call.arguments.first()
+            {
+                self.0.insert(source.value.clone());
+            }
+        }
+    }
+
+    fn visit_import_expression(&mut self, expr: &'a ImportExpression<'a>) {
+        if let Expression::StringLiteral(source) = &expr.source {
+            self.0.insert(source.value.clone());
+        }
+        self.visit_expression(&expr.source);
+        for arg in &expr.arguments {
+            self.visit_expression(arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use super::{ModuleGraph, ProjectOptions};
+    use crate::WalkOptions;
+
+    #[test]
+    fn exports() {
+        let graph = ModuleGraph::default();
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let importer = fixtures.join("index.ts");
+        assert!(ModuleGraph::is_builtin("fs") && ModuleGraph::is_builtin("node:test"));
+        assert!(graph.resolve(&importer, "fs").is_none());
+        assert!(graph.resolve(&importer, "./does-not-exist").is_none());
+
+        let path = graph.resolve(&importer, "./named-exports").unwrap();
+        let module = graph.module(&path).unwrap();
+        let exports = graph.exports(&module).unwrap();
+        assert_eq!(exports.has("a"), Some(true));
+        assert_eq!(exports.has("foo"), Some(false));
+        assert!(!exports.has_default);
+
+        let path = graph.resolve(&importer, "./export-all").unwrap();
+        let exports = graph.exports(&graph.module(&path).unwrap()).unwrap();
+        // `default` of the modules exported by `export *` is not exported
+        assert_eq!(exports.has("a"), Some(true));
+        assert_eq!(exports.has("b"), Some(true));
+        assert!(exports.has_default);
+
+        let path = graph.resolve(&importer, "./common-js").unwrap();
+        assert!(graph.exports(&graph.module(&path).unwrap()).is_none());
+        assert!(graph.module(Path::new("does-not-exist.js")).is_none());
+    }
+
+    #[test]
+    fn project() {
+        let graph = ModuleGraph::default();
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let options = ProjectOptions {
+            src: vec![fixtures.clone()],
+            entries: vec![fixtures.join("barrel.js")],
+        };
+        let project = graph.project(&options);
+        let index = fixtures.join("index.ts").canonicalize().unwrap();
+        assert!(project.contains(&index));
+        assert!(project.is_imported(&index, "used") && project.is_imported(&index, "default"));
+        // `export *` and `export { viaBarrel as renamed } from` of `barrel.js`
+        assert!(project.is_imported(&index, "fromBarrel"));
+        assert!(project.is_imported(&index, "viaBarrel"));
+        assert!(!project.is_imported(&index, "renamed"));
+        // `require` and `import()` of `dynamic.js`
+        let named_exports = fixtures.join("named-exports.js").canonicalize().unwrap();
+        assert!(project.is_imported(&named_exports, "foo"));
+        let default_export = fixtures.join("default-export.js").canonicalize().unwrap();
+        assert!(project.is_imported(&default_export, "default"));
+
+        assert!(project.is_entry(&fixtures.join("barrel.js").canonicalize().unwrap()));
+        assert!(project.is_reachable(&index));
+        assert!(!project.is_reachable(&named_exports));
+        assert!(Arc::ptr_eq(&project, &graph.project(&options)));
+    }
+
+    #[test]
+    fn partial_files() {
+        let graph = ModuleGraph::default();
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let component = fixtures.join("component.vue").canonicalize().unwrap();
+        let module = graph.module(&component).unwrap();
+        assert!(module.has_module_syntax);
+        assert!(module.record.module_requests.contains_key("./used-by-component"));
+
+        let options = ProjectOptions { src: vec![fixtures.clone()], entries: vec![component] };
+        let project = graph.project(&options);
+        let used = fixtures.join("used-by-component.js").canonicalize().unwrap();
+        assert!(project.is_imported(&used, "fromComponent"));
+        assert!(!project.is_imported(&used, "unused"));
+        assert!(project.is_reachable(&used));
+    }
+
+    #[test]
+    fn ignored_files() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let walk_options = WalkOptions {
+            paths: vec![],
+            ignore_path: PathBuf::from(".project-ignore"),
+            no_ignore: false,
+            ignore_pattern: vec![],
+        };
+        let options = ProjectOptions { src: vec![fixtures.clone()], entries: vec![] };
+        let used = fixtures.join("used-by-component.js").canonicalize().unwrap();
+        let graph = ModuleGraph::new(Some(walk_options.clone()), None);
+        assert!(!graph.project(&options).contains(&used));
+        let graph = ModuleGraph::new(Some(WalkOptions { no_ignore: true, ..walk_options }), None);
+        assert!(graph.project(&options).contains(&used));
+    }
+}
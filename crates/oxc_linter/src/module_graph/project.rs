@@ -0,0 +1,219 @@
+//! Which exports of the files of a project are imported by other files,
+//! and which files are reachable from the entry points.
+
+use std::path::{Path, PathBuf};
+
+use oxc_span::Atom;
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::ModuleGraph;
+use crate::{Walk, WalkOptions};
+
+/// The files of a project, see [`ModuleGraph::project`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectOptions {
+    /// Files and directories of the project, e.g. `src`.
+    /// Files ignored by `.gitignore` and the ignore files and patterns of the linted files are skipped.
+    pub src: Vec<PathBuf>,
+    /// Files other files of the project must be reachable from, e.g. `src/main.ts`.
+    /// Reachability is not checked without entries.
+    pub entries: Vec<PathBuf>,
+}
+
+impl Default for ProjectOptions {
+    fn default() -> Self {
+        Self { src: vec![PathBuf::from(".")], entries: vec![] }
+    }
+}
+
+/// The names imported from a module by other modules
+#[derive(Debug, Default)]
+struct ImportedNames {
+    names: FxHashSet<Atom>,
+    /// All names but `default`, e.g. by `import * as ns` or `export * from`
+    all: bool,
+}
+
+impl ImportedNames {
+    fn contains(&self, name: &str) -> bool {
+        (self.all && name != "default") || self.names.contains(name)
+    }
+}
+
+#[derive(Debug)]
+pub struct Project {
+    /// Paths of the files, with symbolic links resolved
+    files: FxHashSet<PathBuf>,
+    entries: FxHashSet<PathBuf>,
+    /// Files reachable from the entries, `None` without entries
+    reachable: Option<FxHashSet<PathBuf>>,
+    imported: FxHashMap<PathBuf, ImportedNames>,
+}
+
+impl Project {
+    pub(super) fn new(module_graph: &ModuleGraph, options: &ProjectOptions) -> Self {
+        let files = if options.src.is_empty() {
+            FxHashSet::default()
+        } else {
+            let walk_options =
+                WalkOptions { paths: options.src.clone(), ..module_graph.walk_options.clone() };
+            Walk::new(&walk_options)
+                .with_config_store(module_graph.config_store.clone())
+                .iter()
+                .filter_map(|path| path.canonicalize().ok())
+                .collect()
+        };
+        let entries = options
+            .entries
+            .iter()
+            .filter_map(|path| path.canonicalize().ok())
+            .collect::<FxHashSet<_>>();
+        let mut project = Self { files, entries, reachable: None, imported: FxHashMap::default() };
+        project.collect_imports(module_graph);
+        if !project.entries.is_empty() {
+            project.reachable = Some(project.collect_reachable(module_graph));
+        }
+        project
+    }
+
+    /// Whether `path` is a file of the project
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains(path)
+    }
+
+    pub fn is_entry(&self, path: &Path) -> bool {
+        self.entries.contains(path)
+    }
+
+    /// Always `true` without entries
+    pub fn is_reachable(&self, path: &Path) -> bool {
+        self.reachable.as_ref().map_or(true, |reachable| reachable.contains(path))
+    }
+
+    /// Whether the export `name` of the file at `path` is imported by a file of the project,
+    /// directly or by the re-exports of other files.
+    pub fn is_imported(&self, path: &Path, name: &str) -> bool {
+        self.imported.get(path).is_some_and(|imported| imported.contains(name))
+    }
+
+    fn collect_imports(&mut self, module_graph: &ModuleGraph) {
+        // Modules whose imported names changed, so their re-exports need to be followed again
+        let mut queue = vec![];
+        for path in &self.files {
+            let Some(module) = module_graph.module(path) else { continue };
+            for entry in &module.record.import_entries {
+                let Some(Some(dependency)) = module.dependencies.get(entry.module_request.name())
+                else {
+                    continue;
+                };
+                let imported = self.imported.entry(dependency.clone()).or_default();
+                let changed = match &entry.import_name {
+                    ImportImportName::Name(name) => imported.names.insert(name.name().clone()),
+                    ImportImportName::Default(_) => imported.names.insert("default".into()),
+                    ImportImportName::NamespaceObject => {
+                        let changed = !imported.all;
+                        imported.all = true;
+                        imported.names.insert("default".into()) || changed
+                    }
+                };
+                if changed {
+                    queue.push(dependency.clone());
+                }
+            }
+            // The exports used by `require` and `import()` are unknown
+            for specifier in &module.dynamic_requests {
+                let Some(Some(dependency)) = module.dependencies.get(specifier) else { continue };
+                let imported = self.imported.entry(dependency.clone()).or_default();
+                imported.all = true;
+                imported.names.insert("default".into());
+                queue.push(dependency.clone());
+            }
+        }
+
+        while let Some(path) = queue.pop() {
+            let Some(module) = module_graph.module(&path) else { continue };
+            let record = &module.record;
+            // Names and whether all names but `default` are imported from the re-exported modules
+            let mut forwarded = vec![];
+            if let Some(imported) = self.imported.get(&path) {
+                for entry in &record.indirect_export_entries {
+                    let Some(request) = &entry.module_request else { continue };
+                    let export_name = match &entry.export_name {
+                        ExportExportName::Name(name) => name.name().as_str(),
+                        ExportExportName::Default(_) => "default",
+                        ExportExportName::Null => continue,
+                    };
+                    if !imported.contains(export_name) {
+                        continue;
+                    }
+                    // `export { a as b } from "mod"` has the imported name as the local name,
+                    // `import { a } from "mod"; export { a as b }` as the import name
+                    match (&entry.import_name, &entry.local_name) {
+                        (ExportImportName::All, _) => forwarded.push((request.name(), None)),
+                        (ExportImportName::Name(name), _)
+                        | (ExportImportName::Null, ExportLocalName::Name(name)) => {
+                            forwarded.push((request.name(), Some(name.name().clone())));
+                        }
+                        _ => {}
+                    }
+                }
+                // `export *` exports the names which are not exported by the module itself
+                let own_names = record
+                    .local_export_entries
+                    .iter()
+                    .chain(&record.indirect_export_entries)
+                    .filter_map(|entry| match &entry.export_name {
+                        ExportExportName::Name(name) => Some(name.name()),
+                        _ => None,
+                    })
+                    .collect::<FxHashSet<_>>();
+                for entry in &record.star_export_entries {
+                    let Some(request) = &entry.module_request else { continue };
+                    if imported.all {
+                        forwarded.push((request.name(), None));
+                        continue;
+                    }
+                    for name in &imported.names {
+                        if name != &"default" && !own_names.contains(name) {
+                            forwarded.push((request.name(), Some(name.clone())));
+                        }
+                    }
+                }
+            }
+
+            for (specifier, name) in forwarded {
+                let Some(Some(dependency)) = module.dependencies.get(specifier) else { continue };
+                let imported = self.imported.entry(dependency.clone()).or_default();
+                let changed = match name {
+                    Some(name) => imported.names.insert(name),
+                    None => !std::mem::replace(&mut imported.all, true),
+                };
+                if changed {
+                    queue.push(dependency.clone());
+                }
+            }
+        }
+    }
+
+    /// Files of the project imported by the entries, directly or indirectly,
+    /// including side effect imports, `require` calls and `import()` expressions
+    fn collect_reachable(&self, module_graph: &ModuleGraph) -> FxHashSet<PathBuf> {
+        let mut reachable = FxHashSet::default();
+        let mut stack = self.entries.iter().cloned().collect::<Vec<_>>();
+        while let Some(path) = stack.pop() {
+            if !reachable.insert(path.clone()) {
+                continue;
+            }
+            let Some(module) = module_graph.module(&path) else { continue };
+            for dependency in module.dependencies.values().flatten() {
+                if self.files.contains(dependency) && !reachable.contains(dependency) {
+                    stack.push(dependency.clone());
+                }
+            }
+        }
+        reachable
+    }
+}
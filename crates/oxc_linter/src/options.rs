@@ -0,0 +1,93 @@
+use oxc_diagnostics::Severity;
+use serde_json::{Number, Value};
+
+/// The severity a rule is configured with, e.g. `-A`, `-W` and `-D` on the command line,
+/// or `"off"`, `"warn"` and `"error"` in a configuration file.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AllowWarnDeny {
+    Allow, // Off
+    Warn,  // Warn
+    Deny,  // Error
+}
+
+impl AllowWarnDeny {
+    pub fn is_enabled(self) -> bool {
+        self != Self::Allow
+    }
+
+    /// The severity of diagnostics reported by a rule configured with this value.
+    /// Returns `None` for a disabled rule.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Allow => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Deny => Some(Severity::Error),
+        }
+    }
+}
+
+impl TryFrom<&str> for AllowWarnDeny {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "allow" | "off" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "deny" | "error" => Ok(Self::Deny),
+            _ => Err(format!(
+                r#"Failed to parse rule severity, expected one of "allow", "off", "deny", "error" or "warn", but got {s:?}"#
+            )),
+        }
+    }
+}
+
+impl TryFrom<&Number> for AllowWarnDeny {
+    type Error = String;
+
+    fn try_from(value: &Number) -> Result<Self, Self::Error> {
+        match value.as_i64() {
+            Some(0) => Ok(Self::Allow),
+            Some(1) => Ok(Self::Warn),
+            Some(2) => Ok(Self::Deny),
+            _ => Err(format!(
+                r#"Failed to parse rule severity, expected one of `0`, `1` or `2`, but got {value:?}"#
+            )),
+        }
+    }
+}
+
+impl TryFrom<&Value> for AllowWarnDeny {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(s) => Self::try_from(s.as_str()),
+            Value::Number(n) => Self::try_from(n),
+            _ => Err(format!(
+                "Failed to parse rule severity, expected a string or a number, but got {value:?}"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::AllowWarnDeny;
+
+    #[test]
+    fn parse_allow_warn_deny() {
+        assert_eq!(AllowWarnDeny::try_from("allow"), Ok(AllowWarnDeny::Allow));
+        assert_eq!(AllowWarnDeny::try_from("off"), Ok(AllowWarnDeny::Allow));
+        assert_eq!(AllowWarnDeny::try_from("warn"), Ok(AllowWarnDeny::Warn));
+        assert_eq!(AllowWarnDeny::try_from("deny"), Ok(AllowWarnDeny::Deny));
+        assert_eq!(AllowWarnDeny::try_from("error"), Ok(AllowWarnDeny::Deny));
+        assert_eq!(AllowWarnDeny::try_from(&json!(0)), Ok(AllowWarnDeny::Allow));
+        assert_eq!(AllowWarnDeny::try_from(&json!(1)), Ok(AllowWarnDeny::Warn));
+        assert_eq!(AllowWarnDeny::try_from(&json!(2)), Ok(AllowWarnDeny::Deny));
+        assert!(AllowWarnDeny::try_from("foo").is_err());
+        assert!(AllowWarnDeny::try_from(&json!(3)).is_err());
+        assert!(AllowWarnDeny::try_from(&json!(true)).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! Extract the JavaScript and TypeScript embedded in files of other languages,
+//! e.g. the `<script>` blocks of Vue, Svelte and HTML files, the frontmatter of Astro files
+//! and the code blocks of Markdown files.
+
+mod markdown;
+
+use oxc_span::SourceType;
+
+pub use self::markdown::{is_markdown, MARKDOWN_RELAXED_RULES};
+
+/// Extensions of the files [`PartialLoader::parse`] extracts scripts from
+pub const LINT_PARTIAL_LOADER_EXT: [&str; 6] = ["vue", "svelte", "astro", "html", "md", "mdx"];
+
+/// A script embedded in a file, linted on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JavaScriptSource<'a> {
+    pub source_text: &'a str,
+    pub source_type: SourceType,
+    /// Offset of `source_text` in the file, to map spans back to the file
+    pub start: u32,
+}
+
+pub struct PartialLoader;
+
+impl PartialLoader {
+    /// The scripts of a file with one of the [`LINT_PARTIAL_LOADER_EXT`] extensions,
+    /// `None` for other extensions.
+    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+        let sources = match ext {
+            "vue" | "svelte" => parse_scripts(source_text, FileKind::Component),
+            "html" => parse_scripts(source_text, FileKind::Html),
+            "astro" => {
+                let mut sources = parse_frontmatter(source_text).into_iter().collect::<Vec<_>>();
+                // The closing fence of the frontmatter is not the start of a script
+                let offset = sources.first().map_or(0, |frontmatter| {
+                    frontmatter.start as usize + frontmatter.source_text.len()
+                });
+                #[allow(clippy::cast_possible_truncation)]
+                let scripts =
+                    parse_scripts(&source_text[offset..], FileKind::Astro).into_iter().map(
+                        |source| JavaScriptSource { start: source.start + offset as u32, ..source },
+                    );
+                sources.extend(scripts);
+                sources
+            }
+            "md" | "mdx" => markdown::parse_code_blocks(source_text),
+            _ => return None,
+        };
+        Some(sources)
+    }
+}
+
+/// How scripts without `lang` or `type` attributes are parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileKind {
+    /// Vue and Svelte components, whose scripts are modules
+    Component,
+    /// Scripts are scripts unless they have `type="module"`
+    Html,
+    /// Scripts are TypeScript modules
+    Astro,
+}
+
+/// The code between the `---` fences at the start of an Astro file.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn parse_frontmatter(source_text: &str) -> Option<JavaScriptSource> {
+    let trimmed = source_text.trim_start();
+    let fence_start = source_text.len() - trimmed.len();
+    let after_fence = trimmed.strip_prefix("---")?;
+    let start = fence_start + 3;
+    let end = after_fence
+        .match_indices("---")
+        .map(|(i, _)| start + i)
+        .find(|&i| source_text[..i].ends_with('\n'))?;
+    Some(JavaScriptSource {
+        source_text: &source_text[start..end],
+        source_type: SourceType::default().with_module(true).with_typescript(true),
+        start: start as u32,
+    })
+}
+
+/// The content of the `<script>` elements, skipping HTML comments.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn parse_scripts(source_text: &str, kind: FileKind) -> Vec<JavaScriptSource> {
+    // Tag names are case insensitive, lowercasing ASCII keeps the byte offsets
+    let lowercase = source_text.to_ascii_lowercase();
+    let mut sources = vec![];
+    let mut pos = 0;
+    while let Some(found) = lowercase[pos..].find('<') {
+        let start = pos + found;
+        let rest = &lowercase[start..];
+        if rest.starts_with("<!--") {
+            pos = rest.find("-->").map_or(source_text.len(), |end| start + end + 3);
+            continue;
+        }
+        let is_script = rest.strip_prefix("<script").is_some_and(|rest| {
+            rest.starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        });
+        if !is_script {
+            pos = start + 1;
+            continue;
+        }
+        let Some(tag_end) = find_tag_end(&source_text[start..]) else { break };
+        let attributes = &source_text[start + "<script".len()..start + tag_end];
+        let content_start = start + tag_end + 1;
+        if attributes.ends_with('/') {
+            // `<script src="..." />`
+            pos = content_start;
+            continue;
+        }
+        let content_end = lowercase[content_start..]
+            .find("</script")
+            .map_or(source_text.len(), |end| content_start + end);
+        if let Some(source_type) = script_source_type(attributes, kind) {
+            sources.push(JavaScriptSource {
+                source_text: &source_text[content_start..content_end],
+                source_type,
+                start: content_start as u32,
+            });
+        }
+        pos = content_end;
+    }
+    sources
+}
+
+/// Offset of the `>` closing the tag at the start of `source_text`, skipping quoted attribute values.
+fn find_tag_end(source_text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in source_text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `None` for scripts which are not JavaScript or TypeScript, e.g. `type="text/template"` or `lang="coffee"`.
+fn script_source_type(attributes: &str, kind: FileKind) -> Option<SourceType> {
+    let r#type = attribute(attributes, "type");
+    if let Some(r#type) = r#type {
+        let is_javascript = matches!(
+            r#type.to_ascii_lowercase().as_str(),
+            "" | "module" | "text/javascript" | "application/javascript" | "text/babel"
+        );
+        if !is_javascript {
+            return None;
+        }
+    }
+    let is_module = kind != FileKind::Html || r#type == Some("module");
+    let source_type = SourceType::default().with_module(is_module);
+    let lang = attribute(attributes, "lang").map(str::to_ascii_lowercase);
+    let source_type = match lang.as_deref() {
+        None if kind == FileKind::Astro => source_type.with_typescript(true),
+        None | Some("js" | "javascript") => source_type,
+        Some("jsx") => source_type.with_jsx(true),
+        Some("ts" | "typescript") => source_type.with_typescript(true),
+        Some("tsx") => source_type.with_typescript(true).with_jsx(true),
+        Some(_) => return None,
+    };
+    Some(source_type)
+}
+
+/// The value of the attribute `name`, the empty string for attributes without a value.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let attribute_name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+        let mut value = "";
+        if let Some(after_equals) = rest.strip_prefix('=') {
+            let after_equals = after_equals.trim_start();
+            let (value_start, quote) = match after_equals.chars().next() {
+                Some(quote @ ('"' | '\'')) => (1, Some(quote)),
+                _ => (0, None),
+            };
+            let value_end = after_equals[value_start..]
+                .find(|c: char| quote.map_or(c.is_ascii_whitespace(), |quote| c == quote))
+                .map_or(after_equals.len(), |end| value_start + end);
+            value = &after_equals[value_start..value_end];
+            rest = after_equals.get(value_end + usize::from(quote.is_some())..).unwrap_or_default();
+        }
+        if attribute_name.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::{attribute, PartialLoader};
+
+    fn parse<'a>(ext: &str, source_text: &'a str) -> Vec<(&'a str, SourceType, u32)> {
+        PartialLoader::parse(ext, source_text)
+            .unwrap()
+            .into_iter()
+            .map(|source| {
+                let start = source.start as usize;
+                assert_eq!(
+                    &source_text[start..start + source.source_text.len()],
+                    source.source_text
+                );
+                (source.source_text, source.source_type, source.start)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn attributes() {
+        let attributes = r#" setup lang="ts" type='module' src=a.js"#;
+        assert_eq!(attribute(attributes, "setup"), Some(""));
+        assert_eq!(attribute(attributes, "lang"), Some("ts"));
+        assert_eq!(attribute(attributes, "type"), Some("module"));
+        assert_eq!(attribute(attributes, "src"), Some("a.js"));
+        assert_eq!(attribute(attributes, "context"), None);
+        assert_eq!(attribute(r#" data-lang="ts""#, "lang"), None);
+    }
+
+    #[test]
+    fn vue() {
+        let module = SourceType::default().with_module(true);
+        let source_text = r#"<template><div>{{ a }}</div></template>
+<script>
+export default {}
+</script>
+<script setup lang="ts">
+const a: number = 1
+</script>
+<style>div { color: red; }</style>"#;
+        let sources = parse("vue", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].0, "\nexport default {}\n");
+        assert_eq!(sources[0].1, module);
+        assert_eq!(sources[1].0, "\nconst a: number = 1\n");
+        assert_eq!(sources[1].1, module.with_typescript(true));
+
+        let sources = parse("vue", "<script lang=\"tsx\">a</script>");
+        assert_eq!(sources[0].1, module.with_typescript(true).with_jsx(true));
+        assert!(parse("vue", "<template><div></div></template>").is_empty());
+    }
+
+    #[test]
+    fn svelte() {
+        let source_text =
+            "<script context=\"module\">a</script>\n<SCRIPT lang='ts'>b</SCRIPT>\n<h1>{name}</h1>";
+        let sources = parse("svelte", source_text);
+        assert_eq!(sources.iter().map(|source| source.0).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(sources[1].2, 54);
+    }
+
+    #[test]
+    fn html() {
+        let source_text = r#"<!-- <script>commented</script> -->
+<script src="a.js" />
+<script type="text/template"><div></div></script>
+<script>var a = "</div>";</script>
+<script type="module">import a from "a";</script>
+<scripts>not a script</scripts>"#;
+        let sources = parse("html", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].0, r#"var a = "</div>";"#);
+        assert_eq!(sources[0].1, SourceType::default());
+        assert_eq!(sources[1].0, r#"import a from "a";"#);
+        assert_eq!(sources[1].1, SourceType::default().with_module(true));
+    }
+
+    #[test]
+    fn astro() {
+        let typescript = SourceType::default().with_module(true).with_typescript(true);
+        let source_text = "---\nconst a: string = '---';\n---\n<h1>{a}</h1>\n<script>b</script>";
+        let sources = parse("astro", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0], ("\nconst a: string = '---';\n", typescript, 3));
+        assert_eq!(sources[1].0, "b");
+        assert_eq!(sources[1].1, typescript);
+        assert_eq!(parse("astro", "<h1>a</h1>").len(), 0);
+    }
+
+    #[test]
+    fn unknown_extension() {
+        assert!(PartialLoader::parse("js", "<script>a</script>").is_none());
+    }
+}
@@ -0,0 +1,191 @@
+//! Fenced code blocks of Markdown and MDX files, like `eslint-plugin-markdown`.
+
+use std::path::Path;
+
+use oxc_span::SourceType;
+
+use super::JavaScriptSource;
+
+/// Rules turned off for code blocks, the same as the recommended configuration of `eslint-plugin-markdown`,
+/// since code samples are usually incomplete programs.
+/// They can be turned on again by `overrides` for Markdown files.
+pub const MARKDOWN_RELAXED_RULES: [&str; 7] = [
+    "eol-last",
+    "no-undef",
+    "no-unused-expressions",
+    "no-unused-vars",
+    "padded-blocks",
+    "strict",
+    "unicode-bom",
+];
+
+/// A comment which skips the code block after it
+const SKIP_COMMENT: &str = "<!-- eslint-skip -->";
+
+pub fn is_markdown(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md" || ext == "mdx")
+}
+
+/// An opened code fence
+struct Fence {
+    char: char,
+    len: usize,
+    /// `None` for code blocks which are not linted
+    source_type: Option<SourceType>,
+    content_start: usize,
+}
+
+/// The fenced code blocks whose info string is a JavaScript or TypeScript language, e.g. ```` ```ts ````.
+pub fn parse_code_blocks(source_text: &str) -> Vec<JavaScriptSource> {
+    let mut sources = vec![];
+    let mut fence: Option<Fence> = None;
+    let mut skip_next = false;
+    let mut line_start = 0;
+    for line in source_text.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+        if let Some(open) = &fence {
+            if is_closing_fence(line, open) {
+                if let Some(source_type) = open.source_type {
+                    sources.push(source(source_text, open.content_start, start, source_type));
+                }
+                fence = None;
+            }
+            continue;
+        }
+        if let Some((char, len, info)) = opening_fence(line) {
+            let source_type = if skip_next { None } else { info_source_type(info) };
+            fence = Some(Fence { char, len, source_type, content_start: line_start });
+            skip_next = false;
+        } else if line.trim() == SKIP_COMMENT {
+            skip_next = true;
+        } else if !line.trim().is_empty() {
+            skip_next = false;
+        }
+    }
+    // A code block which is not closed ends with the document
+    if let Some(Fence { source_type: Some(source_type), content_start, .. }) = fence {
+        sources.push(source(source_text, content_start, source_text.len(), source_type));
+    }
+    sources
+}
+
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn source(
+    source_text: &str,
+    start: usize,
+    end: usize,
+    source_type: SourceType,
+) -> JavaScriptSource {
+    JavaScriptSource { source_text: &source_text[start..end], source_type, start: start as u32 }
+}
+
+/// Fences are indented by at most 3 spaces, deeper indented lines are indented code blocks.
+fn strip_indentation(line: &str) -> Option<&str> {
+    let content = line.trim_start_matches(' ');
+    (line.len() - content.len() <= 3).then_some(content)
+}
+
+/// The fence character, its length and the info string.
+fn opening_fence(line: &str) -> Option<(char, usize, &str)> {
+    let line = strip_indentation(line)?;
+    let char = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.len() - line.trim_start_matches(char).len();
+    if len < 3 {
+        return None;
+    }
+    let info = line[len..].trim();
+    // Backticks in the info string would be inline code
+    if char == '`' && info.contains('`') {
+        return None;
+    }
+    Some((char, len, info))
+}
+
+fn is_closing_fence(line: &str, fence: &Fence) -> bool {
+    let Some(line) = strip_indentation(line) else { return false };
+    let rest = line.trim_start_matches(fence.char);
+    line.len() - rest.len() >= fence.len && rest.trim().is_empty()
+}
+
+/// The language is the first word of the info string, e.g. `js` of ```` ```js title="a.js" ````.
+fn info_source_type(info: &str) -> Option<SourceType> {
+    let language = info
+        .split(|c: char| c.is_whitespace() || c == '{' || c == ',')
+        .next()?
+        .to_ascii_lowercase();
+    let source_type = SourceType::default().with_module(true);
+    match language.as_str() {
+        "js" | "javascript" | "mjs" | "cjs" => Some(source_type),
+        "jsx" => Some(source_type.with_jsx(true)),
+        "ts" | "typescript" | "mts" | "cts" => Some(source_type.with_typescript(true)),
+        "tsx" => Some(source_type.with_typescript(true).with_jsx(true)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_span::SourceType;
+
+    use super::{is_markdown, parse_code_blocks};
+
+    #[test]
+    fn code_blocks() {
+        let source_text = "# Title
+
+```js
+const a = 1;
+```
+
+```sh
+npm install
+```
+
+~~~~tsx title=\"a.tsx\"
+const b = <div />;
+```
+~~~~
+
+  ```TypeScript {1}
+  let c: number;
+  ```
+";
+        let sources = parse_code_blocks(source_text);
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].source_text, "const a = 1;\n");
+        assert_eq!(sources[0].start, 15);
+        assert_eq!(sources[0].source_type, SourceType::default().with_module(true));
+        assert_eq!(sources[1].source_text, "const b = <div />;\n```\n");
+        assert_eq!(
+            sources[1].source_type,
+            SourceType::default().with_module(true).with_typescript(true).with_jsx(true)
+        );
+        assert_eq!(sources[2].source_text, "  let c: number;\n");
+        for source in sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+
+    #[test]
+    fn skip_and_unclosed() {
+        let source_text = "<!-- eslint-skip -->\n\n```js\nskipped\n```\n\n```js\nnot closed\n";
+        let sources = parse_code_blocks(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "not closed\n");
+
+        let source_text = "    ```js\n    indented code\n    ```\n``js\n``\n";
+        assert!(parse_code_blocks(source_text).is_empty());
+    }
+
+    #[test]
+    fn markdown_path() {
+        assert!(is_markdown(Path::new("docs/README.md")));
+        assert!(is_markdown(Path::new("page.mdx")));
+        assert!(!is_markdown(Path::new("index.js")));
+    }
+}
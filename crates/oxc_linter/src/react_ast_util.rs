@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentTarget, BindingPatternKind, CallExpression, Class, Expression,
+        JSXAttribute, JSXAttributeItem, JSXAttributeName, JSXElementName, JSXMemberExpression,
+        JSXMemberExpressionObject, JSXOpeningElement, MemberExpression, ObjectExpression,
+        ObjectProperty, ObjectPropertyKind, SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_semantic::AstNode;
+use oxc_span::{Atom, Span};
+
+use crate::{ast_util::get_name_from_property_key, context::LintContext};
+
+/// `use`, or `use` followed by an uppercase letter or a digit, e.g. `useState`
+pub fn is_hook_name(name: &str) -> bool {
+    name == "use"
+        || name.strip_prefix("use").is_some_and(|rest| {
+            rest.starts_with(|c: char| c.is_ascii_uppercase() || c.is_ascii_digit())
+        })
+}
+
+/// Components are functions whose name starts with an uppercase letter, e.g. `Button`
+pub fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// The name of the hook called by `useFoo()` or `React.useFoo()`.
+/// Hooks of other objects are only called by PascalCase names, e.g. `Foo.useBar()`
+pub fn get_hook_name<'a>(call: &'a CallExpression<'a>) -> Option<&'a str> {
+    match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => is_hook_name(&ident.name).then_some(ident.name.as_str()),
+        Expression::MemberExpression(member)
+            if matches!(**member, MemberExpression::StaticMemberExpression(_)) =>
+        {
+            let Expression::Identifier(object) = member.object() else { return None };
+            let name = member.static_property_name()?;
+            (is_component_name(&object.name) && is_hook_name(name)).then_some(name)
+        }
+        _ => None,
+    }
+}
+
+/// The name of a `Function` or `ArrowExpression` node: the name of the function,
+/// or the name it is assigned to, e.g. `Foo` of `const Foo = () => {}`
+pub fn get_function_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Atom> {
+    if let AstKind::Function(func) = node.kind() {
+        if let Some(id) = &func.id {
+            return Some(id.name.clone());
+        }
+    }
+    let parent = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+        !matches!(parent.kind(), AstKind::ParenthesizedExpression(_) | AstKind::Argument(_))
+    })?;
+    match parent.kind() {
+        AstKind::VariableDeclarator(decl) => match &decl.id.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+            _ => None,
+        },
+        AstKind::AssignmentExpression(assign) => match &assign.left {
+            AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+            ) => Some(ident.name.clone()),
+            _ => None,
+        },
+        AstKind::ObjectProperty(prop) => get_name_from_property_key(&prop.key),
+        AstKind::MethodDefinition(method) => get_name_from_property_key(&method.key),
+        AstKind::PropertyDefinition(prop) => get_name_from_property_key(&prop.key),
+        _ => None,
+    }
+}
+
+/// Whether the function is the argument of `memo` or `forwardRef`,
+/// e.g. `memo(function (props) {})`
+pub fn is_component_wrapper_callback(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(AstKind::CallExpression(call)) = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| {
+            !matches!(parent.kind(), AstKind::ParenthesizedExpression(_) | AstKind::Argument(_))
+        })
+        .map(AstNode::kind)
+    else {
+        return false;
+    };
+    let name = match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::MemberExpression(member) => {
+            if !member.object().is_specific_id("React") {
+                return false;
+            }
+            let Some(name) = member.static_property_name() else { return false };
+            name
+        }
+        _ => return false,
+    };
+    matches!(name, "memo" | "forwardRef")
+}
+
+/// The names JSX is compiled to references of, see [`LintContext::jsx_pragma`]
+#[derive(Debug)]
+pub struct JsxPragma {
+    /// The object `createElement` is called on, e.g. `React` or `h` of `@jsx h`
+    pub pragma: String,
+    /// The expression fragments are compiled to, e.g. `React.Fragment`
+    pub fragment: String,
+    /// The automatic runtime imports its own functions instead
+    pub automatic: bool,
+}
+
+impl JsxPragma {
+    /// Configured by `@jsx`, `@jsxFrag` and `@jsxRuntime` comments,
+    /// falling back to `settings.react`
+    pub fn from_context(ctx: &LintContext) -> Self {
+        let react = &ctx.settings().react;
+        let mut pragma = react.pragma();
+        let mut fragment = None;
+        let mut automatic = false;
+        for (start, comment) in ctx.semantic().trivias().comments() {
+            let text = Span::new(*start, comment.end()).source_text(ctx.source_text());
+            for directive in text.split('@').skip(1) {
+                let mut parts = directive.split_whitespace();
+                match (parts.next(), parts.next()) {
+                    (Some("jsx"), Some(name)) => pragma = name,
+                    (Some("jsxFrag"), Some(name)) => fragment = Some(name),
+                    (Some("jsxRuntime"), Some(runtime)) => automatic = runtime == "automatic",
+                    _ => {}
+                }
+            }
+        }
+        // `@jsx h.createElement` calls `h.createElement`, the pragma is its object
+        let pragma = pragma.split('.').next().unwrap_or(pragma);
+        let fragment =
+            fragment.map_or_else(|| format!("{pragma}.{}", react.fragment()), ToString::to_string);
+        Self { pragma: pragma.to_string(), fragment, automatic }
+    }
+}
+
+/// `React.createElement(...)`, of the configured pragma
+pub fn is_create_element_call(call: &CallExpression, ctx: &LintContext) -> bool {
+    let Expression::MemberExpression(member) = call.callee.get_inner_expression() else {
+        return false;
+    };
+    member.static_property_name() == Some("createElement")
+        && member.object().is_specific_id(&ctx.jsx_pragma().pragma)
+}
+
+/// `createReactClass({...})` or `React.createClass({...})`
+pub fn is_create_class_call(call: &CallExpression, ctx: &LintContext) -> bool {
+    match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == "createReactClass",
+        Expression::MemberExpression(member) => {
+            member.static_property_name() == Some("createClass")
+                && member.object().is_specific_id(&ctx.jsx_pragma().pragma)
+        }
+        _ => false,
+    }
+}
+
+/// Classes extending `Component` or `PureComponent`, e.g. `class Foo extends React.Component {}`
+pub fn is_es6_component(class: &Class, ctx: &LintContext) -> bool {
+    let is_component_class = |name: &str| matches!(name, "Component" | "PureComponent");
+    match class.super_class.as_ref().map(Expression::get_inner_expression) {
+        Some(Expression::Identifier(ident)) => is_component_class(&ident.name),
+        Some(Expression::MemberExpression(member)) => {
+            member.static_property_name().is_some_and(is_component_class)
+                && member.object().is_specific_id(&ctx.jsx_pragma().pragma)
+        }
+        _ => false,
+    }
+}
+
+/// The closest class component or `createReactClass` object `node` is in
+pub fn get_parent_component<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| match parent.kind() {
+        AstKind::Class(class) => is_es6_component(class, ctx),
+        AstKind::ObjectExpression(_) => {
+            let mut ancestors = ctx.nodes().iter_parents(parent.id()).skip(1);
+            matches!(ancestors.next().map(AstNode::kind), Some(AstKind::Argument(_)))
+                && matches!(
+                    ancestors.next().map(AstNode::kind),
+                    Some(AstKind::CallExpression(call)) if is_create_class_call(call, ctx)
+                )
+        }
+        _ => false,
+    })
+}
+
+/// The name of the element, e.g. `div`, `Foo.Bar` or `svg:path`
+pub fn get_element_type<'a>(name: &'a JSXElementName<'a>) -> Cow<'a, str> {
+    match name {
+        JSXElementName::Identifier(ident) => Cow::Borrowed(ident.name.as_str()),
+        JSXElementName::NamespacedName(name) => {
+            Cow::Owned(format!("{}:{}", name.namespace.name, name.property.name))
+        }
+        JSXElementName::MemberExpression(member) => Cow::Owned(get_member_expression_name(member)),
+    }
+}
+
+fn get_member_expression_name(member: &JSXMemberExpression) -> String {
+    let object = match &member.object {
+        JSXMemberExpressionObject::Identifier(ident) => ident.name.to_string(),
+        JSXMemberExpressionObject::MemberExpression(member) => get_member_expression_name(member),
+    };
+    format!("{object}.{}", member.property.name)
+}
+
+/// The name of the prop, e.g. `onClick` or `xlink:href`
+pub fn get_prop_name<'a>(name: &'a JSXAttributeName<'a>) -> Cow<'a, str> {
+    match name {
+        JSXAttributeName::Identifier(ident) => Cow::Borrowed(ident.name.as_str()),
+        JSXAttributeName::NamespacedName(name) => {
+            Cow::Owned(format!("{}:{}", name.namespace.name, name.property.name))
+        }
+    }
+}
+
+/// The attribute `name` of the element, e.g. `key` of `<div key={1} />`
+pub fn get_jsx_attribute<'a>(
+    element: &'a JSXOpeningElement<'a>,
+    name: &str,
+) -> Option<&'a JSXAttribute<'a>> {
+    element.attributes.iter().find_map(|item| match item {
+        JSXAttributeItem::Attribute(attr) if get_prop_name(&attr.name) == name => Some(&**attr),
+        _ => None,
+    })
+}
+
+/// The property `name` of an object literal, e.g. the props of `React.createElement("div", { key })`
+pub fn get_object_property<'a>(
+    object: &'a ObjectExpression<'a>,
+    name: &str,
+) -> Option<&'a ObjectProperty<'a>> {
+    object.properties.iter().find_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop)
+            if !prop.computed
+                && get_name_from_property_key(&prop.key).is_some_and(|key| key == name) =>
+        {
+            Some(&**prop)
+        }
+        _ => None,
+    })
+}
+
+/// The props object literal of `React.createElement(type, props, ...children)`
+pub fn get_create_element_props<'a>(
+    call: &'a CallExpression<'a>,
+) -> Option<&'a ObjectExpression<'a>> {
+    match call.arguments.get(1)? {
+        Argument::Expression(expr) => match expr.get_inner_expression() {
+            Expression::ObjectExpression(object) => Some(object),
+            _ => None,
+        },
+        Argument::SpreadElement(_) => None,
+    }
+}
@@ -1,8 +1,9 @@
 use std::fmt;
 
+use oxc_ast::AstType;
 use oxc_semantic::SymbolId;
 
-use crate::{context::LintContext, AstNode};
+use crate::{context::LintContext, AllowWarnDeny, AstNode, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -10,6 +11,10 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// The types of the AST nodes [`Rule::run`] handles, it is only called for nodes of these types.
+    /// `None` to visit all nodes, `Some(&[])` for rules which do not implement [`Rule::run`].
+    const NODE_TYPES: Option<&'static [AstType]> = None;
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -28,6 +33,164 @@ pub trait RuleMeta {
     fn documentation() -> Option<&'static str> {
         None
     }
+
+    /// The source file declaring the rule, as given by `file!()`
+    const SOURCE_PATH: &'static str;
+}
+
+/// A rule together with the severity it is configured with.
+#[derive(Debug, Clone)]
+pub struct RuleWithSeverity {
+    pub rule: RuleEnum,
+    pub severity: AllowWarnDeny,
+    /// The configuration `rule` was created from by [`Rule::from_configuration`], `None` for the defaults
+    pub options: Option<serde_json::Value>,
+}
+
+impl RuleWithSeverity {
+    pub fn new(rule: RuleEnum, severity: AllowWarnDeny) -> Self {
+        Self { rule, severity, options: None }
+    }
+
+    #[must_use]
+    pub fn with_options(mut self, options: Option<serde_json::Value>) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// The rules to run on the nodes of each [`AstType`], declared by [`Rule::NODE_TYPES`],
+/// so rules are not called for nodes they do not handle.
+#[derive(Debug)]
+pub struct RuleDispatch {
+    /// Indices of the rules by `AstType as usize`, in the order of the rules
+    rules_by_type: Vec<Vec<usize>>,
+}
+
+impl RuleDispatch {
+    pub fn new(rules: &[RuleWithSeverity]) -> Self {
+        let mut rules_by_type = vec![vec![]; AstType::COUNT];
+        for (index, rule) in rules.iter().enumerate() {
+            match rule.rule.node_types() {
+                None => rules_by_type.iter_mut().for_each(|indices| indices.push(index)),
+                Some(types) => {
+                    for ty in types {
+                        let indices = &mut rules_by_type[*ty as usize];
+                        if indices.last() != Some(&index) {
+                            indices.push(index);
+                        }
+                    }
+                }
+            }
+        }
+        Self { rules_by_type }
+    }
+
+    /// Indices of the rules to run on nodes of type `ty`.
+    pub fn rules(&self, ty: AstType) -> &[usize] {
+        &self.rules_by_type[ty as usize]
+    }
+}
+
+/// Metadata of the rule that reported a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleInfo {
+    /// The plugin the rule belongs to, e.g. `eslint`, `typescript` or `jest`
+    pub plugin: &'static str,
+    pub name: &'static str,
+    pub category: RuleCategory,
+    /// See [`RuleMeta::documentation`]
+    pub documentation: Option<&'static str>,
+    /// See [`RuleMeta::SOURCE_PATH`]
+    pub source_path: &'static str,
+}
+
+impl RuleInfo {
+    pub fn new(rule: &RuleEnum) -> Self {
+        Self {
+            plugin: rule.plugin_name(),
+            name: rule.name(),
+            category: rule.category(),
+            documentation: rule.documentation(),
+            source_path: rule.source_path(),
+        }
+    }
+
+    /// The rule name used in configuration files and disable directives,
+    /// e.g. `no-debugger`, `@typescript-eslint/no-namespace` or `jest/no-focused-tests`
+    pub fn rule_id(&self) -> String {
+        if self.plugin == "eslint" {
+            return self.eslint_name().to_string();
+        }
+        let prefix = PLUGIN_PREFIXES
+            .iter()
+            .find_map(|(plugin, prefix)| (*plugin == self.plugin).then_some(*prefix))
+            .unwrap_or(self.plugin);
+        format!("{prefix}/{}", self.name)
+    }
+
+    /// The name of the rule in ESLint, which differs from `name` for the rules of [`RULE_NAME_ALIASES`],
+    /// e.g. `eqeqeq` for `eq-eq-eq`
+    pub fn eslint_name(&self) -> &'static str {
+        if self.plugin != "eslint" {
+            return self.name;
+        }
+        RULE_NAME_ALIASES
+            .iter()
+            .find_map(|(alias, rule_name)| (*rule_name == self.name).then_some(*alias))
+            .unwrap_or(self.name)
+    }
+
+    /// The rule is documented by its [`RuleMeta::documentation`] in the source file,
+    /// e.g. `rules/eslint/no_debugger.rs` or `rules/eslint/array_callback_return/mod.rs`.
+    pub fn documentation_url(&self) -> Option<String> {
+        self.documentation?;
+        let source_path = self.source_path.replace('\\', "/");
+        let (_, rule_path) = source_path.split_once("src/rules/")?;
+        Some(format!(
+            "https://github.com/web-infra-dev/oxc/blob/main/crates/oxc_linter/src/rules/{rule_path}"
+        ))
+    }
+}
+
+/// Plugins whose prefix in rule names differs from the plugin name
+const PLUGIN_PREFIXES: &[(&str, &str)] =
+    &[("typescript", "@typescript-eslint"), ("react_hooks", "react-hooks")];
+
+/// The plugin name of the prefix of a rule name in configuration files and disable directives,
+/// e.g. `@typescript-eslint` => `typescript`, `react-hooks` => `react_hooks`, `@foo` => `foo`
+pub fn plugin_name_of_prefix(prefix: &str) -> &str {
+    PLUGIN_PREFIXES
+        .iter()
+        .find_map(|(plugin, plugin_prefix)| (*plugin_prefix == prefix).then_some(*plugin))
+        .unwrap_or_else(|| prefix.trim_start_matches('@'))
+}
+
+/// ESLint rules whose name differs from the name of the oxc rule, as (ESLint name, oxc name)
+const RULE_NAME_ALIASES: &[(&str, &str)] = &[("eqeqeq", "eq-eq-eq")];
+
+/// The name of the oxc rule of a rule name in configuration files and disable directives,
+/// e.g. `eqeqeq` => `eq-eq-eq`
+pub fn rule_name_of_alias(name: &str) -> &str {
+    RULE_NAME_ALIASES
+        .iter()
+        .find_map(|(alias, rule_name)| (*alias == name).then_some(*rule_name))
+        .unwrap_or(name)
+}
+
+/// Rendered in front of diagnostic messages, e.g. `eslint(no-debugger)`
+impl fmt::Display for RuleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plugin = match self.plugin {
+            "typescript" => "typescript-eslint",
+            "jest" => "eslint-plugin-jest",
+            "import" => "eslint-plugin-import",
+            "react" => "eslint-plugin-react",
+            "react_hooks" => "eslint-plugin-react-hooks",
+            plugin => plugin,
+        };
+        write!(f, "{plugin}({})", self.eslint_name())
+    }
 }
 
 /// Rule categories defined by rust-clippy
@@ -80,7 +243,10 @@ impl fmt::Display for RuleCategory {
 
 #[cfg(test)]
 mod test {
-    use crate::RULES;
+    use oxc_ast::AstType;
+
+    use super::{plugin_name_of_prefix, RuleDispatch, RuleInfo, RuleWithSeverity};
+    use crate::{AllowWarnDeny, RULES};
 
     #[test]
     fn ensure_documentation() {
@@ -89,4 +255,67 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    #[test]
+    fn rule_info() {
+        let info =
+            |name: &str| RuleInfo::new(RULES.iter().find(|rule| rule.name() == name).unwrap());
+
+        let no_debugger = info("no-debugger");
+        assert_eq!(no_debugger.to_string(), "eslint(no-debugger)");
+        assert_eq!(no_debugger.rule_id(), "no-debugger");
+        assert_eq!(
+            no_debugger.documentation_url().as_deref(),
+            Some("https://github.com/web-infra-dev/oxc/blob/main/crates/oxc_linter/src/rules/eslint/no_debugger.rs")
+        );
+
+        // Reported by the ESLint name of the rule
+        let eq_eq_eq = info("eq-eq-eq");
+        assert_eq!(eq_eq_eq.to_string(), "eslint(eqeqeq)");
+        assert_eq!(eq_eq_eq.rule_id(), "eqeqeq");
+
+        // Rules with submodules are declared in `mod.rs`
+        assert_eq!(
+            info("array-callback-return").documentation_url().as_deref(),
+            Some("https://github.com/web-infra-dev/oxc/blob/main/crates/oxc_linter/src/rules/eslint/array_callback_return/mod.rs")
+        );
+
+        let no_namespace = info("no-namespace");
+        assert_eq!(no_namespace.to_string(), "typescript-eslint(no-namespace)");
+        assert_eq!(no_namespace.rule_id(), "@typescript-eslint/no-namespace");
+
+        let no_focused_tests = info("no-focused-tests");
+        assert_eq!(no_focused_tests.to_string(), "eslint-plugin-jest(no-focused-tests)");
+        assert_eq!(no_focused_tests.rule_id(), "jest/no-focused-tests");
+
+        let rules_of_hooks = info("rules-of-hooks");
+        assert_eq!(rules_of_hooks.to_string(), "eslint-plugin-react-hooks(rules-of-hooks)");
+        assert_eq!(rules_of_hooks.rule_id(), "react-hooks/rules-of-hooks");
+
+        let jsx_key = info("jsx-key");
+        assert_eq!(jsx_key.to_string(), "eslint-plugin-react(jsx-key)");
+        assert_eq!(jsx_key.rule_id(), "react/jsx-key");
+
+        for info in RULES.iter().map(RuleInfo::new) {
+            let rule_id = info.rule_id();
+            let plugin = rule_id
+                .rsplit_once('/')
+                .map_or("eslint", |(prefix, _)| plugin_name_of_prefix(prefix));
+            assert_eq!(plugin, info.plugin, "{rule_id}");
+        }
+    }
+
+    #[test]
+    fn rule_dispatch() {
+        let rules = ["no-debugger", "no-undef", "no-empty", "no-bitwise"]
+            .iter()
+            .map(|name| RULES.iter().find(|rule| rule.name() == *name).unwrap().clone())
+            .map(|rule| RuleWithSeverity::new(rule, AllowWarnDeny::Deny))
+            .collect::<Vec<_>>();
+        let dispatch = RuleDispatch::new(&rules);
+        assert_eq!(dispatch.rules(AstType::DebuggerStatement), [0]);
+        assert_eq!(dispatch.rules(AstType::BlockStatement), [2]);
+        assert_eq!(dispatch.rules(AstType::BinaryExpression), [3]);
+        assert!(dispatch.rules(AstType::Program).is_empty());
+    }
 }
@@ -66,6 +66,7 @@ mod eslint {
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
+    pub mod no_unused_vars;
     pub mod no_useless_catch;
     pub mod no_useless_escape;
     pub mod require_yield;
@@ -95,6 +96,35 @@ mod jest {
     pub mod valid_describe_callback;
 }
 
+mod react {
+    pub mod jsx_key;
+    pub mod jsx_no_duplicate_props;
+    pub mod jsx_no_target_blank;
+    pub mod jsx_no_undef;
+    pub mod no_children_prop;
+    pub mod no_danger_with_children;
+    pub mod no_direct_mutation_state;
+    pub mod no_string_refs;
+    pub mod no_unescaped_entities;
+    pub mod void_dom_elements_no_children;
+}
+
+mod react_hooks {
+    pub mod exhaustive_deps;
+    pub mod rules_of_hooks;
+}
+
+mod import {
+    pub mod default;
+    pub mod export;
+    pub mod named;
+    pub mod namespace;
+    pub mod no_cycle;
+    pub mod no_self_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
+}
+
 oxc_macros::declare_all_lint_rules! {
     deepscan::bad_array_method_on_arguments,
     deepscan::bad_bitwise_operator,
@@ -153,6 +183,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
+    eslint::no_unused_vars,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
     eslint::require_yield,
@@ -174,4 +205,24 @@ oxc_macros::declare_all_lint_rules! {
     jest::no_test_prefixes,
     jest::no_focused_tests,
     jest::valid_describe_callback,
+    import::default,
+    import::export,
+    import::named,
+    import::namespace,
+    import::no_cycle,
+    import::no_self_import,
+    import::no_unresolved,
+    import::no_unused_modules,
+    react::jsx_key,
+    react::jsx_no_duplicate_props,
+    react::jsx_no_target_blank,
+    react::jsx_no_undef,
+    react::no_children_prop,
+    react::no_danger_with_children,
+    react::no_direct_mutation_state,
+    react::no_string_refs,
+    react::no_unescaped_entities,
+    react::void_dom_elements_no_children,
+    react_hooks::exhaustive_deps,
+    react_hooks::rules_of_hooks,
 }
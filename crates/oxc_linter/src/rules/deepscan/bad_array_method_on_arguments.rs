@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -47,6 +47,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadArrayMethodOnArguments {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::IdentifierReference]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if !node.kind().is_specific_id_reference("arguments") {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Argument, AstKind};
+use oxc_ast::{ast::Argument, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -36,6 +36,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadRemoveEventListener {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
         let Some(member) = call_expr.callee.get_member_expr() else { return };
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -38,6 +38,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NumberArgOutOfRange {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(expr) = node.kind() else {
             return ;
@@ -2,7 +2,7 @@ pub mod return_checker;
 
 use oxc_ast::{
     ast::{ChainElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -23,14 +23,14 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 enum ArrayCallbackReturnDiagnostic {
-    #[error("eslint(array-callback-return): Missing return on some path for array method {0:?}")]
+    #[error("Missing return on some path for array method {0:?}")]
     #[diagnostic(
         severity(warning),
         help("Array method {0:?} needs to have valid return on all code paths")
     )]
     ExpectReturn(Atom, #[label] Span),
 
-    #[error("eslint(array-callback-return): Unexpected return for array method {0}")]
+    #[error("Unexpected return for array method {0}")]
     #[diagnostic(
         severity(warning),
         help("Array method {0} expects no useless return from the function")
@@ -69,6 +69,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for ArrayCallbackReturn {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::ArrowExpression, AstType::Function]);
+
     fn from_configuration(value: Value) -> Self {
         let (check_for_each, allow_implicit_return) =
             value.get(0).map_or((false, false), |config| {
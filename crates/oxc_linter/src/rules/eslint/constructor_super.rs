@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, MethodDefinitionKind, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,12 +12,12 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(constructor-super): Expected to call 'super()'.")]
+#[error("Expected to call 'super()'.")]
 #[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
 struct ConstructorSuperDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(constructor-super): Unexpected 'super()' because 'super' is not a constructor.")]
+#[error("Unexpected 'super()' because 'super' is not a constructor.")]
 #[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
 struct SuperNotConstructorDiagnostic(
     #[label("unexpected 'super()'")] pub Span,
@@ -46,6 +46,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ConstructorSuper {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MethodDefinition]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MethodDefinition(ctor) = node.kind() else { return };
         if ctor.kind != MethodDefinitionKind::Constructor || ctor.value.body.is_none() {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,7 +13,7 @@ use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(eqeqeq): Expected {1} and instead saw {0}")]
+#[error("Expected {1} and instead saw {0}")]
 #[diagnostic(severity(warning), help("Prefer {1} operator"))]
 struct EqEqEqDiagnostic(&'static str, &'static str, #[label] pub Span);
 
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for EqEqEq {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::BinaryExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj1 = value.get(0);
         let obj2 = value.get(1);
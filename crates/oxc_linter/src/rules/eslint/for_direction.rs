@@ -3,7 +3,7 @@ use oxc_ast::{
         AssignmentExpression, AssignmentTarget, Expression, IdentifierReference,
         SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -16,9 +16,7 @@ use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator, Up
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(for-direction): The update clause in this loop moves the variable in the wrong direction"
-)]
+#[error("The update clause in this loop moves the variable in the wrong direction")]
 #[diagnostic(severity(warning), help("Use while loop for intended infinite loop"))]
 struct ForDirectionDiagnostic(
     #[label("This test moves in the wrong direction")] pub Span, /*test clause */
@@ -46,6 +44,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ForDirection {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ForStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ForStatement(for_loop) = node.kind() {
             if let Some(Expression::BinaryExpression(test)) = &for_loop.test {
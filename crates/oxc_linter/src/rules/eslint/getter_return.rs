@@ -3,7 +3,7 @@ use oxc_ast::{
         Argument, CallExpression, ChainElement, Expression, FunctionBody, MemberExpression,
         MethodDefinitionKind, ObjectExpression, ObjectPropertyKind, PropertyKind,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -16,7 +16,7 @@ use super::array_callback_return::return_checker;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(getter-return): Expected to always return a value in getter.")]
+#[error("Expected to always return a value in getter.")]
 #[diagnostic(severity(warning), help("Return a value from all code paths in getter."))]
 struct GetterReturnDiagnostic(#[label] pub Span);
 
@@ -111,6 +111,9 @@ impl GetterReturn {
 }
 
 impl Rule for GetterReturn {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::MethodDefinition, AstType::ObjectProperty, AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::MethodDefinition(method) if method.kind == MethodDefinitionKind::Get => {
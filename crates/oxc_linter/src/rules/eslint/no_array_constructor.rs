@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-array-constructor): Disallow `Array` constructors")]
+#[error("Disallow `Array` constructors")]
 #[diagnostic(severity(warning), help("Use array literal instead"))]
 struct NoArrayConstructorDiagnostic(#[label] pub Span);
 
@@ -34,6 +34,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoArrayConstructor {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::CallExpression, AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let (span, callee, arguments, type_parameters, optional) = match node.kind() {
             AstKind::CallExpression(call_expr) => (
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,7 +12,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-async-promise-executor): Promise executor functions should not be `async`.")]
+#[error("Promise executor functions should not be `async`.")]
 #[diagnostic(severity(warning))]
 struct NoAsyncPromiseExecutorDiagnostic(#[label] pub Span);
 
@@ -50,6 +50,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAsyncPromiseExecutor {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expression) = node.kind() else { return };
         if !new_expression.callee.is_specific_id("Promise") {
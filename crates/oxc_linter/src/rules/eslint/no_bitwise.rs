@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -10,7 +10,7 @@ use oxc_syntax::operator::BinaryOperator;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-bitwise): Unexpected use of {0:?}")]
+#[error("Unexpected use of {0:?}")]
 #[diagnostic(
     severity(warning),
     help("bitwise operators are not allowed, maybe you mistyped `&&` or `||`")
@@ -43,6 +43,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoBitwise {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::BinaryExpression, AstType::UnaryExpression, AstType::AssignmentExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
 
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{ast::MemberExpression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-caller): Disallow the use of arguments.caller or arguments.callee")]
+#[error("Disallow the use of arguments.caller or arguments.callee")]
 #[diagnostic(
     severity(warning),
     help(
@@ -50,6 +50,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCaller {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MemberExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) = node.kind() else { return };
         if expr.object.is_specific_id("arguments")
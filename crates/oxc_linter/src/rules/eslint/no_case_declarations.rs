@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Declaration, Statement, VariableDeclarationKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,7 +12,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-case-declarations): Unexpected lexical declaration in case block.")]
+#[error("Unexpected lexical declaration in case block.")]
 #[diagnostic(severity(warning))]
 struct NoCaseDeclarationsDiagnostic(#[label] pub Span);
 
@@ -49,6 +49,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCaseDeclarations {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::SwitchCase]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchCase(switch_case) = node.kind() {
             let consequent = &switch_case.consequent;
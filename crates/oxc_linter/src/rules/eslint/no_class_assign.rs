@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -9,7 +10,7 @@ use oxc_span::{Atom, Span};
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-class-assign): Unexpected re-assignment of class {0}")]
+#[error("Unexpected re-assignment of class {0}")]
 #[diagnostic(severity(warning))]
 struct NoClassAssignDiagnostic(
     Atom,
@@ -39,6 +40,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoClassAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
         if symbol_table.get_flag(symbol_id).is_class() {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -10,7 +10,7 @@ use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-compare-neg-zero): Do not use the {0} operator to compare against -0.")]
+#[error("Do not use the {0} operator to compare against -0.")]
 #[diagnostic(
     severity(warning),
     help("Use Object.is(x, -0) to test equality with -0 and use 0 for other cases")
@@ -38,6 +38,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCompareNegZero {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::BinaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(expr) = node.kind() else { return; };
         if Self::should_check(expr.operator) {
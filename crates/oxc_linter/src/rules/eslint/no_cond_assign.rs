@@ -3,7 +3,7 @@ use oxc_ast::{
         ConditionalExpression, DoWhileStatement, Expression, ForStatement, IfStatement,
         WhileStatement,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -15,7 +15,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-cond-assign): Expected a conditional expression and instead saw an assignment")]
+#[error("Expected a conditional expression and instead saw an assignment")]
 #[diagnostic(severity(warning), help("Consider wrapping the assignment in additional parentheses"))]
 struct NoCondAssignDiagnostic(#[label] pub Span);
 
@@ -46,6 +46,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCondAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::IfStatement,
+        AstType::WhileStatement,
+        AstType::DoWhileStatement,
+        AstType::ForStatement,
+        AstType::ConditionalExpression,
+        AstType::AssignmentExpression,
+    ]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
             NoCondAssignConfig::default,
@@ -1,5 +1,5 @@
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, AstKind};
+use oxc_ast::{ast::*, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -53,16 +53,12 @@ declare_oxc_lint!(
 );
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(no-constant-binary-expression): Disallow expressions where the operation doesn't affect the value"
-)]
+#[error("Disallow expressions where the operation doesn't affect the value")]
 #[diagnostic()]
 struct NoConstantBinaryExpressionDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(no-constant-binary-expression): Unexpected constant {0:?} on the left-hand side of a {1:?} expression"
-)]
+#[error("Unexpected constant {0:?} on the left-hand side of a {1:?} expression")]
 #[diagnostic(severity(warning))]
 struct ConstantShortCircuit(
     &'static str, // property
@@ -71,7 +67,7 @@ struct ConstantShortCircuit(
 );
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-constant-binary-expression): Unexpected constant binary expression")]
+#[error("Unexpected constant binary expression")]
 #[diagnostic(severity(warning))]
 struct ConstantBinaryOperand(
     &'static str, // otherSide
@@ -80,18 +76,19 @@ struct ConstantBinaryOperand(
 );
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-constant-binary-expression): Unexpected comparison to newly constructed object")]
+#[error("Unexpected comparison to newly constructed object")]
 #[diagnostic(severity(warning))]
 struct ConstantAlwaysNew(#[label("These two values can never be equal")] Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(no-constant-binary-expression): Unexpected comparison of two newly constructed objects"
-)]
+#[error("Unexpected comparison of two newly constructed objects")]
 #[diagnostic(severity(warning))]
 struct ConstantBothAlwaysNew(#[label("These two values can never be equal")] Span);
 
 impl Rule for NoConstantBinaryExpression {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::LogicalExpression, AstType::BinaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::LogicalExpression(expr) => match expr.operator {
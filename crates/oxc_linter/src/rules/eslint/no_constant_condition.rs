@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-constant-condition): Unexpected constant condition")]
+#[error("Unexpected constant condition")]
 #[diagnostic(severity(warning), help("Constant expression as a test condition is not allowed"))]
 struct NoConstantConditionDiagnostic(#[label] pub Span);
 
@@ -39,6 +39,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoConstantCondition {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::IfStatement, AstType::ConditionalExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
 
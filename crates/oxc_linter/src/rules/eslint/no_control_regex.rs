@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{Argument, Expression, RegExpFlags},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -14,7 +14,7 @@ use regex::{Matches, Regex};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-control-regex): Unexpected control character(s)")]
+#[error("Unexpected control character(s)")]
 #[diagnostic(
     severity(warning),
     help("Unexpected control character(s) in regular expression: \"{0}\"")
@@ -68,6 +68,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoControlRegex {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::RegExpLiteral, AstType::NewExpression, AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, context: &LintContext<'a>) {
         if let Some(RegexPatternData { pattern, flags, span }) = regex_pattern(node) {
             let mut violations: Vec<&str> = Vec::new();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-debugger): `debugger` statement is not allowed")]
+#[error("`debugger` statement is not allowed")]
 #[diagnostic(severity(warning))]
 struct NoDebuggerDiagnostic(#[label] pub Span);
 
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDebugger {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::DebuggerStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::DebuggerStatement(stmt) = node.kind() {
             ctx.diagnostic_with_fix(NoDebuggerDiagnostic(stmt.span), || Fix::delete(stmt.span));
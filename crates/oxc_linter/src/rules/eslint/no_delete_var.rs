@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -10,7 +10,7 @@ use oxc_syntax::operator::UnaryOperator;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-delete-var): variables should not be deleted")]
+#[error("variables should not be deleted")]
 #[diagnostic(severity(warning))]
 struct NoDeleteVarDiagnostic(#[label] pub Span);
 
@@ -36,6 +36,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDeleteVar {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::UnaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::UnaryExpression(expr) = node.kind() else { return };
         if expr.operator == UnaryOperator::Delete && expr.argument.is_identifier_reference() {
@@ -2,7 +2,7 @@ use std::hash::BuildHasherDefault;
 
 use oxc_ast::{
     ast::{ClassElement, MethodDefinitionKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -15,7 +15,7 @@ use rustc_hash::FxHashMap;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-dupe-class-members): Duplicate class member: {0:?}")]
+#[error("Duplicate class member: {0:?}")]
 #[diagnostic(
     severity(warning),
     help(
@@ -53,6 +53,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeClassMembers {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::Class]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Class(class) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,7 +13,7 @@ use oxc_syntax::operator::LogicalOperator;
 use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-dupe-else-if): duplicate conditions in if-else-if chains")]
+#[error("duplicate conditions in if-else-if chains")]
 #[diagnostic(
     severity(warning),
     help(
@@ -52,6 +52,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeElseIf {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::IfStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         // if (a) {} else if (a) {}
         //                ^^ get this if statement
@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{Expression, ObjectPropertyKind, PropertyKey, PropertyKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -14,7 +14,7 @@ use rustc_hash::FxHashMap;
 use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-dupe-keys): Disallow duplicate keys in object literals")]
+#[error("Disallow duplicate keys in object literals")]
 #[diagnostic(severity(warning), help("Consider removing the duplicated key"))]
 struct NoDupeKeysDiagnostic(#[label] pub Span, #[label] pub Span);
 
@@ -42,6 +42,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeKeys {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ObjectExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ObjectExpression(obj_expr) = node.kind() else { return };
         let mut map = FxHashMap::default();
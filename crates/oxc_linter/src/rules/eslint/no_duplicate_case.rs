@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -10,7 +10,7 @@ use rustc_hash::FxHashMap;
 use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-duplicate-case): Disallow duplicate case labels")]
+#[error("Disallow duplicate case labels")]
 #[diagnostic(severity(warning), help("Remove the duplicated case"))]
 struct NoDuplicateCaseDiagnostic(#[label] pub Span, #[label] pub Span);
 
@@ -46,6 +46,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicateCase {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::SwitchStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchStatement(ss) = node.kind() {
             let mut map = FxHashMap::default();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-empty): Disallow empty block statements")]
+#[error("Disallow empty block statements")]
 #[diagnostic(severity(warning), help("Add comment inside empty {0} statement"))]
 struct NoEmptyDiagnostic(&'static str, #[label("Empty {0} statement")] pub Span);
 
@@ -37,6 +37,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmpty {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::BlockStatement, AstType::CatchClause, AstType::SwitchStatement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
         Self {
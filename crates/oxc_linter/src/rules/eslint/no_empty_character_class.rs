@@ -1,6 +1,6 @@
 // Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-empty-character-class.js
 use lazy_static::lazy_static;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -12,7 +12,7 @@ use regex::Regex;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-empty-character-class): Empty character class")]
+#[error("Empty character class")]
 #[diagnostic(severity(warning), help("Try to remove empty character class `[]` in regexp literal"))]
 struct NoEmptyCharacterClassDiagnostic(#[label] pub Span);
 
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyCharacterClass {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::RegExpLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         lazy_static! {
             /*
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-empty-pattern): Disallow empty destructuring patterns")]
+#[error("Disallow empty destructuring patterns")]
 #[diagnostic(severity(warning))]
 struct NoEmptyPatternDiagnostic(&'static str, #[label("Empty {0} binding pattern")] pub Span);
 
@@ -75,6 +75,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyPattern {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::ArrayPattern, AstType::ObjectPattern]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let (pattern_type, span) = match node.kind() {
             AstKind::ArrayPattern(array) if array.is_empty() => ("array", array.span),
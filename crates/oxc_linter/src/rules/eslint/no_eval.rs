@@ -1,6 +1,6 @@
 // Ported from https://github.com/eslint/eslint/tree/main/lib/rules/no-eval.js
 
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -12,7 +12,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-eval): eval can be harmful.")]
+#[error("eval can be harmful.")]
 #[diagnostic(severity(warning))]
 struct NoEvalDiagnostic(#[label] pub Span);
 
@@ -53,6 +53,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEval {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::IdentifierReference, AstType::MemberExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let allow_indirect = value.get(0).map_or(false, |config| {
             config.get("allowIndirect").and_then(serde_json::Value::as_bool).unwrap_or(false)
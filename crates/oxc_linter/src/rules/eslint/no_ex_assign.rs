@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +10,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-ex-assign): Do not assign to the exception parameter.")]
+#[error("Do not assign to the exception parameter.")]
 #[diagnostic(severity(warning))]
 struct NoExAssignDiagnostic(#[label] pub Span);
 
@@ -40,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
         if symbol_table.get_flag(symbol_id).is_catch_variable() {
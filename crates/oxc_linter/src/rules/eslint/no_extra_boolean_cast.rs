@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use oxc_ast::{ast::Argument, AstKind};
+use oxc_ast::{ast::Argument, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -11,7 +11,7 @@ use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-extra-boolean-cast): Redundant double negation")]
+#[error("Redundant double negation")]
 #[diagnostic(
     severity(warning),
     help("Remove the double negation as it will already be coerced to a boolean")
@@ -19,7 +19,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct NoExtraDoubleNegationCastDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-extra-boolean-cast): Redundant Boolean call")]
+#[error("Redundant Boolean call")]
 #[diagnostic(
     severity(warning),
     help("Remove the Boolean call as it will already be coerced to a boolean")
@@ -55,6 +55,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExtraBooleanCast {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::CallExpression, AstType::UnaryExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             enforce_for_logical_operands: value
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -10,7 +10,7 @@ use oxc_span::{Atom, Span};
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-func-assign): '{0}' is a function.")]
+#[error("'{0}' is a function.")]
 #[diagnostic(severity(warning))]
 struct NoFuncAssignDiagnostic(Atom, #[label("{0} is re-assigned here")] pub Span);
 
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoFuncAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
         let decl = symbol_table.get_declaration(symbol_id);
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -5,10 +6,10 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{config::GlobalValue, context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
+#[error("Read-only global '{0}' should not be modified.")]
 #[diagnostic(severity(warning))]
 struct NoGlobalAssignDiagnostic(
     Atom,
@@ -36,6 +37,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoGlobalAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
 
@@ -60,7 +63,9 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && BUILTINS.contains_key(name) {
+                    if !self.excludes.contains(name)
+                        && ctx.global(name) == Some(GlobalValue::Readonly)
+                    {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
@@ -78,22 +83,22 @@ fn test() {
         ("var string;", None),
         ("Object = 0;", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
         ("top = 0;", None),
-        // ("onload = 0;", None), // env: { browser: true }
+        ("/*eslint-env browser*/ onload = 0;", None),
         ("require = 0;", None),
-        // ("a = 1", None), // globals: { a: true } },
-        // ("/*global a:true*/ a = 1", None),
+        ("/*global a:true*/ a = 1", None),
+        ("/*global Object:off*/ Object = 1", None),
     ];
 
     let fail = vec![
         ("String = 'hello world';", None),
         ("String++;", None),
         ("({Object = 0, String = 0} = {});", None),
-        // ("top = 0;", None), // env: { browser: true },
-        // ("require = 0;", None), // env: { node: true },
+        ("/*eslint-env browser*/ top = 0;", None),
+        ("/*eslint-env node*/ require = 0;", None),
         ("function f() { Object = 1; }", None),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ function f() { b = 1; }", None),
+        ("/*global b:false*/ function f() { b++; }", None),
+        ("/*global b*/ b = 1;", None),
         ("Array = 1;", None),
     ];
 
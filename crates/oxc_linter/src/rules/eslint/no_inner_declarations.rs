@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_ast::{ast::ModuleDeclaration, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,9 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(no-inner-declarations): Variable or `function` declarations are not allowed in nested blocks"
-)]
+#[error("Variable or `function` declarations are not allowed in nested blocks")]
 #[diagnostic(severity(warning), help("Move {0} declaration to {1} root"))]
 struct NoInnerDeclarationsDiagnostic(&'static str, &'static str, #[label] pub Span);
 
@@ -52,6 +50,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoInnerDeclarations {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::VariableDeclaration, AstType::Function]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
             NoInnerDeclarationsConfig::default,
@@ -1,6 +1,6 @@
 use once_cell::sync::Lazy;
 use oxc_ast::ast::NumberLiteral;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -13,7 +13,7 @@ use std::borrow::Cow;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-loss-of-precision): This number literal will lose precision at runtime.")]
+#[error("This number literal will lose precision at runtime.")]
 #[diagnostic(severity(warning))]
 struct NoLossOfPrecisionDiagnostic(#[label] pub Span);
 
@@ -40,6 +40,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoLossOfPrecision {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NumberLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::NumberLiteral(node) if Self::lose_precision(node) => {
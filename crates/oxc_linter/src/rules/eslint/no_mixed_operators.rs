@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -10,7 +10,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-mixed-operators): Unexpected mix of {0} with {1}")]
+#[error("Unexpected mix of {0} with {1}")]
 #[diagnostic(
     severity(warning),
     help("Use parentheses to clarify the intended order of operations.")
@@ -56,6 +56,9 @@ declare_oxc_lint! {
 }
 
 impl Rule for NoMixedOperators {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::BinaryExpression, AstType::LogicalExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let node_kind = node.kind();
         if !matches!(node_kind, AstKind::BinaryExpression(_) | AstKind::LogicalExpression(_)) {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-new-symbol): Disallow new operators with the Symbol object")]
+#[error("Disallow new operators with the Symbol object")]
 #[diagnostic(
     severity(warning),
     help(
@@ -40,6 +40,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNewSymbol {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(expr) = node.kind() else { return };
         let Expression::Identifier(ident) = &expr.callee else { return };
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, IdentifierReference, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -16,7 +16,7 @@ const GLOBAL_THIS: &str = "globalThis";
 const NON_CALLABLE_GLOBALS: [&str; 5] = ["Atomics", "Intl", "JSON", "Math", "Reflect"];
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-obj-calls): Disallow calling some global objects as functions")]
+#[error("Disallow calling some global objects as functions")]
 #[diagnostic(severity(error), help("{0} is not a function."))]
 struct NoObjCallsDiagnostic(Atom, #[label] pub Span);
 
@@ -119,6 +119,9 @@ fn resolve_global_binding<'a, 'b: 'a>(
 }
 
 impl Rule for NoObjCalls {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::NewExpression, AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         #[allow(clippy::needless_return)]
         let (callee, span) = match node.kind() {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,9 +9,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "eslint(no-prototype-builtins): do not access Object.prototype method {0:?} from target object"
-)]
+#[error("do not access Object.prototype method {0:?} from target object")]
 #[diagnostic(
     severity(warning),
     help("to avoid prototype pollution, use `Object.prototype.{0}.call` instead")
@@ -50,6 +48,8 @@ declare_oxc_lint!(
 const DISALLOWED_PROPS: &[&str; 3] = &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
 
 impl Rule for NoPrototypeBuiltins {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(expr) = node.kind() else { return };
         let Some(member_expr) = expr.callee.get_member_expr() else { return };
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-return-await): Redundant use of `await` on a return value.")]
+#[error("Redundant use of `await` on a return value.")]
 #[diagnostic(severity(warning), help("Remove redundant `await`."))]
 struct NoReturnAwaitDiagnostic(#[label] pub Span);
 
@@ -35,13 +35,15 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoReturnAwait {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AwaitExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::AwaitExpression(await_expr) = node.kind() {
             if is_in_tail_call_position(node, ctx) && !has_error_handler(node, ctx) {
                 let start = await_expr.span.start;
                 let end = start + 5;
                 let await_keyword_span = Span::new(start, end);
-                ctx.diagnostic_with_fix(NoReturnAwaitDiagnostic(await_keyword_span), || {
+                ctx.diagnostic_with_suggestion(NoReturnAwaitDiagnostic(await_keyword_span), || {
                     Fix::new("", await_keyword_span)
                 });
             }
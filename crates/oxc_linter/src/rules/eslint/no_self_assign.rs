@@ -5,7 +5,7 @@ use oxc_ast::{
         AssignmentTargetPattern, AssignmentTargetProperty, ChainElement, ChainExpression,
         Expression, MemberExpression, ObjectProperty, ObjectPropertyKind, SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -18,7 +18,7 @@ use oxc_syntax::operator::AssignmentOperator;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-self-assign): this expression is assigned to itself")]
+#[error("this expression is assigned to itself")]
 #[diagnostic(severity(warning))]
 struct NoSelfAssignDiagnostic(#[label] pub Span);
 
@@ -53,6 +53,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AssignmentExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             props: value
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-self-compare): Disallow comparisons where both sides are exactly the same")]
+#[error("Disallow comparisons where both sides are exactly the same")]
 #[diagnostic(
     severity(warning),
     help("If you are testing for NaN, you can use Number.isNaN function.")
@@ -42,6 +42,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfCompare {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::BinaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::BinaryExpression(binary_expr) = node.kind() else {return};
         if !binary_expr.operator.is_compare() && !binary_expr.operator.is_equality() {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-setter-return): Setter cannot return a value")]
+#[error("Setter cannot return a value")]
 struct NoSetterReturnDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -40,6 +40,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSetterReturn {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ReturnStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ReturnStatement(stmt) = node.kind() else { return };
         if stmt.argument.is_some() && ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
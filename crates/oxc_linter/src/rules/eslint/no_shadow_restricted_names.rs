@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -10,7 +10,7 @@ use oxc_span::{Atom, Span};
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-shadow-restricted-names): Shadowing of global property '{0}'")]
+#[error("Shadowing of global property '{0}'")]
 #[diagnostic(severity(warning))]
 struct NoShadowRestrictedNamesDiagnostic(
     Atom,
@@ -58,6 +58,8 @@ fn safely_shadows_undefined(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool
 }
 
 impl Rule for NoShadowRestrictedNames {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
         let name = symbol_table.get_name(symbol_id);
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ArrayExpressionElement, AstKind};
+use oxc_ast::{ast::ArrayExpressionElement, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-sparse-arrays): Unexpected comma in middle of array")]
+#[error("Unexpected comma in middle of array")]
 #[diagnostic(severity(warning), help("remove the comma or insert `undefined`"))]
 struct NoSparseArraysDiagnostic(#[label] pub Span);
 
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSparseArrays {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ArrayExpressionElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ArrayExpressionElement(ArrayExpressionElement::Elision(span)) = node.kind()
         {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -7,10 +7,10 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-undef): Disallow the use of undeclared variables")]
+#[error("Disallow the use of undeclared variables")]
 #[diagnostic(severity(warning), help("'{0}' is not defined."))]
 struct NoUndefDiagnostic(Atom, #[label] pub Span);
 
@@ -39,6 +39,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUndef {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let type_of = value
             .get(0)
@@ -53,13 +55,13 @@ impl Rule for NoUndef {
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
-                    return;
+                if ctx.global(reference.name().as_str()).is_some() {
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -82,16 +84,16 @@ fn test() {
 
     let pass = vec![
         ("var a = 1, b = 2; a;", None),
-        // ("/*global b*/ function f() { b; }", None),
+        ("/*global b*/ function f() { b; }", None),
         // { code: "function f() { b; }", globals: { b: false } },
-        // ("/*global b a:false*/  a;  function f() { b; a; }", None),
+        ("/*global b a:false*/  a;  function f() { b; a; }", None),
         ("function a(){}  a();", None),
         ("function f(b) { b; }", None),
         ("var a; a = 1; a++;", None),
         ("var a; function f() { a = 1; }", None),
-        // ("/*global b:true*/ b++;", None),
-        // ("/*eslint-env browser*/ window;", None),
-        // ("/*eslint-env node*/ require(\"a\");", None),
+        ("/*global b:true*/ b++;", None),
+        ("/*eslint-env browser*/ window;", None),
+        ("/*eslint-env node*/ require(\"a\");", None),
         ("Object; isNaN();", None),
         ("toString()", None),
         ("hasOwnProperty()", None),
@@ -121,11 +123,11 @@ fn test() {
         // ("customElements;", None),
         // ("PromiseRejectionEvent;", None),
         ("(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }", None),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
+        ("/*global b:false*/ function f() { b = 1; }", None),
         // { code: "function f() { b = 1; }", globals: { b: false } },
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
-        // ("/*global b:false*/ var b = 1;", None),
+        ("/*global b:false*/ function f() { b++; }", None),
+        ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ var b = 1;", None),
         ("Array = 1;", None),
         ("class A { constructor() { new.target; } }", None),
         // {
@@ -183,6 +185,10 @@ fn test() {
         ("class C { static { let a; } [a]; }", None),
         ("class C { static { function a() {} } [a]; }", None),
         ("class C { static { var a; } } a;", None),
+        ("/*global b:off*/ b;", None),
+        ("/*eslint-env node*/ window;", None),
+        ("// global b\n b;", None),
+        ("Object; typeof a; b;", None),
     ];
 
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
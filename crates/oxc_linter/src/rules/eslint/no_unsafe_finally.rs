@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BreakStatement, ContinueStatement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,7 +12,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-unsafe-finally): Unsafe finally block")]
+#[error("Unsafe finally block")]
 #[diagnostic(
     severity(warning),
     help("Control flow inside try or catch blocks will be overwritten by this statement")
@@ -52,6 +52,13 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnsafeFinally {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::BreakStatement,
+        AstType::ContinueStatement,
+        AstType::ReturnStatement,
+        AstType::ThrowStatement,
+    ]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let kind = node.kind();
 
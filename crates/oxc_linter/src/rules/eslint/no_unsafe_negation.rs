@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -51,6 +51,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnsafeNegation {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::BinaryExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let enforce_for_ordering_relations = value
             .get(0)
@@ -98,7 +100,7 @@ impl NoUnsafeNegation {
             Fix::new(modified_code, expr.span)
         };
 
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        ctx.diagnostic_with_suggestion(diagnostic, fix_producer);
     }
 }
 
@@ -141,5 +143,9 @@ fn test() {
         ("! a <= b", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
     ];
 
-    Tester::new(NoUnsafeNegation::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![("!a in b", "!(a in b)", None), ("!a instanceof b", "!(a instanceof b)", None)];
+
+    let mut tester = Tester::new(NoUnsafeNegation::NAME, pass, fail);
+    tester.test_and_snapshot();
+    tester.test_fix(fix);
 }
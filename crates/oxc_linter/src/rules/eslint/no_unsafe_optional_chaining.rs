@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, AssignmentTarget, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,7 +13,7 @@ use oxc_syntax::operator::LogicalOperator;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-unsafe-optional-chaining): Unsafe usage of optional chaining")]
+#[error("Unsafe usage of optional chaining")]
 #[diagnostic(
     severity(warning),
     help("If this short-circuits with 'undefined' the evaluation will throw TypeError")
@@ -21,7 +21,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct NoUnsafeOptionalChainingDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-unsafe-optional-chaining): Unsafe arithmetic operation on optional chaining")]
+#[error("Unsafe arithmetic operation on optional chaining")]
 #[diagnostic(severity(warning), help("This can result in NaN."))]
 struct NoUnsafeArithmeticDiagnostic(#[label] pub Span);
 
@@ -57,6 +57,23 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnsafeOptionalChaining {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::CallExpression,
+        AstType::MemberExpression,
+        AstType::TaggedTemplateExpression,
+        AstType::NewExpression,
+        AstType::AssignmentExpression,
+        AstType::BinaryExpression,
+        AstType::UnaryExpression,
+        AstType::ForOfStatement,
+        AstType::WithStatement,
+        AstType::Class,
+        AstType::AssignmentPattern,
+        AstType::Argument,
+        AstType::VariableDeclarator,
+        AstType::AssignmentTargetWithDefault,
+    ]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             disallow_arithmetic_operators: value
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::{Atom, Span};
 use crate::{context::LintContext, fixer::Fix, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-unused-labels): Disallow unused labels")]
+#[error("Disallow unused labels")]
 #[diagnostic(severity(warning), help("'{0}:' is defined but never used."))]
 struct NoUnusedLabelsDiagnostic(Atom, #[label] pub Span);
 
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnusedLabels {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_once(&self, ctx: &LintContext) {
         for id in ctx.semantic().unused_labels() {
             let node = ctx.semantic().nodes().get_node(*id);
@@ -0,0 +1,677 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPattern, BindingPatternKind, Expression, FormalParameters,
+        ImportDeclaration, ImportDeclarationSpecifier, ModifierKind, ModuleDeclaration,
+        SimpleAssignmentTarget, TSTypeName,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind, AstType, Visit,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNode, AstNodeId, Reference, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnusedVarsDiagnostic {
+    #[error("{0:?} is declared but never used")]
+    #[diagnostic(severity(warning))]
+    Declared(Atom, #[label] Span),
+    #[error("{0:?} is assigned a value but never used")]
+    #[diagnostic(severity(warning))]
+    Assigned(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum VarsOption {
+    /// Check all variables
+    #[default]
+    All,
+    /// Do not check the variables of the global scope
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ArgsOption {
+    /// Only check the parameters after the last used parameter
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars {
+    vars: VarsOption,
+    vars_ignore_pattern: Option<Regex>,
+    args: ArgsOption,
+    args_ignore_pattern: Option<Regex>,
+    /// Check the parameters of `catch` clauses
+    caught_errors: bool,
+    caught_errors_ignore_pattern: Option<Regex>,
+    /// Do not check the siblings of a rest property, e.g. `a` in `const { a, ...rest } = obj`
+    ignore_rest_siblings: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows variables, functions, classes, imports, parameters and types
+    /// which are declared but never read.
+    /// Exported declarations, names used by TypeScript types
+    /// and the JSX pragma of files with JSX are used.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused declarations are dead code, or a sign of a mistake such as a typo
+    /// or an incomplete refactoring.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { unused } from 'foo';
+    /// let a = 1;
+    /// a = 2;
+    /// function foo(unusedArg) {}
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `vars`: `"all"` (default) or `"local"` to not check the global scope of scripts
+    /// * `varsIgnorePattern`: a regular expression of variable names which are not checked
+    /// * `args`: `"after-used"` (default) to only check the parameters after the last used one,
+    ///   `"all"` or `"none"`
+    /// * `argsIgnorePattern`: a regular expression of parameter names which are not checked
+    /// * `caughtErrors`: `"none"` (default) or `"all"` to check the parameters of `catch` clauses
+    /// * `caughtErrorsIgnorePattern`: a regular expression of `catch` parameter names which are not checked
+    /// * `ignoreRestSiblings`: do not check the siblings of rest properties
+    NoUnusedVars,
+    nursery
+);
+
+impl Rule for NoUnusedVars {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        // `"local"` is short for `{ "vars": "local" }`
+        if let Some(vars) = config.as_str() {
+            return Self { vars: parse_vars(Some(vars)), ..Self::default() };
+        }
+        let get_str = |name: &str| config.get(name).and_then(serde_json::Value::as_str);
+        let get_regex = |name: &str| get_str(name).and_then(|pattern| Regex::new(pattern).ok());
+        Self {
+            vars: parse_vars(get_str("vars")),
+            vars_ignore_pattern: get_regex("varsIgnorePattern"),
+            args: match get_str("args") {
+                Some("all") => ArgsOption::All,
+                Some("none") => ArgsOption::None,
+                _ => ArgsOption::AfterUsed,
+            },
+            args_ignore_pattern: get_regex("argsIgnorePattern"),
+            caught_errors: get_str("caughtErrors") == Some("all"),
+            caught_errors_ignore_pattern: get_regex("caughtErrorsIgnorePattern"),
+            ignore_rest_siblings: config
+                .get("ignoreRestSiblings")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        // Declarations of `.d.ts` files are used by other files
+        if ctx.source_type().is_typescript_definition() {
+            return;
+        }
+        let usages = Usages::collect(ctx);
+        let symbols = ctx.symbols();
+
+        let mut unused = vec![];
+        // Unused specifiers by import declaration
+        let mut unused_imports = FxHashMap::<AstNodeId, Vec<Span>>::default();
+        for symbol_id in symbols.iter() {
+            if symbols.get_flag(symbol_id).intersects(
+                SymbolFlags::TypeParameter
+                    | SymbolFlags::EnumMember
+                    | SymbolFlags::Ambient
+                    | SymbolFlags::Export,
+            ) {
+                continue;
+            }
+            let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+            if !self.is_checked(symbol_id, declaration, ctx, &usages)
+                || is_used(symbol_id, declaration, ctx, &usages)
+            {
+                continue;
+            }
+            if let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(_)) =
+                declaration.kind()
+            {
+                unused_imports
+                    .entry(declaration.id())
+                    .or_default()
+                    .push(symbols.get_span(symbol_id));
+            }
+            unused.push((symbol_id, declaration));
+        }
+
+        for (symbol_id, declaration) in unused {
+            let name = symbols.get_name(symbol_id).clone();
+            let span = symbols.get_span(symbol_id);
+            let is_assigned = symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+                || matches!(declaration.kind(), AstKind::VariableDeclarator(decl) if decl.init.is_some());
+            let diagnostic = if is_assigned {
+                NoUnusedVarsDiagnostic::Assigned(name, span)
+            } else {
+                NoUnusedVarsDiagnostic::Declared(name, span)
+            };
+            match declaration.kind() {
+                AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) => {
+                    let unused_specifiers = &unused_imports[&declaration.id()];
+                    ctx.diagnostic_with_suggestion(diagnostic, || {
+                        remove_import_specifiers(decl, unused_specifiers, ctx.source_text())
+                    });
+                }
+                _ => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+fn parse_vars(vars: Option<&str>) -> VarsOption {
+    match vars {
+        Some("local") => VarsOption::Local,
+        _ => VarsOption::All,
+    }
+}
+
+impl NoUnusedVars {
+    /// Whether the symbol is checked according to the options and the kind of its declaration
+    fn is_checked(
+        &self,
+        symbol_id: SymbolId,
+        declaration: &AstNode,
+        ctx: &LintContext,
+        usages: &Usages,
+    ) -> bool {
+        let name = ctx.symbols().get_name(symbol_id);
+        let span = ctx.symbols().get_span(symbol_id);
+        match declaration.kind() {
+            AstKind::FormalParameters(params) => {
+                if self.args == ArgsOption::None || is_match(&self.args_ignore_pattern, name) {
+                    return false;
+                }
+                // Parameters of overloads, `declare function` and signatures only describe types
+                if let Some(AstKind::Function(func)) = ctx.nodes().parent_kind(declaration.id()) {
+                    if func.is_typescript_syntax() {
+                        return false;
+                    }
+                }
+                // TypeScript parameter properties are class members
+                if params.items.iter().any(|param| {
+                    contains(param.span, span) && (param.accessibility.is_some() || param.readonly)
+                }) {
+                    return false;
+                }
+                return self.args == ArgsOption::All
+                    || !has_used_later_param(params, symbol_id, declaration, ctx, usages);
+            }
+            AstKind::CatchClause(_) => {
+                return self.caught_errors && !is_match(&self.caught_errors_ignore_pattern, name);
+            }
+            AstKind::Function(func) if func.modifiers.contains(ModifierKind::Declare) => {
+                return false;
+            }
+            // The name of a class expression is only visible inside of the class
+            AstKind::Class(class)
+                if class.is_expression() || class.modifiers.contains(ModifierKind::Declare) =>
+            {
+                return false;
+            }
+            AstKind::VariableDeclarator(decl) => {
+                if let Some(AstKind::VariableDeclaration(decl)) =
+                    ctx.nodes().parent_kind(declaration.id())
+                {
+                    if decl.modifiers.contains(ModifierKind::Declare) {
+                        return false;
+                    }
+                }
+                if self.ignore_rest_siblings && has_rest_sibling(&decl.id, span) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+        if self.vars == VarsOption::Local
+            && !ctx.source_type().is_module()
+            && ctx.symbols().get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+        {
+            return false;
+        }
+        !is_match(&self.vars_ignore_pattern, name)
+    }
+}
+
+fn is_match(pattern: &Option<Regex>, name: &str) -> bool {
+    pattern.as_ref().is_some_and(|pattern| pattern.is_match(name))
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+fn is_used(symbol_id: SymbolId, declaration: &AstNode, ctx: &LintContext, usages: &Usages) -> bool {
+    let symbols = ctx.symbols();
+    let name = symbols.get_name(symbol_id);
+    let is_root = ctx.scopes().get_binding(ctx.scopes().root_scope_id(), name) == Some(symbol_id);
+    if (is_root && usages.exported.contains(name))
+        || usages.type_symbols.contains(&symbol_id)
+        || usages.jsx_pragmas.contains(name)
+    {
+        return true;
+    }
+    symbols.get_resolved_references(symbol_id).any(|reference| {
+        reference.is_read()
+            && !is_self_reference(reference, declaration)
+            && !is_update_statement(reference, name, ctx)
+    })
+}
+
+/// Whether the reference is inside of the declaration of its function or class,
+/// e.g. a recursive call
+fn is_self_reference(reference: &Reference, declaration: &AstNode) -> bool {
+    let span = match declaration.kind() {
+        AstKind::Function(func) => func.span,
+        AstKind::Class(class) => class.span,
+        AstKind::VariableDeclarator(decl) => match &decl.init {
+            Some(
+                init @ (Expression::FunctionExpression(_)
+                | Expression::ArrowExpression(_)
+                | Expression::ClassExpression(_)),
+            ) => init.span(),
+            _ => return false,
+        },
+        _ => return false,
+    };
+    contains(span, reference.span())
+}
+
+/// Whether the reference only updates the variable itself,
+/// e.g. `a++;`, `a += 1;` or `a = a + 1;`
+fn is_update_statement(reference: &Reference, name: &Atom, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let is_statement = |node: &AstNode| {
+        matches!(nodes.parent_kind(node.id()), Some(AstKind::ExpressionStatement(_)))
+    };
+    let mut node_id = reference.node_id();
+    while let Some(parent) = nodes.parent_node(node_id) {
+        match parent.kind() {
+            AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::BinaryExpression(_)
+            | AstKind::LogicalExpression(_) => node_id = parent.id(),
+            AstKind::UpdateExpression(_) => return is_statement(parent),
+            AstKind::AssignmentExpression(assign) => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(target),
+                ) = &assign.left
+                else {
+                    return false;
+                };
+                return target.name == *name && is_statement(parent);
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Whether a parameter after the parameter of the symbol is used,
+/// destructured parameters are always checked
+fn has_used_later_param(
+    params: &FormalParameters,
+    symbol_id: SymbolId,
+    declaration: &AstNode,
+    ctx: &LintContext,
+    usages: &Usages,
+) -> bool {
+    let span = ctx.symbols().get_span(symbol_id);
+    let Some(index) = params.items.iter().position(|param| contains(param.span, span)) else {
+        return false;
+    };
+    if !is_positional(&params.items[index].pattern) {
+        return false;
+    }
+    let scope_id = ctx.symbols().get_scope_id(symbol_id);
+    let mut later = vec![];
+    for param in params.items.iter().skip(index + 1) {
+        param.pattern.bound_names(&mut |ident| later.push(ident.name.clone()));
+    }
+    if let Some(rest) = &params.rest {
+        rest.argument.bound_names(&mut |ident| later.push(ident.name.clone()));
+    }
+    later.iter().any(|name| {
+        ctx.scopes()
+            .get_binding(scope_id, name)
+            .is_some_and(|symbol_id| is_used(symbol_id, declaration, ctx, usages))
+    })
+}
+
+fn is_positional(pattern: &BindingPattern) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => true,
+        BindingPatternKind::AssignmentPattern(assignment) => is_positional(&assignment.left),
+        _ => false,
+    }
+}
+
+/// Whether the binding at `span` is a property of an object pattern with a rest element
+fn has_rest_sibling(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => object.properties.iter().any(|property| {
+            (object.rest.is_some() && binds(&property.value, span))
+                || has_rest_sibling(&property.value, span)
+        }),
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| has_rest_sibling(element, span))
+        }
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            has_rest_sibling(&assignment.left, span)
+        }
+    }
+}
+
+/// Whether the pattern itself binds the identifier at `span`
+fn binds(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => ident.span == span,
+        BindingPatternKind::AssignmentPattern(assignment) => binds(&assignment.left, span),
+        _ => false,
+    }
+}
+
+/// Remove the unused specifiers of an import declaration,
+/// or the whole declaration if none of them is used
+fn remove_import_specifiers<'a>(
+    decl: &ImportDeclaration,
+    unused: &[Span],
+    source_text: &'a str,
+) -> Fix<'a> {
+    let mut clauses = vec![];
+    let mut named = vec![];
+    for specifier in &decl.specifiers {
+        let (local, span) = match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(s) => (s.local.span, s.span),
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => (s.local.span, s.span),
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => (s.local.span, s.span),
+        };
+        if unused.contains(&local) {
+            continue;
+        }
+        let text = span.source_text(source_text).to_string();
+        match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(_) => named.push(text),
+            _ => clauses.push(text),
+        }
+    }
+    if clauses.is_empty() && named.is_empty() {
+        return Fix::delete(decl.span);
+    }
+    if !named.is_empty() {
+        clauses.push(format!("{{ {} }}", named.join(", ")));
+    }
+    let kind = if decl.import_kind.is_type() { "type " } else { "" };
+    Fix::new(
+        format!("import {kind}{} from ", clauses.join(", ")),
+        Span::new(decl.span.start, decl.source.span.start),
+    )
+}
+
+/// Usages of names which are not references of the semantic analysis
+#[derive(Debug, Default)]
+struct Usages {
+    /// Symbols used by TypeScript types, e.g. `A` of `let a: A.B` or `typeof a`
+    type_symbols: FxHashSet<SymbolId>,
+    /// Spans of the first names of the type names, resolved to `type_symbols` by their scopes
+    type_name_spans: FxHashSet<Span>,
+    /// Local names of `export { a }` and `export type { A }`
+    exported: FxHashSet<Atom>,
+    /// Names JSX is compiled to references of, e.g. `React` of `React.createElement`
+    jsx_pragmas: FxHashSet<Atom>,
+    has_jsx: bool,
+    has_jsx_fragment: bool,
+}
+
+impl Usages {
+    fn collect(ctx: &LintContext) -> Self {
+        let mut usages = Self::default();
+        if let Some(AstKind::Program(program)) = ctx.nodes().iter().next().map(AstNode::kind) {
+            usages.visit_program(program);
+        }
+        usages.collect_type_symbols(ctx);
+        if usages.has_jsx || usages.has_jsx_fragment {
+            usages.collect_jsx_pragmas(ctx);
+        }
+        usages
+    }
+
+    /// Resolve the type names in the scopes they are used in, like the references of values.
+    fn collect_type_symbols(&mut self, ctx: &LintContext) {
+        let mut type_names = vec![];
+        // Type parameters have scopes of their own, they are matched by the nodes declaring them
+        let mut type_parameters = vec![];
+        for node in ctx.nodes().iter() {
+            match node.kind() {
+                AstKind::IdentifierName(ident) if self.type_name_spans.contains(&ident.span) => {
+                    type_names.push((ident.name.clone(), ident.span, node.scope_id()));
+                }
+                AstKind::Class(class) => {
+                    for implements in class.implements.iter().flatten() {
+                        let ident = TSTypeName::get_first_name(&implements.expression);
+                        type_names.push((ident.name, ident.span, node.scope_id()));
+                    }
+                }
+                AstKind::TSInterfaceDeclaration(decl) => {
+                    for heritage in decl.extends.iter().flatten() {
+                        let mut expr = &heritage.expression;
+                        while let Expression::MemberExpression(member) = expr {
+                            expr = member.object();
+                        }
+                        if let Expression::Identifier(ident) = expr {
+                            type_names.push((ident.name.clone(), ident.span, node.scope_id()));
+                        }
+                    }
+                }
+                AstKind::TSTypeParameter(param) => {
+                    let owner = ctx
+                        .nodes()
+                        .parent_id(node.id())
+                        .and_then(|declaration| ctx.nodes().parent_kind(declaration));
+                    if let Some(owner) = owner {
+                        type_parameters.push((&param.name.name, owner.span()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        let scopes = ctx.scopes();
+        for (name, span, scope_id) in type_names {
+            if type_parameters
+                .iter()
+                .any(|(param, owner)| **param == name && contains(*owner, span))
+            {
+                continue;
+            }
+            if let Some(symbol_id) =
+                scopes.ancestors(scope_id).find_map(|scope_id| scopes.get_binding(scope_id, &name))
+            {
+                self.type_symbols.insert(symbol_id);
+            }
+        }
+    }
+
+    /// `React`, unless configured by `settings.react` or `@jsx`, `@jsxFrag` and `@jsxRuntime` comments
+    fn collect_jsx_pragmas(&mut self, ctx: &LintContext) {
+        let pragma = ctx.jsx_pragma();
+        if pragma.automatic {
+            return;
+        }
+        let first_name = |name: &str| Atom::from(name.split('.').next().unwrap_or(name));
+        if self.has_jsx {
+            self.jsx_pragmas.insert(first_name(&pragma.pragma));
+        }
+        if self.has_jsx_fragment {
+            self.jsx_pragmas.insert(first_name(&pragma.fragment));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for Usages {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::ModuleDeclaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+                if decl.source.is_none() =>
+            {
+                for specifier in &decl.specifiers {
+                    self.exported.insert(specifier.local.name().clone());
+                }
+            }
+            AstKind::JSXElement(_) => self.has_jsx = true,
+            AstKind::JSXFragment(_) => self.has_jsx_fragment = true,
+            _ => {}
+        }
+    }
+
+    fn visit_ts_type_name(&mut self, name: &'a TSTypeName<'a>) {
+        self.type_name_spans.insert(TSTypeName::get_first_name(name).span);
+        match name {
+            TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const a = 1; console.log(a)", None),
+        ("function foo() {} foo()", None),
+        ("export function foo() {}", None),
+        ("export const a = 1", None),
+        ("const a = 1; export { a }", None),
+        ("const a = 1; export default a", None),
+        ("class A {} new A()", None),
+        ("import { a } from 'a'; a()", None),
+        ("import * as ns from 'a'; ns.a()", None),
+        ("let a = 1; a = 2; console.log(a)", None),
+        ("let a = 0; foo(a++)", None),
+        ("let a = 0; const b = a += 1; foo(b)", None),
+        ("for (const x of xs) { foo(x) }", None),
+        ("try {} catch (e) {}", None),
+        ("try {} catch {}", None),
+        ("foo(function bar() {})", None),
+        ("foo(class A {})", None),
+        ("(function foo() { return foo })()", None),
+        ("function foo(a, b) { return b } foo()", None),
+        ("function foo(a) { return a } foo()", None),
+        ("function foo(_a, b) { return b } foo()", Some(json!([{ "args": "all", "argsIgnorePattern": "^_" }]))),
+        ("function foo(a) {} foo()", Some(json!([{ "args": "none" }]))),
+        ("const _a = 1", Some(json!([{ "varsIgnorePattern": "^_" }]))),
+        ("try {} catch (_e) {}", Some(json!([{ "caughtErrors": "all", "caughtErrorsIgnorePattern": "^_" }]))),
+        ("const { a, ...rest } = obj; foo(rest)", Some(json!([{ "ignoreRestSiblings": true }]))),
+        // TypeScript
+        ("import type { A } from 'a'; let a: A; foo(a)", None),
+        ("import { A } from 'a'; let a: A.B; foo(a)", None),
+        ("const a = 1; type B = typeof a; export type { B }", None),
+        ("interface A {} export class B implements A {}", None),
+        ("interface A {} export interface B extends A {}", None),
+        ("type A = string; export function foo(a: A) { return a }", None),
+        ("interface A { a: string } interface A { b: string } export const a: A = { a: '', b: '' }", None),
+        ("export namespace N { const a = 1; export const b = a }", None),
+        ("declare const a: number", None),
+        ("declare function foo(a: number): void", None),
+        ("function foo(a: number): void; function foo(a: any) { return a } foo(1)", None),
+        ("export class A { constructor(private a: number, readonly b: number) {} }", None),
+        ("export function foo<T>() {}", None),
+        ("enum E { A } foo(E.A)", None),
+        ("type A = string; export function foo() { let a: A; return a }", None),
+        ("type A = string; export function foo<T>(a: T, b: A) { return [a, b] }", None),
+        // JSX
+        ("import React from 'react'; export const a = <div />", None),
+        ("import React from 'react'; export const a = <></>", None),
+        ("/** @jsx h */ import { h } from 'preact'; export const a = <div />", None),
+        ("import Foo from 'foo'; export const a = <Foo />", None),
+    ];
+
+    let fail = vec![
+        ("const a = 1", None),
+        ("let a; a = 1", None),
+        ("let a = 0; a++", None),
+        ("let a = 0; a += 1", None),
+        ("let a = 0; a = a + 1", None),
+        ("function foo() {}", None),
+        ("function foo() { foo() }", None),
+        ("const foo = () => foo()", None),
+        ("class A {}", None),
+        ("import a from 'a'", None),
+        ("import { a, b } from 'a'; b()", None),
+        ("function foo(a) {} foo()", None),
+        ("function foo(a, b) { return a } foo()", None),
+        ("function foo(a, b) { return b } foo()", Some(json!([{ "args": "all" }]))),
+        ("function foo({ a }) {} foo()", None),
+        ("try {} catch (e) {}", Some(json!([{ "caughtErrors": "all" }]))),
+        ("const _a = 1", Some(json!([{ "argsIgnorePattern": "^_" }]))),
+        ("const { a, ...rest } = obj; foo(rest)", None),
+        ("const { a, b } = obj; foo(b)", Some(json!([{ "ignoreRestSiblings": true }]))),
+        ("const [a, b] = arr; foo(b)", None),
+        ("const a = 1", Some(json!(["local"]))),
+        // TypeScript
+        ("type A = string", None),
+        ("interface A {}", None),
+        ("enum E { A }", None),
+        ("namespace N {}", None),
+        ("import type { A } from 'a'", None),
+        ("function foo<T>(a: T) {} foo()", None),
+        // Type references resolve to the innermost declaration of the name
+        ("type A = string; export function foo() { type A = number; let a: A; return a }", None),
+        ("import { A } from 'a'; export function foo() { interface A {} let a: A; return a }", None),
+        ("type T = string; export function foo<T>(a: T) { return a }", None),
+        ("type A = string; export function foo() { let a: A.B; return a } namespace N { type A = 1 }", None),
+        ("const a = 1; export function foo() { const a = 2; type B = typeof a; let b: B; return b }", None),
+        ("interface A {} export function foo() { interface A {} return class implements A {} }", None),
+        // JSX
+        ("/** @jsx h */ import React from 'react'; export const a = <div />", None),
+        ("/** @jsxRuntime automatic */ import React from 'react'; export const a = <div />", None),
+        ("import React from 'react'", None),
+    ];
+
+    let fix = vec![
+        ("import a from 'a'; foo()", " foo()", None),
+        ("import { a, b } from 'a'; b()", "import { b } from 'a'; b()", None),
+        ("import a, { b, c } from 'a'; a(c)", "import a, { c } from 'a'; a(c)", None),
+        ("import a, * as ns from 'a'; ns.b()", "import * as ns from 'a'; ns.b()", None),
+        (
+            "import type { A, B } from 'a'; let b: B; foo(b)",
+            "import type { B } from 'a'; let b: B; foo(b)",
+            None,
+        ),
+        ("const a = 1", "const a = 1", None),
+    ];
+
+    let mut tester = Tester::new(NoUnusedVars::NAME, pass, fail);
+    tester.test_and_snapshot();
+    tester.test_fix(fix);
+}
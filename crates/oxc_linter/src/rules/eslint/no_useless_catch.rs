@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPatternKind, Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,12 +12,12 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-useless-catch): Unnecessary try/catch wrapper")]
+#[error("Unnecessary try/catch wrapper")]
 #[diagnostic(severity(warning))]
 struct NoUselessCatchDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-useless-catch): Unnecessary catch clause")]
+#[error("Unnecessary catch clause")]
 #[diagnostic(severity(warning))]
 struct NoUselessCatchFinalizerDiagnostic(#[label] pub Span);
 
@@ -49,6 +49,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessCatch {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TryStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TryStatement(try_stmt) = node.kind() else { return };
         let Some(catch_clause) = &try_stmt.handler else { return };
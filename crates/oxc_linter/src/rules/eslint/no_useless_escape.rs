@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(no-useless-escape): Unnecessary escape character {0:?}")]
+#[error("Unnecessary escape character {0:?}")]
 #[diagnostic(severity(warning))]
 struct NoUselessEscapeDiagnostic(char, #[label] pub Span);
 
@@ -31,6 +31,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessEscape {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::RegExpLiteral, AstType::StringLiteral, AstType::TemplateLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::RegExpLiteral(literal)
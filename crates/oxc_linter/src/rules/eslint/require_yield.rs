@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(require-yield): This generator function does not have 'yield'")]
+#[error("This generator function does not have 'yield'")]
 #[diagnostic(severity(warning))]
 struct RequireYieldDiagnostic(#[label] pub Span);
 
@@ -36,6 +36,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for RequireYield {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::Function, AstType::ArrowExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let kind = node.kind();
         if (matches!(kind, AstKind::Function(func) if func.generator && func.body.as_ref().is_some_and(|body| !body.statements.is_empty()))
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, ChainElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,10 +13,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 enum UseIsnanDiagnostic {
-    #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
+    #[error("Requires calls to isNaN() when checking for NaN")]
     #[diagnostic(severity(warning), help("Use the isNaN function to compare with NaN."))]
     ComparisonWithNaN(#[label] Span),
-    #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
+    #[error("Requires calls to isNaN() when checking for NaN")]
     #[diagnostic(
         severity(warning),
         help(
@@ -24,13 +24,13 @@ enum UseIsnanDiagnostic {
         )
     )]
     SwitchNaN(#[label] Span),
-    #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
+    #[error("Requires calls to isNaN() when checking for NaN")]
     #[diagnostic(
         severity(warning),
         help("'case NaN' can never match. Use Number.isNaN before the switch.")
     )]
     CaseNaN(#[label] Span),
-    #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
+    #[error("Requires calls to isNaN() when checking for NaN")]
     #[diagnostic(severity(warning), help("Array prototype method '{0}' cannot find NaN."))]
     IndexOfNaN(&'static str, #[label] Span),
 }
@@ -78,6 +78,13 @@ declare_oxc_lint!(
 );
 
 impl Rule for UseIsnan {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::BinaryExpression,
+        AstType::SwitchCase,
+        AstType::SwitchStatement,
+        AstType::CallExpression,
+    ]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::BinaryExpression(expr)
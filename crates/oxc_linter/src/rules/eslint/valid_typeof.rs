@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -12,10 +12,10 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 enum ValidTypeofDiagnostic {
-    #[error("eslint(valid-typeof): Typeof comparisons should be to string literals.")]
+    #[error("Typeof comparisons should be to string literals.")]
     #[diagnostic(severity(warning))]
     NotString(#[help] Option<&'static str>, #[label] Span),
-    #[error("eslint(valid-typeof): Invalid typeof comparison value.")]
+    #[error("Invalid typeof comparison value.")]
     #[diagnostic(severity(warning))]
     InvalidValue(#[help] Option<&'static str>, #[label] Span),
 }
@@ -49,6 +49,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ValidTypeof {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::UnaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         // match on `typeof` unary expression for better performance
         let _unary_expr = match node.kind() {
@@ -88,7 +90,7 @@ impl Rule for ValidTypeof {
 
         if let Expression::Identifier(ident) = sibling {
             if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident) {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     if self.require_string_literals {
                         ValidTypeofDiagnostic::NotString(
                             Some("Use `\"undefined\"` instead of `undefined`."),
@@ -0,0 +1,94 @@
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("No default export found in imported module {0:?}")]
+#[diagnostic(severity(warning))]
+struct DefaultDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct Default;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies that a module has a default export when it is imported by a default import.
+    /// Only checked when the import plugin is enabled, modules without `export` declarations,
+    /// e.g. CommonJS modules, are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The default import of a module without a default export results in `undefined`,
+    /// or fails to link.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 1;
+    ///
+    /// // ./bar.js
+    /// import foo from './foo';
+    /// ```
+    Default,
+    correctness
+);
+
+impl Rule for Default {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) = node.kind()
+        else {
+            return;
+        };
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let Some(default_specifier) = decl.specifiers.iter().find_map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => Some(specifier),
+            _ => None,
+        }) else {
+            return;
+        };
+        let Some(module) = module_graph.import(ctx.file_path(), &decl.source.value) else { return };
+        let Some(exports) = module_graph.exports(&module) else { return };
+        if !exports.has_default {
+            ctx.diagnostic(DefaultDiagnostic(decl.source.value.clone(), default_specifier.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import a from "./default-export""#,
+        r#"import a, { d } from "./default-export""#,
+        r#"import a from "./export-all""#,
+        r#"import a from "./common-js""#,
+        r#"import a from "./does-not-exist""#,
+        r#"import { a } from "./named-exports""#,
+        r#"import * as ns from "./named-exports""#,
+        r#"import type A from "./named-exports""#,
+    ];
+
+    let fail = vec![
+        r#"import a from "./named-exports""#,
+        r#"import a, { b } from "./named-exports""#,
+        r#"import a from "./export-all-named""#,
+    ];
+
+    Tester::new_without_config(Default::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
@@ -0,0 +1,123 @@
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::ExportExportName;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ExportDiagnostic {
+    #[error("Multiple exports of name {0:?}")]
+    #[diagnostic(severity(warning))]
+    MultipleExports(Atom, #[label] Span),
+    #[error("No named exports found in module {0:?}")]
+    #[diagnostic(severity(warning))]
+    NoNamedExports(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Export;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports names exported more than once when some of them come from `export * from`,
+    /// and `export * from` of modules without named exports.
+    /// Only checked when the import plugin is enabled,
+    /// duplicate exports declared by the module itself are syntax errors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A name exported by two `export *` declarations is silently not exported,
+    /// and a name exported by the module itself silently shadows the name of `export *`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 1;
+    ///
+    /// // ./bar.js
+    /// export * from './foo';
+    /// export const foo = 2;
+    /// ```
+    Export,
+    correctness
+);
+
+impl Rule for Export {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let record = ctx.semantic().module_record();
+
+        // Exported names in source order, and whether they come from `export *`
+        let mut exported = vec![];
+        for entry in record.local_export_entries.iter().chain(&record.indirect_export_entries) {
+            if let ExportExportName::Name(name) = &entry.export_name {
+                if name.name() != &"default" {
+                    exported.push((name.name().clone(), name.span(), false));
+                }
+            }
+        }
+        for entry in &record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(module) = module_graph.import(ctx.file_path(), request.name()) else {
+                continue;
+            };
+            let Some(exports) = module_graph.exports(&module) else { continue };
+            if exports.names.is_empty() && !exports.is_incomplete {
+                ctx.diagnostic(ExportDiagnostic::NoNamedExports(
+                    request.name().clone(),
+                    request.span(),
+                ));
+            }
+            let mut names = exports.names.into_iter().collect::<Vec<_>>();
+            names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+            exported.extend(names.into_iter().map(|name| (name, request.span(), true)));
+        }
+
+        let mut counts = FxHashMap::<&Atom, (usize, bool)>::default();
+        for (name, _, is_star) in &exported {
+            let count = counts.entry(name).or_default();
+            count.0 += 1;
+            count.1 |= is_star;
+        }
+        for (name, span, _) in &exported {
+            if matches!(counts.get(name), Some((count, true)) if *count > 1) {
+                ctx.diagnostic(ExportDiagnostic::MultipleExports(name.clone(), *span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"export * from "./named-exports"; export const foo = 1"#,
+        r#"export * from "./named-exports"; export * from "./default-export""#,
+        r#"export * from "./named-exports"; export default 1"#,
+        r#"export * from "./common-js"; export const a = 1"#,
+        r#"export * from "./export-all-unknown"; export const foo = 1"#,
+        r#"export * from "./does-not-exist"; export const a = 1"#,
+        r#"export * as ns from "./named-exports"; export const a = 1"#,
+        r#"export const a = 1; export { a as b }"#,
+    ];
+
+    let fail = vec![
+        r#"export * from "./named-exports"; export const a = 1"#,
+        r#"export * from "./named-exports"; const x = 1; export { x as b }"#,
+        r#"export * from "./named-exports"; export * from "./named-exports-2""#,
+        r#"export * from "./named-exports"; export { d as c } from "./default-export""#,
+        r#"export * from "./no-exports""#,
+    ];
+
+    Tester::new_without_config(Export::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
@@ -0,0 +1,120 @@
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0:?} is not exported by {1:?}")]
+#[diagnostic(severity(warning))]
+struct NamedDiagnostic(Atom, Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct Named;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies that all named imports and re-exports are exported by the imported module.
+    /// Only checked when the import plugin is enabled, modules without `export` declarations,
+    /// e.g. CommonJS modules, are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a name which is not exported results in `undefined`, or fails to link.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 1;
+    ///
+    /// // ./bar.js
+    /// import { bar } from './foo';
+    /// ```
+    Named,
+    correctness
+);
+
+impl Rule for Named {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let AstKind::ModuleDeclaration(decl) = node.kind() else { return };
+        // The imported names and their spans
+        let (source, names) = match decl {
+            ModuleDeclaration::ImportDeclaration(decl) if decl.import_kind.is_value() => {
+                let names = decl
+                    .specifiers
+                    .iter()
+                    .filter_map(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            Some((specifier.imported.name(), specifier.imported.span()))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                (&decl.source, names)
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) if decl.export_kind.is_value() => {
+                let Some(source) = &decl.source else { return };
+                let names = decl
+                    .specifiers
+                    .iter()
+                    .filter(|specifier| specifier.export_kind.is_value())
+                    .map(|specifier| (specifier.local.name(), specifier.local.span()))
+                    .collect();
+                (source, names)
+            }
+            _ => return,
+        };
+        if names.is_empty() {
+            return;
+        }
+        let Some(module) = module_graph.import(ctx.file_path(), &source.value) else { return };
+        let Some(exports) = module_graph.exports(&module) else { return };
+        for (name, span) in names {
+            let is_exported =
+                if name == &"default" { Some(exports.has_default) } else { exports.has(name) };
+            if is_exported == Some(false) {
+                ctx.diagnostic(NamedDiagnostic(name.clone(), source.value.clone(), span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import { a, b, c } from "./named-exports""#,
+        r#"import { a as x } from "./named-exports""#,
+        r#"import { d, default as e } from "./default-export""#,
+        r#"import { a, b, d } from "./export-all""#,
+        r#"export { a, c as f } from "./named-exports""#,
+        r#"import { foo } from "./common-js""#,
+        r#"import { foo } from "./does-not-exist""#,
+        r#"import { foo } from "./export-all-unknown""#,
+        r#"import type { foo } from "./named-exports""#,
+        r#"export type { foo } from "./named-exports""#,
+        r#"import a, * as ns from "./named-exports""#,
+    ];
+
+    let fail = vec![
+        r#"import { foo } from "./named-exports""#,
+        r#"import { a, foo as bar } from "./named-exports""#,
+        r#"import { default as a } from "./named-exports""#,
+        r#"import { foo } from "./export-all""#,
+        r#"export { foo } from "./named-exports""#,
+    ];
+
+    Tester::new_without_config(Named::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
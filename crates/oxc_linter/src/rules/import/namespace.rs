@@ -0,0 +1,135 @@
+use oxc_ast::{
+    ast::{ImportDeclarationSpecifier, MemberExpression, ModuleDeclaration},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NamespaceDiagnostic {
+    #[error("{0:?} is not exported by {1:?}")]
+    #[diagnostic(severity(warning), help("{0:?} is a member of the imported namespace {2:?}"))]
+    NotExported(Atom, Atom, Atom, #[label] Span),
+    #[error("No exported names found in module {0:?}")]
+    #[diagnostic(severity(warning))]
+    NoExports(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Namespace;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies that the members of a namespace import, e.g. `ns.foo` of `import * as ns from './ns'`,
+    /// are exported by the imported module.
+    /// Only checked when the import plugin is enabled, modules without `export` declarations,
+    /// e.g. CommonJS modules, are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The members of a namespace object are the exports of the module,
+    /// other members are `undefined`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 1;
+    ///
+    /// // ./bar.js
+    /// import * as ns from './foo';
+    /// ns.bar;
+    /// ```
+    Namespace,
+    correctness
+);
+
+impl Rule for Namespace {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) = node.kind()
+        else {
+            return;
+        };
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let Some(namespace) = decl.specifiers.iter().find_map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => Some(specifier),
+            _ => None,
+        }) else {
+            return;
+        };
+        let Some(module) = module_graph.import(ctx.file_path(), &decl.source.value) else { return };
+        let Some(exports) = module_graph.exports(&module) else { return };
+        if exports.names.is_empty() && !exports.has_default && !exports.is_incomplete {
+            ctx.diagnostic(NamespaceDiagnostic::NoExports(
+                decl.source.value.clone(),
+                namespace.span,
+            ));
+            return;
+        }
+
+        let Some(symbol_id) = ctx.scopes().get_root_binding(&namespace.local.name) else { return };
+        for reference in ctx.symbols().get_resolved_references(symbol_id) {
+            let Some(AstKind::MemberExpression(member)) = ctx.nodes().parent_kind(reference.node_id())
+            else {
+                continue;
+            };
+            // `ns.foo` and `ns["foo"]`, but not `obj[ns]`
+            if member.object().span() != reference.span() {
+                continue;
+            }
+            let Some(name) = member.static_property_name() else { continue };
+            let is_exported =
+                if name == "default" { Some(exports.has_default) } else { exports.has(name) };
+            if is_exported == Some(false) {
+                let span = match member {
+                    MemberExpression::StaticMemberExpression(member) => member.property.span,
+                    _ => member.span(),
+                };
+                ctx.diagnostic(NamespaceDiagnostic::NotExported(
+                    name.into(),
+                    decl.source.value.clone(),
+                    namespace.local.name.clone(),
+                    span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import * as ns from "./named-exports"; ns.a; ns["b"]; ns.c.foo"#,
+        r#"import * as ns from "./export-all"; ns.a; ns.d; ns.default"#,
+        r#"import * as ns from "./named-exports"; ns[foo]; obj[ns]"#,
+        r#"import * as ns from "./named-exports"; function f(ns) { ns.foo }"#,
+        r#"import * as ns from "./common-js"; ns.foo"#,
+        r#"import * as ns from "./export-all-unknown"; ns.foo"#,
+        r#"import * as ns from "./does-not-exist"; ns.foo"#,
+        r#"import { a } from "./named-exports"; a.foo"#,
+    ];
+
+    let fail = vec![
+        r#"import * as ns from "./named-exports"; ns.foo"#,
+        r#"import * as ns from "./named-exports"; ns["foo"]; ns.default"#,
+        r#"import * as ns from "./export-all"; ns.foo"#,
+        r#"import * as ns from "./no-exports""#,
+    ];
+
+    Tester::new_without_config(Namespace::NAME, pass, fail)
+        .with_import_plugin()
+        .test_and_snapshot();
+}
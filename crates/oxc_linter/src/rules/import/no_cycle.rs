@@ -0,0 +1,135 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::get_module_source, context::LintContext, module_graph::ModuleGraph, rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Dependency cycle detected")]
+#[diagnostic(severity(warning), help("This file is imported again by {0}"))]
+struct NoCycleDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoCycle {
+    /// Maximum number of modules in a cycle, including this one
+    max_depth: usize,
+    /// Do not follow imports of `node_modules`
+    ignore_external: bool,
+}
+
+impl Default for NoCycle {
+    fn default() -> Self {
+        Self { max_depth: usize::MAX, ignore_external: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures that there is no path from an imported module back to this module.
+    /// Only checked when the import plugin is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The modules of a cycle are evaluated before the exports they import are initialized,
+    /// and cycles make it hard to understand and split up the code.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// import './bar';
+    ///
+    /// // ./bar.js
+    /// import './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `maxDepth`: the maximum number of imports to follow, defaults to `"∞"`
+    /// * `ignoreExternal`: do not follow imports of modules in `node_modules`
+    NoCycle,
+    pedantic
+);
+
+impl Rule for NoCycle {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let max_depth = obj
+            .and_then(|v| v.get("maxDepth"))
+            .and_then(serde_json::Value::as_u64)
+            .map_or(usize::MAX, |max_depth| usize::try_from(max_depth).unwrap_or(usize::MAX));
+        Self {
+            max_depth,
+            ignore_external: obj
+                .and_then(|v| v.get("ignoreExternal"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let AstKind::ModuleDeclaration(decl) = node.kind() else { return };
+        let Some(source) = get_module_source(decl) else { return };
+        let Ok(file_path) = ctx.file_path().canonicalize() else { return };
+        let Some(path) = module_graph.resolve(ctx.file_path(), &source.value) else { return };
+        if path == file_path || (self.ignore_external && ModuleGraph::is_external(&path)) {
+            return;
+        }
+        // The shortest chains back to this file are computed once for all of its imports
+        let mut dependencies = ctx
+            .semantic()
+            .module_record()
+            .module_requests
+            .keys()
+            .filter_map(|specifier| module_graph.resolve(ctx.file_path(), specifier))
+            .filter(|dependency| !(self.ignore_external && ModuleGraph::is_external(dependency)))
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        let import_paths =
+            module_graph.import_paths_to(&file_path, dependencies, self.ignore_external);
+        if let Some(cycle) = import_paths.chain(&path).filter(|chain| chain.len() <= self.max_depth)
+        {
+            let cycle = cycle
+                .iter()
+                .map(|path| path.file_name().unwrap_or_default().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            ctx.diagnostic(NoCycleDiagnostic(cycle, source.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import a from "./named-exports""#, None),
+        (r#"import a from "./does-not-exist""#, None),
+        (r#"import "./index""#, None),
+        (r#"import type { A } from "./cycle-a""#, None),
+        (r#"import "./cycle-a""#, Some(json!([{ "maxDepth": 1 }]))),
+    ];
+
+    let fail = vec![
+        (r#"import "./cycle-b""#, None),
+        (r#"import { a } from "./cycle-a""#, None),
+        (r#"export * from "./cycle-a""#, None),
+        (r#"import "./cycle-a""#, Some(json!([{ "maxDepth": 2 }]))),
+    ];
+
+    Tester::new(NoCycle::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
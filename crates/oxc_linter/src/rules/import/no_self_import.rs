@@ -0,0 +1,92 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{ast_util::get_module_source, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Module imports itself")]
+#[diagnostic(severity(warning))]
+struct NoSelfImportDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSelfImport;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids a module from importing itself, by `import` or `require`.
+    /// Only checked when the import plugin is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing the module itself is a mistake, its exports are not initialized yet.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// import foo from './foo';
+    /// const bar = require('./foo');
+    /// ```
+    NoSelfImport,
+    suspicious
+);
+
+impl Rule for NoSelfImport {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::ModuleDeclaration, AstType::CallExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let source = match node.kind() {
+            AstKind::ModuleDeclaration(decl) => get_module_source(decl),
+            AstKind::CallExpression(call) if call.is_require_call() => {
+                match call.arguments.first() {
+                    Some(Argument::Expression(Expression::StringLiteral(source))) => {
+                        Some(&**source)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let Some(source) = source else { return };
+        let Some(path) = module_graph.resolve(ctx.file_path(), &source.value) else { return };
+        // The resolved path has symbolic links resolved
+        if ctx.file_path().canonicalize().is_ok_and(|file_path| file_path == path) {
+            ctx.diagnostic(NoSelfImportDiagnostic(source.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"import a from "./named-exports""#,
+        r#"import index from "./index/index""#,
+        r#"const a = require("./named-exports")"#,
+        r#"import fs from "fs""#,
+        r#"import type { A } from "./index""#,
+    ];
+
+    let fail = vec![
+        r#"import a from "./index""#,
+        r#"import "./index.ts""#,
+        r#"import a from "../import/index""#,
+        r#"export { a } from "./index""#,
+        r#"const a = require("./index")"#,
+    ];
+
+    Tester::new_without_config(NoSelfImport::NAME, pass, fail)
+        .with_import_plugin()
+        .test_and_snapshot();
+}
@@ -0,0 +1,133 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use regex::Regex;
+
+use crate::{
+    ast_util::get_module_source, context::LintContext, module_graph::ModuleGraph, rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Ensure that each import resolves to a module")]
+#[diagnostic(severity(warning), help("Unable to resolve path to module {0:?}"))]
+struct NoUnresolvedDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved {
+    /// Also check `require("source")` calls
+    commonjs: bool,
+    /// Regular expressions of the module specifiers to ignore
+    ignore: Vec<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local filesystem.
+    /// Only checked when the import plugin is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module which does not exist fails at runtime or when bundling.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import a from './does-not-exist';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `commonjs`: also check `require("source")` calls
+    /// * `ignore`: regular expressions of module specifiers which are not checked
+    NoUnresolved,
+    correctness
+);
+
+impl Rule for NoUnresolved {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::ModuleDeclaration, AstType::CallExpression]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        Self {
+            commonjs: obj
+                .and_then(|v| v.get("commonjs"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignore: obj
+                .and_then(|v| v.get("ignore"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .filter_map(|pattern| Regex::new(pattern).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let source = match node.kind() {
+            AstKind::ModuleDeclaration(decl) => get_module_source(decl),
+            AstKind::CallExpression(call) if self.commonjs && call.is_require_call() => {
+                match call.arguments.first() {
+                    Some(Argument::Expression(Expression::StringLiteral(source))) => {
+                        Some(&**source)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let Some(source) = source else { return };
+        let specifier = source.value.as_str();
+        if ModuleGraph::is_builtin(specifier)
+            || self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+        {
+            return;
+        }
+        if module_graph.resolve(ctx.file_path(), specifier).is_none() {
+            ctx.diagnostic(NoUnresolvedDiagnostic(source.value.clone(), source.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"import a from "./named-exports""#, None),
+        (r#"import a from "./named-exports.js""#, None),
+        (r#"import fs from "fs"; import test from "node:test""#, None),
+        (r#"export { a } from "./named-exports""#, None),
+        (r#"export * from "./named-exports""#, None),
+        (r#"import type { A } from "./does-not-exist""#, None),
+        (r#"const a = require("./does-not-exist")"#, None),
+        (r#"import a from "./does-not-exist""#, Some(json!([{ "ignore": ["^\\./does-"] }]))),
+        (r#"const a = require("./named-exports")"#, Some(json!([{ "commonjs": true }]))),
+    ];
+
+    let fail = vec![
+        (r#"import a from "./does-not-exist""#, None),
+        (r#"import "./does-not-exist""#, None),
+        (r#"import a from "does-not-exist""#, None),
+        (r#"export { a } from "./does-not-exist""#, None),
+        (r#"export * from "./does-not-exist""#, None),
+        (r#"const a = require("./does-not-exist")"#, Some(json!([{ "commonjs": true }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
@@ -0,0 +1,226 @@
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_ast::AstType;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::ExportExportName;
+
+use crate::{context::LintContext, module_graph::ProjectOptions, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnusedModulesDiagnostic {
+    #[error("Exported name {0:?} is not imported by other modules")]
+    #[diagnostic(severity(warning))]
+    UnusedExport(Atom, #[label] Span),
+    #[error("No exports found")]
+    #[diagnostic(severity(warning))]
+    MissingExports(#[label] Span),
+    #[error("File is not reachable from the entry points")]
+    #[diagnostic(
+        severity(warning),
+        help("The file is not imported by the entry points or the files they import")
+    )]
+    Unreachable(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModules {
+    /// Report files without exports
+    missing_exports: bool,
+    /// Report exports which are not imported by other files of the project
+    unused_exports: bool,
+    /// Files whose exports are not reported, e.g. the entry points of a package
+    ignore_exports: GlobSet,
+    project: ProjectOptions,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports exports which are not imported by any other file of the project,
+    /// files without exports, and files which are not reachable from the entry points.
+    /// Imports through `export *` and `export { foo } from` are followed,
+    /// all exports of modules imported by `import * as ns`, `require` or `import()` are used.
+    /// Only checked when the import plugin is enabled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused exports and unreachable files are dead code.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 1;
+    /// export const bar = 2;
+    ///
+    /// // ./bar.js
+    /// import { foo } from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `unusedExports`: report exports which are not imported by other files
+    /// * `missingExports`: report files without exports
+    /// * `src`: files and directories of the project, defaults to the current working directory
+    /// * `entries`: entry points, report files which are not imported by them, directly or indirectly
+    /// * `ignoreExports`: globs of files whose exports are not reported, e.g. `["**/index.js"]`
+    NoUnusedModules,
+    pedantic
+);
+
+impl Rule for NoUnusedModules {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |name: &str| {
+            obj.and_then(|v| v.get(name)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        let get_strings = |name: &str| {
+            obj.and_then(|v| v.get(name))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| v.iter().filter_map(serde_json::Value::as_str).collect::<Vec<_>>())
+        };
+        let mut ignore_exports = GlobSetBuilder::new();
+        for pattern in get_strings("ignoreExports").unwrap_or_default() {
+            if let Ok(glob) = Glob::new(pattern.trim_start_matches("./")) {
+                ignore_exports.add(glob);
+            }
+        }
+        let mut project = ProjectOptions::default();
+        if let Some(src) = get_strings("src") {
+            project.src = src.into_iter().map(PathBuf::from).collect();
+        }
+        if let Some(entries) = get_strings("entries") {
+            project.entries = entries.into_iter().map(PathBuf::from).collect();
+        }
+        Self {
+            missing_exports: get_bool("missingExports"),
+            unused_exports: get_bool("unusedExports"),
+            ignore_exports: ignore_exports.build().unwrap_or_default(),
+            project,
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if !self.missing_exports && !self.unused_exports && self.project.entries.is_empty() {
+            return;
+        }
+        let Some(module_graph) = ctx.module_graph() else { return };
+        let Ok(path) = ctx.file_path().canonicalize() else { return };
+        let project = module_graph.project(&self.project);
+        if !project.contains(&path) {
+            return;
+        }
+        if !project.is_reachable(&path) {
+            ctx.diagnostic(NoUnusedModulesDiagnostic::Unreachable(Span::default()));
+            return;
+        }
+        if self.is_ignored(ctx.file_path()) {
+            return;
+        }
+
+        let record = ctx.semantic().module_record();
+        let exports = record
+            .local_export_entries
+            .iter()
+            .chain(&record.indirect_export_entries)
+            .filter_map(|entry| match &entry.export_name {
+                ExportExportName::Name(name) => Some((name.name().clone(), name.span())),
+                ExportExportName::Default(span) => Some(("default".into(), *span)),
+                ExportExportName::Null => None,
+            })
+            .collect::<Vec<_>>();
+        if self.missing_exports && exports.is_empty() && record.star_export_entries.is_empty() {
+            ctx.diagnostic(NoUnusedModulesDiagnostic::MissingExports(Span::default()));
+        }
+        // The exports of the entry points are used outside of the project
+        if !self.unused_exports || project.is_entry(&path) {
+            return;
+        }
+        for (name, span) in exports {
+            if !project.is_imported(&path, &name) {
+                ctx.diagnostic(NoUnusedModulesDiagnostic::UnusedExport(name, span));
+            }
+        }
+    }
+}
+
+impl NoUnusedModules {
+    fn is_ignored(&self, path: &Path) -> bool {
+        let path = path.strip_prefix(".").unwrap_or(path);
+        self.ignore_exports.is_match(path)
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let unused_exports = json!([{ "unusedExports": true, "src": ["fixtures/import"] }]);
+    let missing_exports = json!([{ "missingExports": true, "src": ["fixtures/import"] }]);
+
+    let pass = vec![
+        ("export const used = 1; export default 1", Some(unused_exports.clone())),
+        ("export const fromBarrel = 1; export const viaBarrel = 2", Some(unused_exports.clone())),
+        ("export const unused = 1", None),
+        (
+            "export const unused = 1",
+            Some(json!([{ "unusedExports": true, "src": ["fixtures/config"] }])),
+        ),
+        (
+            "export const unused = 1",
+            Some(json!([{
+                "unusedExports": true,
+                "src": ["fixtures/import"],
+                "ignoreExports": ["**/index.ts"]
+            }])),
+        ),
+        (
+            "export const unused = 1",
+            Some(json!([{
+                "unusedExports": true,
+                "src": ["fixtures/import"],
+                "entries": ["fixtures/import/index.ts"]
+            }])),
+        ),
+        ("export default 1", Some(missing_exports.clone())),
+        (r#"export * from "./named-exports""#, Some(missing_exports.clone())),
+        (
+            "",
+            Some(json!([{
+                "src": ["fixtures/import"],
+                "entries": ["fixtures/import/imports-barrel.js"]
+            }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("export const unused = 1", Some(unused_exports.clone())),
+        ("export const used = 1; export function unused() {}", Some(unused_exports.clone())),
+        ("const a = 1; export { a as unused }", Some(unused_exports.clone())),
+        (
+            "export const fromBarrel = 1; export const viaBarrel = 2; export const renamed = 3",
+            Some(unused_exports.clone()),
+        ),
+        (r#"export { a as renamed } from "./named-exports""#, Some(unused_exports)),
+        ("const a = 1", Some(missing_exports.clone())),
+        (r#"import "./named-exports""#, Some(missing_exports)),
+        (
+            "",
+            Some(json!([{
+                "src": ["fixtures/import"],
+                "entries": ["fixtures/import/named-exports.js"]
+            }])),
+        ),
+    ];
+
+    Tester::new(NoUnusedModules::NAME, pass, fail).with_import_plugin().test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -56,7 +56,7 @@ declare_oxc_lint!(
 );
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(jest/no-disabled-tests): {0:?}")]
+#[error("{0:?}")]
 #[diagnostic(severity(warning), help("{1:?}"))]
 struct NoDisabledTestsDiagnostic(&'static str, &'static str, #[label] pub Span);
 
@@ -83,6 +83,8 @@ impl Message {
 }
 
 impl Rule for NoDisabledTests {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::CallExpression(call_expr) = node.kind() {
             if let Some(jest_fn_call) = parse_general_jest_fn_call(call_expr, node, ctx) {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -18,7 +18,7 @@ use crate::{
 };
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("eslint(jest/no-test-prefixes): Use {0:?} instead.")]
+#[error("Use {0:?} instead.")]
 #[diagnostic(severity(warning))]
 struct NoTestPrefixesDiagnostic(Atom, #[label] pub Span);
 
@@ -70,6 +70,8 @@ fn get_preferred_node_names(jest_fn_call: &ParsedGeneralJestFnCall) -> Atom {
 }
 
 impl Rule for NoTestPrefixes {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else { return };
         let Some(jest_fn_call) = parse_general_jest_fn_call(call_expr, node, ctx) else { return };
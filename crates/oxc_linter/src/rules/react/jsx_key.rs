@@ -0,0 +1,305 @@
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, ArrowExpression, CallExpression, Expression, Function,
+        JSXAttributeItem, JSXOpeningElement, ReturnStatement, Statement,
+    },
+    AstKind, AstType, Visit,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    react_ast_util::{get_jsx_attribute, get_prop_name},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum JsxKeyDiagnostic {
+    #[error("Missing \"key\" prop for element in array")]
+    #[diagnostic(severity(warning))]
+    MissingArrayKey(#[label] Span),
+    #[error("Missing \"key\" prop for element in iterator")]
+    #[diagnostic(severity(warning))]
+    MissingIterKey(#[label] Span),
+    #[error("Missing \"key\" prop for fragment in array")]
+    #[diagnostic(
+        severity(warning),
+        help("Shorthand fragment syntax does not support providing keys, use `{0}` instead")
+    )]
+    MissingArrayKeyFragment(String, #[label] Span),
+    #[error("Missing \"key\" prop for fragment in iterator")]
+    #[diagnostic(
+        severity(warning),
+        help("Shorthand fragment syntax does not support providing keys, use `{0}` instead")
+    )]
+    MissingIterKeyFragment(String, #[label] Span),
+    #[error("`key` prop must be placed before any `{{...spread}}`")]
+    #[diagnostic(
+        severity(warning),
+        help("The new JSX transform cannot pass a `key` placed after a spread separately")
+    )]
+    KeyAfterSpread(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxKey {
+    /// Also report shorthand fragments `<></>`, which cannot have keys
+    check_fragment_shorthand: bool,
+    /// Report `key` props placed after a spread, e.g. `<div {...props} key="a" />`
+    check_key_must_before_spread: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires a `key` prop for elements in array literals and elements returned
+    /// by the callbacks of `.map()` and `Array.from()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React uses keys to match the elements of a list between renders,
+    /// without them items are re-rendered and lose their state when the list changes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// [<Hello />, <Hello />];
+    /// data.map((x) => <Hello>{x}</Hello>);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `checkFragmentShorthand`: also report shorthand fragments `<></>`, default `false`
+    /// * `checkKeyMustBeforeSpread`: report `key` props placed after a `{...spread}`, default `false`
+    JsxKey,
+    correctness
+);
+
+#[derive(Clone, Copy)]
+enum Source {
+    Array,
+    Iterator,
+}
+
+impl Rule for JsxKey {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::ArrayExpression, AstType::CallExpression, AstType::JSXOpeningElement]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            check_fragment_shorthand: option("checkFragmentShorthand"),
+            check_key_must_before_spread: option("checkKeyMustBeforeSpread"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ArrayExpression(array) => {
+                for element in &array.elements {
+                    if let ArrayExpressionElement::Expression(expr) = element {
+                        self.check_element(expr, Source::Array, ctx);
+                    }
+                }
+            }
+            AstKind::CallExpression(call) => {
+                let Some(callback) = get_iterator_callback(call) else { return };
+                match callback {
+                    Expression::ArrowExpression(arrow) if arrow.expression => {
+                        if let Some(Statement::ExpressionStatement(stmt)) =
+                            arrow.body.statements.first()
+                        {
+                            self.check_returned(&stmt.expression, ctx);
+                        }
+                    }
+                    Expression::ArrowExpression(arrow) => {
+                        self.check_returns(&ReturnCollector::collect_arrow(arrow), ctx);
+                    }
+                    Expression::FunctionExpression(func) => {
+                        self.check_returns(&ReturnCollector::collect_function(func), ctx);
+                    }
+                    _ => {}
+                }
+            }
+            AstKind::JSXOpeningElement(element) if self.check_key_must_before_spread => {
+                check_key_before_spread(element, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl JsxKey {
+    fn check_returns<'a>(&self, returns: &[&'a ReturnStatement<'a>], ctx: &LintContext<'a>) {
+        for stmt in returns {
+            if let Some(argument) = &stmt.argument {
+                self.check_returned(argument, ctx);
+            }
+        }
+    }
+
+    /// The returned value of an iterator callback, e.g. `cond ? <a /> : <b />`
+    fn check_returned<'a>(&self, expr: &'a Expression<'a>, ctx: &LintContext<'a>) {
+        match expr.get_inner_expression() {
+            Expression::ConditionalExpression(cond) => {
+                self.check_returned(&cond.consequent, ctx);
+                self.check_returned(&cond.alternate, ctx);
+            }
+            Expression::LogicalExpression(logical) => self.check_returned(&logical.right, ctx),
+            expr => self.check_element(expr, Source::Iterator, ctx),
+        }
+    }
+
+    fn check_element<'a>(&self, expr: &'a Expression<'a>, source: Source, ctx: &LintContext<'a>) {
+        match expr.get_inner_expression() {
+            Expression::JSXElement(element) => {
+                if get_jsx_attribute(&element.opening_element, "key").is_none() {
+                    let span = element.opening_element.span;
+                    ctx.diagnostic(match source {
+                        Source::Array => JsxKeyDiagnostic::MissingArrayKey(span),
+                        Source::Iterator => JsxKeyDiagnostic::MissingIterKey(span),
+                    });
+                }
+            }
+            Expression::JSXFragment(fragment) if self.check_fragment_shorthand => {
+                let fragment_name = ctx.jsx_pragma().fragment.clone();
+                let span = fragment.opening_fragment.span;
+                ctx.diagnostic(match source {
+                    Source::Array => JsxKeyDiagnostic::MissingArrayKeyFragment(fragment_name, span),
+                    Source::Iterator => {
+                        JsxKeyDiagnostic::MissingIterKeyFragment(fragment_name, span)
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The callback of `items.map(callback)` or `Array.from(items, callback)`
+fn get_iterator_callback<'a>(call: &'a CallExpression<'a>) -> Option<&'a Expression<'a>> {
+    let Expression::MemberExpression(member) = call.callee.get_inner_expression() else {
+        return None;
+    };
+    let index = match member.static_property_name()? {
+        "map" | "flatMap" => 0,
+        "from" if member.object().is_specific_id("Array") => 1,
+        _ => return None,
+    };
+    match call.arguments.get(index)? {
+        Argument::Expression(expr) => Some(expr.get_inner_expression()),
+        Argument::SpreadElement(_) => None,
+    }
+}
+
+fn check_key_before_spread(element: &JSXOpeningElement, ctx: &LintContext) {
+    let mut has_spread = false;
+    for item in &element.attributes {
+        match item {
+            JSXAttributeItem::SpreadAttribute(_) => has_spread = true,
+            JSXAttributeItem::Attribute(attr)
+                if has_spread && get_prop_name(&attr.name) == "key" =>
+            {
+                ctx.diagnostic(JsxKeyDiagnostic::KeyAfterSpread(attr.span));
+            }
+            JSXAttributeItem::Attribute(_) => {}
+        }
+    }
+}
+
+/// The return statements of a function, ignoring nested functions
+#[derive(Default)]
+struct ReturnCollector<'a> {
+    returns: Vec<&'a ReturnStatement<'a>>,
+}
+
+impl<'a> ReturnCollector<'a> {
+    fn collect_arrow(arrow: &'a ArrowExpression<'a>) -> Vec<&'a ReturnStatement<'a>> {
+        let mut collector = Self::default();
+        collector.visit_function_body(&arrow.body);
+        collector.returns
+    }
+
+    fn collect_function(func: &'a Function<'a>) -> Vec<&'a ReturnStatement<'a>> {
+        let mut collector = Self::default();
+        if let Some(body) = &func.body {
+            collector.visit_function_body(body);
+        }
+        collector.returns
+    }
+}
+
+impl<'a> Visit<'a> for ReturnCollector<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let AstKind::ReturnStatement(stmt) = kind {
+            self.returns.push(stmt);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &'a Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &'a ArrowExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("fn()", None),
+        ("[1, 2, 3].map(function () {})", None),
+        ("<App />;", None),
+        ("[<App key={0} />, <App key={1} />];", None),
+        ("[1, 2, 3].map(function(x) { return <App key={x} /> });", None),
+        ("[1, 2, 3].map(x => <App key={x} />);", None),
+        ("[1, 2, 3].map(x => { return <App key={x} /> });", None),
+        ("[1, 2, 3].map(x => (x ? <App key={x} /> : <Other key={x} />));", None),
+        ("[1, 2, 3].map(x => x && <App key={x} />);", None),
+        ("Array.from([1, 2, 3], x => <App key={x} />);", None),
+        ("[1, 2, 3].foo(x => <App />);", None),
+        (
+            "[1, 2, 3].map(x => { function inner() { return <App />; } return <App key={x} />; });",
+            None,
+        ),
+        ("[<></>];", None),
+        ("[1, 2, 3].map(x => <>{x}</>);", None),
+        ("<div key=\"a\" {...props} />", Some(json!([{ "checkKeyMustBeforeSpread": true }]))),
+        ("<div {...props} key=\"a\" />", None),
+    ];
+
+    let fail = vec![
+        ("[<App />];", None),
+        ("[<App {...key} />];", None),
+        ("[<App key={0} />, <App />];", None),
+        ("[1, 2, 3].map(function(x) { return <App /> });", None),
+        ("[1, 2, 3].map(x => <App />);", None),
+        ("[1, 2, 3].map(x => (<App />));", None),
+        ("[1, 2, 3].map(x => { if (x) { return <App />; } return null; });", None),
+        ("[1, 2, 3].map(x => (x ? <App key={x} /> : <Other />));", None),
+        ("[1, 2, 3].map(x => x && <App />);", None),
+        ("[1, 2, 3].flatMap(x => <App />);", None),
+        ("Array.from([1, 2, 3], function(x) { return <App /> });", None),
+        ("[<></>];", Some(json!([{ "checkFragmentShorthand": true }]))),
+        ("[1, 2, 3].map(x => <>{x}</>);", Some(json!([{ "checkFragmentShorthand": true }]))),
+        (
+            "/** @jsx Preact.h */ /** @jsxFrag Preact.Fragment */ [<></>];",
+            Some(json!([{ "checkFragmentShorthand": true }])),
+        ),
+        ("<div {...props} key=\"a\" />", Some(json!([{ "checkKeyMustBeforeSpread": true }]))),
+    ];
+
+    Tester::new(JsxKey::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,107 @@
+use oxc_ast::{ast::JSXAttributeItem, AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, react_ast_util::get_prop_name, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("No duplicate props allowed, {0:?} is set more than once")]
+#[diagnostic(severity(warning), help("Remove one of the props, the last one overrides the others"))]
+struct JsxNoDuplicatePropsDiagnostic(
+    Atom,
+    #[label("first set here")] Span,
+    #[label("set again here")] Span,
+);
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoDuplicateProps {
+    /// Props differing only in case are duplicates, e.g. `onClick` and `onclick`
+    ignore_case: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows setting the same prop more than once on a JSX element.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Only the last value is used, the earlier ones are most likely mistakes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <Hello name="John" name="John" />;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `ignoreCase`: also report props differing only in case, default `false`
+    JsxNoDuplicateProps,
+    correctness
+);
+
+impl Rule for JsxNoDuplicateProps {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_case = value
+            .get(0)
+            .and_then(|config| config.get("ignoreCase"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { ignore_case }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        let mut seen = FxHashMap::default();
+        for item in &element.attributes {
+            let JSXAttributeItem::Attribute(attr) = item else { continue };
+            let name = get_prop_name(&attr.name);
+            let key = if self.ignore_case { name.to_lowercase() } else { name.to_string() };
+            let name_span = attr.name.span();
+            if let Some(first) = seen.insert(key, name_span) {
+                ctx.diagnostic(JsxNoDuplicatePropsDiagnostic(
+                    Atom::from(name.as_ref()),
+                    first,
+                    name_span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<App />;", None),
+        ("<App {...this.props} />;", None),
+        ("<App a b c />;", None),
+        ("<App a {...this.props} b />;", None),
+        ("<App c {...this.props} a=\"a\" />;", None),
+        ("<App onClick={a} onclick={b} />;", None),
+        ("<App xlink:href=\"a\" href=\"b\" />;", None),
+        ("<div a=\"1\"><span a=\"2\" /></div>;", None),
+    ];
+
+    let fail = vec![
+        ("<App a a />;", None),
+        ("<App A a />;", Some(json!([{ "ignoreCase": true }]))),
+        ("<App a b a />;", None),
+        ("<App a {...this.props} a b />;", None),
+        ("<App c {...this.props} a A />;", Some(json!([{ "ignoreCase": true }]))),
+        ("<App a=\"b\" a={c} />;", None),
+        ("<App xlink:href=\"a\" xlink:href=\"b\" />;", None),
+    ];
+
+    Tester::new(JsxNoDuplicateProps::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,345 @@
+use oxc_ast::{
+    ast::{
+        Expression, JSXAttribute, JSXAttributeItem, JSXAttributeValue, JSXExpression,
+        JSXOpeningElement,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    react_ast_util::{get_element_type, get_prop_name},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum JsxNoTargetBlankDiagnostic {
+    #[error("Using `target=\"_blank\"` without `rel=\"noreferrer\"` is a security risk")]
+    #[diagnostic(
+        severity(warning),
+        help("The opened page can navigate this page with `window.opener` in older browsers, `noreferrer` implies `noopener`")
+    )]
+    NoReferrer(#[label] Span),
+    #[error("Using `target=\"_blank\"` without `rel=\"noopener\"` is a security risk")]
+    #[diagnostic(
+        severity(warning),
+        help("The opened page can navigate this page with `window.opener` in older browsers")
+    )]
+    NoOpener(#[label] Span),
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct JsxNoTargetBlank {
+    /// `rel="noopener"` is enough, the referrer may be sent
+    allow_referrer: bool,
+    /// Dynamic links, e.g. `href={url}`, may be external
+    enforce_dynamic_links: bool,
+    /// Spread attributes may set `target`, `href` and `rel`
+    warn_on_spread_attributes: bool,
+    /// Check `<a href>`
+    links: bool,
+    /// Check `<form action>`
+    forms: bool,
+}
+
+impl Default for JsxNoTargetBlank {
+    fn default() -> Self {
+        Self {
+            allow_referrer: false,
+            enforce_dynamic_links: true,
+            warn_on_spread_attributes: false,
+            links: true,
+            forms: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires `rel="noreferrer"` for external links opened with `target="_blank"`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In older browsers the opened page can access this page with `window.opener`,
+    /// e.g. to navigate it to a phishing page.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <a target="_blank" href="https://example.com/"></a>;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `allowReferrer`: accept `rel="noopener"` without `noreferrer`, default `false`
+    /// * `enforceDynamicLinks`: `"always"` (default) to treat dynamic links as external, or `"never"`
+    /// * `warnOnSpreadAttributes`: spread attributes may set `target`, `href` and `rel`, default `false`
+    /// * `links`: check `<a href>`, default `true`
+    /// * `forms`: check `<form action>`, default `false`
+    JsxNoTargetBlank,
+    correctness
+);
+
+impl Rule for JsxNoTargetBlank {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        let option = |name: &str, default: bool| {
+            config.get(name).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+        Self {
+            allow_referrer: option("allowReferrer", default.allow_referrer),
+            enforce_dynamic_links: config
+                .get("enforceDynamicLinks")
+                .and_then(serde_json::Value::as_str)
+                .map_or(default.enforce_dynamic_links, |value| value != "never"),
+            warn_on_spread_attributes: option(
+                "warnOnSpreadAttributes",
+                default.warn_on_spread_attributes,
+            ),
+            links: option("links", default.links),
+            forms: option("forms", default.forms),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        let link_name = match get_element_type(&element.name).as_ref() {
+            "a" if self.links => "href",
+            "form" if self.forms => "action",
+            _ => return,
+        };
+
+        let attributes = Attributes::new(element, link_name);
+        let spread_after =
+            |index: Option<usize>| self.warn_on_spread_attributes && attributes.last_spread > index;
+
+        let has_target_blank = attributes.target.is_some_and(|(_, target)| {
+            get_static_values(target).iter().any(|value| value.eq_ignore_ascii_case("_blank"))
+        }) || spread_after(attributes.target.map(|(index, _)| index));
+        if !has_target_blank {
+            return;
+        }
+
+        let has_dangerous_link = attributes.link.is_some_and(|(_, link)| {
+            get_static_values(link).iter().any(|value| is_external_link(value))
+                || (self.enforce_dynamic_links && is_dynamic(link))
+        }) || spread_after(attributes.link.map(|(index, _)| index));
+        if !has_dangerous_link {
+            return;
+        }
+
+        let required = if self.allow_referrer { "noopener" } else { "noreferrer" };
+        let has_secure_rel = !spread_after(attributes.rel.map(|(index, _)| index))
+            && attributes.rel.is_some_and(|(_, rel)| {
+                let values = get_static_values(rel);
+                !values.is_empty()
+                    && values.iter().all(|value| {
+                        value.split_whitespace().any(|tag| {
+                            tag.eq_ignore_ascii_case("noreferrer")
+                                || tag.eq_ignore_ascii_case(required)
+                        })
+                    })
+            });
+        if has_secure_rel {
+            return;
+        }
+
+        let span = attributes.target.map_or_else(|| element.name.span(), |(_, target)| target.span);
+        let diagnostic = if self.allow_referrer {
+            JsxNoTargetBlankDiagnostic::NoOpener(span)
+        } else {
+            JsxNoTargetBlankDiagnostic::NoReferrer(span)
+        };
+        match attributes.rel {
+            None => {
+                let end = element
+                    .attributes
+                    .last()
+                    .map_or(element.name.span().end, |attr| attr.span().end);
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    Fix::new(" rel=\"noreferrer\"", Span::new(end, end))
+                });
+            }
+            Some((_, JSXAttribute { value: Some(JSXAttributeValue::StringLiteral(rel)), .. })) => {
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    let value = rel.value.trim();
+                    let value = if value.is_empty() {
+                        "noreferrer".to_string()
+                    } else {
+                        format!("{value} noreferrer")
+                    };
+                    Fix::new(format!("\"{value}\""), rel.span)
+                });
+            }
+            Some(_) => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// The last of each attribute and their indices, later attributes override earlier ones
+struct Attributes<'a> {
+    target: Option<(usize, &'a JSXAttribute<'a>)>,
+    link: Option<(usize, &'a JSXAttribute<'a>)>,
+    rel: Option<(usize, &'a JSXAttribute<'a>)>,
+    last_spread: Option<usize>,
+}
+
+impl<'a> Attributes<'a> {
+    fn new(element: &'a JSXOpeningElement<'a>, link_name: &str) -> Self {
+        let mut attributes = Self { target: None, link: None, rel: None, last_spread: None };
+        for (index, item) in element.attributes.iter().enumerate() {
+            match item {
+                JSXAttributeItem::SpreadAttribute(_) => attributes.last_spread = Some(index),
+                JSXAttributeItem::Attribute(attr) => match get_prop_name(&attr.name).as_ref() {
+                    "target" => attributes.target = Some((index, attr)),
+                    "rel" => attributes.rel = Some((index, attr)),
+                    name if name == link_name => attributes.link = Some((index, attr)),
+                    _ => {}
+                },
+            }
+        }
+        attributes
+    }
+}
+
+/// The possible string values of the attribute, e.g. both branches of `{cond ? "a" : "b"}`
+fn get_static_values<'a>(attr: &'a JSXAttribute<'a>) -> Vec<&'a str> {
+    match &attr.value {
+        Some(JSXAttributeValue::StringLiteral(lit)) => vec![lit.value.as_str()],
+        Some(JSXAttributeValue::ExpressionContainer(container)) => match &container.expression {
+            JSXExpression::Expression(expr) => {
+                let mut values = vec![];
+                collect_static_values(expr, &mut values);
+                values
+            }
+            JSXExpression::EmptyExpression(_) => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn collect_static_values<'a>(expr: &'a Expression<'a>, values: &mut Vec<&'a str>) {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(lit) => values.push(lit.value.as_str()),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            if let Some(cooked) = lit.quasis.first().and_then(|quasi| quasi.value.cooked.as_ref()) {
+                values.push(cooked.as_str());
+            }
+        }
+        Expression::ConditionalExpression(cond) => {
+            collect_static_values(&cond.consequent, values);
+            collect_static_values(&cond.alternate, values);
+        }
+        _ => {}
+    }
+}
+
+/// `href={url}`, the value is only known at runtime
+fn is_dynamic(attr: &JSXAttribute) -> bool {
+    matches!(&attr.value, Some(JSXAttributeValue::ExpressionContainer(container))
+        if matches!(&container.expression, JSXExpression::Expression(expr)
+            if !matches!(expr.get_inner_expression(), Expression::StringLiteral(_))))
+}
+
+/// Links with a protocol or protocol-relative links, e.g. `https://example.com` or `//example.com`
+fn is_external_link(link: &str) -> bool {
+    link.starts_with("//")
+        || link.split_once(':').is_some_and(|(scheme, _)| {
+            !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<a href=\"foobar\"></a>", None),
+        ("<a randomTag></a>", None),
+        ("<a target />", None),
+        ("<a href=\"foobar\" target=\"_blank\" rel=\"noopener noreferrer\"></a>", None),
+        ("<a href=\"foobar\" target=\"_blank\" rel=\"noreferrer\"></a>", None),
+        ("<a href=\"foobar\" target=\"_blank\" rel={\"noopener noreferrer\"}></a>", None),
+        ("<a href=\"foobar\" target=\"_blank\" rel={`noreferrer`}></a>", None),
+        ("<a href=\"http://example.com\" target=\"_blank\" rel=\"NOREFERRER\"></a>", None),
+        ("<a target=\"_blank\" {...spreadProps} rel=\"noopener noreferrer\"></a>", None),
+        ("<a href=\"/absolute/path\" target=\"_blank\"></a>", None),
+        ("<a href=\"relative/path\" target=\"_blank\"></a>", None),
+        ("<a href=\"http://example.com\" target=\"_self\"></a>", None),
+        ("<a href=\"http://example.com\"></a>", None),
+        ("<a href={dynamicLink} target=\"_blank\"></a>", Some(json!([{ "enforceDynamicLinks": "never" }]))),
+        (
+            "<a href=\"http://example.com\" target=\"_blank\" rel={cond ? \"noreferrer\" : \"noopener noreferrer\"}></a>",
+            None,
+        ),
+        ("<a href=\"http://example.com\" target=\"_blank\" rel=\"noopener\"></a>", Some(json!([{ "allowReferrer": true }]))),
+        ("<a {...props} target=\"_blank\" href=\"http://example.com\" rel=\"noreferrer\"></a>", Some(json!([{ "warnOnSpreadAttributes": true }]))),
+        ("<a {...props} target=\"_self\" href=\"http://example.com\"></a>", Some(json!([{ "warnOnSpreadAttributes": true }]))),
+        ("<a href=\"http://example.com\" target=\"_blank\"></a>", Some(json!([{ "links": false }]))),
+        ("<form action=\"http://example.com\" target=\"_blank\"></form>", None),
+        ("<Link href=\"http://example.com\" target=\"_blank\"></Link>", None),
+    ];
+
+    let fail = vec![
+        ("<a target=\"_blank\" href=\"https://example.com/1\"></a>", None),
+        ("<a target=\"_blank\" rel=\"\" href=\"https://example.com/2\"></a>", None),
+        ("<a target=\"_blank\" rel={0} href=\"https://example.com/3\"></a>", None),
+        ("<a target=\"_blank\" rel=\"noopenernoreferrer\" href=\"https://example.com/4\"></a>", None),
+        ("<a target=\"_blank\" rel=\"noopener\" href=\"https://example.com/5\"></a>", None),
+        ("<a target=\"_BLANK\" href=\"https://example.com/6\"></a>", None),
+        ("<a target=\"_blank\" href=\"//example.com/7\"></a>", None),
+        ("<a target=\"_blank\" href=\"mailto:foo@example.com\"></a>", None),
+        ("<a target=\"_blank\" href={dynamicLink}></a>", None),
+        ("<a target={\"_blank\"} href=\"https://example.com/8\"></a>", None),
+        ("<a target={cond ? \"_blank\" : \"_self\"} href=\"https://example.com/9\"></a>", None),
+        (
+            "<a target=\"_blank\" href=\"https://example.com/10\" rel={cond ? \"noreferrer\" : \"noopener\"}></a>",
+            None,
+        ),
+        ("<a target=\"_blank\" href=\"https://example.com/11\"></a>", Some(json!([{ "allowReferrer": true }]))),
+        ("<a target=\"_blank\" href=\"https://example.com/12\" rel=\"noreferrer\" {...props}></a>", Some(json!([{ "warnOnSpreadAttributes": true }]))),
+        ("<a {...props} href=\"https://example.com/13\"></a>", Some(json!([{ "warnOnSpreadAttributes": true }]))),
+        ("<form action=\"https://example.com/14\" target=\"_blank\"></form>", Some(json!([{ "forms": true }]))),
+    ];
+
+    let fix = vec![
+        (
+            "<a target=\"_blank\" href=\"https://example.com\"></a>",
+            "<a target=\"_blank\" href=\"https://example.com\" rel=\"noreferrer\"></a>",
+            None,
+        ),
+        (
+            "<a target=\"_blank\" rel=\"noopener\" href=\"https://example.com\"></a>",
+            "<a target=\"_blank\" rel=\"noopener noreferrer\" href=\"https://example.com\"></a>",
+            None,
+        ),
+        (
+            "<a target=\"_blank\" rel=\"\" href=\"https://example.com\"></a>",
+            "<a target=\"_blank\" rel=\"noreferrer\" href=\"https://example.com\"></a>",
+            None,
+        ),
+        (
+            "<a target=\"_blank\" rel={rel} href=\"https://example.com\"></a>",
+            "<a target=\"_blank\" rel={rel} href=\"https://example.com\"></a>",
+            None,
+        ),
+    ];
+
+    let mut tester = Tester::new(JsxNoTargetBlank::NAME, pass, fail);
+    tester.test_and_snapshot();
+    tester.test_fix(fix);
+}
@@ -0,0 +1,119 @@
+use oxc_ast::{
+    ast::{JSXElementName, JSXIdentifier},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0:?} is not defined")]
+#[diagnostic(severity(warning), help("Import or declare the component before using it"))]
+struct JsxNoUndefDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoUndef {
+    /// Also accept components declared as globals, e.g. by `globals` or `env`
+    allow_globals: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows undeclared components in JSX, e.g. a misspelled or missing import.
+    /// Lowercase names are DOM elements and not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Rendering an undeclared component throws a `ReferenceError`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <Hello name="John" />;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `allowGlobals`: accept components declared as global variables, default `false`
+    JsxNoUndef,
+    correctness
+);
+
+impl Rule for JsxNoUndef {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_globals = value
+            .get(0)
+            .and_then(|config| config.get("allowGlobals"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { allow_globals }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        let ident = match &element.name {
+            JSXElementName::Identifier(ident) if !is_dom_element(ident) => ident,
+            // `<Foo.Bar />` references `Foo`
+            JSXElementName::MemberExpression(member) => member.get_object_identifier(),
+            _ => return,
+        };
+        if ident.name == "this" {
+            return;
+        }
+        let scopes = ctx.scopes();
+        let is_declared = scopes
+            .ancestors(node.scope_id())
+            .any(|scope_id| scopes.get_binding(scope_id, &ident.name).is_some());
+        if is_declared || (self.allow_globals && ctx.global(&ident.name).is_some()) {
+            return;
+        }
+        ctx.diagnostic(JsxNoUndefDiagnostic(ident.name.clone(), ident.span));
+    }
+}
+
+/// `<div />`, lowercase names are compiled to strings instead of references
+fn is_dom_element(ident: &JSXIdentifier) -> bool {
+    ident.name.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var React, App; React.render(<App />);", None),
+        ("var React; React.render(<img />);", None),
+        ("var React; React.render(<x-gif />);", None),
+        ("var React, app; React.render(<app.Foo />);", None),
+        ("var React, app; React.render(<app.foo.Bar />);", None),
+        ("import App from './App'; <App />;", None),
+        ("function Component({ Icon }) { return <Icon />; }", None),
+        ("class Hello { render() { return <this.props.tag />; } }", None),
+        ("function Component() { const Item = () => null; return <Item />; }", None),
+        ("<svg:path />;", None),
+        ("<Map />;", Some(json!([{ "allowGlobals": true }]))),
+        ("/* global Text */ <Text />;", Some(json!([{ "allowGlobals": true }]))),
+    ];
+
+    let fail = vec![
+        ("<App />;", None),
+        ("<Appp.Foo />;", None),
+        ("<appp.Foo />;", None),
+        ("<appp.foo.Bar />;", None),
+        ("<Text />;", None),
+        ("function Component() { return <Item />; } function Other() { const Item = 1; }", None),
+        ("import Ap from './App'; <App />;", None),
+        ("<Unknown />;", Some(json!([{ "allowGlobals": true }]))),
+    ];
+
+    Tester::new(JsxNoUndef::NAME, pass, fail).test_and_snapshot();
+}
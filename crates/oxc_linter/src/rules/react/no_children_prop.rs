@@ -0,0 +1,191 @@
+use oxc_ast::{
+    ast::{Argument, JSXAttributeValue, JSXChild, JSXExpression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    react_ast_util::{
+        get_create_element_props, get_jsx_attribute, get_object_property, is_create_element_call,
+    },
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoChildrenPropDiagnostic {
+    #[error("Do not pass children as props")]
+    #[diagnostic(
+        severity(warning),
+        help("Nest the children between the opening and closing tags")
+    )]
+    Jsx(#[label] Span),
+    #[error("Do not pass children as props")]
+    #[diagnostic(
+        severity(warning),
+        help("Pass the children as additional arguments to `createElement`")
+    )]
+    CreateElement(#[label] Span),
+    #[error("Do not nest a function between the opening and closing tags")]
+    #[diagnostic(severity(warning), help("Pass the function as the `children` prop instead"))]
+    NestedFunction(#[label] Span),
+    #[error("Do not pass a function as an additional argument to `createElement`")]
+    #[diagnostic(severity(warning), help("Pass the function as the `children` prop instead"))]
+    FunctionArgument(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoChildrenProp {
+    /// Functions are passed as the `children` prop instead, e.g. render props
+    allow_functions: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows passing children as the `children` prop of JSX elements
+    /// and `React.createElement` calls.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Children are nested between the opening and closing tags in JSX,
+    /// and passed as additional arguments to `React.createElement`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <div children="Children" />;
+    /// React.createElement("div", { children: "Children" });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `allowFunctions`: allow functions as the `children` prop, and report functions nested as children instead, default `false`
+    NoChildrenProp,
+    correctness
+);
+
+impl Rule for NoChildrenProp {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::JSXElement, AstType::CallExpression]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_functions = value
+            .get(0)
+            .and_then(|config| config.get("allowFunctions"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { allow_functions }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(element) => {
+                if let Some(attr) = get_jsx_attribute(&element.opening_element, "children") {
+                    let is_function = match &attr.value {
+                        Some(JSXAttributeValue::ExpressionContainer(container)) => matches!(
+                            &container.expression,
+                            JSXExpression::Expression(expr) if expr.get_inner_expression().is_function()
+                        ),
+                        _ => false,
+                    };
+                    if !(self.allow_functions && is_function) {
+                        ctx.diagnostic(NoChildrenPropDiagnostic::Jsx(attr.span));
+                    }
+                }
+                if self.allow_functions {
+                    for child in &element.children {
+                        if let JSXChild::ExpressionContainer(container) = child {
+                            if let JSXExpression::Expression(expr) = &container.expression {
+                                if expr.get_inner_expression().is_function() {
+                                    ctx.diagnostic(NoChildrenPropDiagnostic::NestedFunction(
+                                        container.span,
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            AstKind::CallExpression(call) if is_create_element_call(call, ctx) => {
+                if let Some(prop) = get_create_element_props(call)
+                    .and_then(|props| get_object_property(props, "children"))
+                {
+                    if !(self.allow_functions && prop.value.get_inner_expression().is_function()) {
+                        ctx.diagnostic(NoChildrenPropDiagnostic::CreateElement(prop.key.span()));
+                    }
+                }
+                if self.allow_functions {
+                    for child in call.arguments.iter().skip(2) {
+                        if let Argument::Expression(expr) = child {
+                            if expr.get_inner_expression().is_function() {
+                                ctx.diagnostic(NoChildrenPropDiagnostic::FunctionArgument(
+                                    expr.span(),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div />;", None),
+        ("<div></div>;", None),
+        ("<div className=\"class-name\"></div>;", None),
+        ("<div>Children</div>;", None),
+        ("<div className=\"class-name\">Children</div>;", None),
+        ("<MyComponent><div /><div /></MyComponent>;", None),
+        ("React.createElement(\"div\");", None),
+        ("React.createElement(\"div\", {});", None),
+        ("React.createElement(\"div\", undefined, \"Children\");", None),
+        ("React.createElement(\"div\", { className: \"class-name\" }, \"Children\");", None),
+        ("React.createElement(MyComponent, {}, React.createElement(\"div\"));", None),
+        ("document.createElement(\"div\", { children: \"Children\" });", None),
+        ("/** @jsx h */ React.createElement(\"div\", { children: \"Children\" });", None),
+        ("<MyComponent children={() => {}} />;", Some(json!([{ "allowFunctions": true }]))),
+        ("<MyComponent children={function() {}} />;", Some(json!([{ "allowFunctions": true }]))),
+        (
+            "React.createElement(MyComponent, { children: () => {} });",
+            Some(json!([{ "allowFunctions": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("<div children />;", None),
+        ("<div children=\"Children\" />;", None),
+        ("<div children={<div />} />;", None),
+        ("<div children={[<div />, <div />]} />;", None),
+        ("<div children=\"Children\">Children</div>;", None),
+        ("React.createElement(\"div\", { children: \"Children\" });", None),
+        ("React.createElement(\"div\", { children: \"Children\" }, \"Children\");", None),
+        ("React.createElement(\"div\", { children: React.createElement(\"div\") });", None),
+        ("/** @jsx h */ h.createElement(\"div\", { children: \"Children\" });", None),
+        ("<MyComponent children={() => {}} />;", None),
+        ("<MyComponent>{() => {}}</MyComponent>;", Some(json!([{ "allowFunctions": true }]))),
+        (
+            "React.createElement(MyComponent, { children: \"Children\" });",
+            Some(json!([{ "allowFunctions": true }])),
+        ),
+        (
+            "React.createElement(MyComponent, {}, () => {});",
+            Some(json!([{ "allowFunctions": true }])),
+        ),
+    ];
+
+    Tester::new(NoChildrenProp::NAME, pass, fail).test_and_snapshot();
+}
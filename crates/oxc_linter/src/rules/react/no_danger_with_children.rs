@@ -0,0 +1,118 @@
+use oxc_ast::{
+    ast::{JSXChild, JSXElement},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    react_ast_util::{
+        get_create_element_props, get_jsx_attribute, get_object_property, is_create_element_call,
+    },
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Only set one of `children` or `props.dangerouslySetInnerHTML`")]
+#[diagnostic(
+    severity(warning),
+    help("React throws an error when an element has both, the inner HTML replaces the children")
+)]
+struct NoDangerWithChildrenDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDangerWithChildren;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows setting both `dangerouslySetInnerHTML` and children of an element.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React throws an error when an element has both.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <div dangerouslySetInnerHTML={{ __html: "HTML" }}>Children</div>;
+    /// React.createElement("div", { dangerouslySetInnerHTML: { __html: "HTML" } }, "Children");
+    /// ```
+    NoDangerWithChildren,
+    correctness
+);
+
+impl Rule for NoDangerWithChildren {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::JSXElement, AstType::CallExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(element) => {
+                let opening = &element.opening_element;
+                if get_jsx_attribute(opening, "dangerouslySetInnerHTML").is_none() {
+                    return;
+                }
+                if has_jsx_children(element) || get_jsx_attribute(opening, "children").is_some() {
+                    ctx.diagnostic(NoDangerWithChildrenDiagnostic(opening.span));
+                }
+            }
+            AstKind::CallExpression(call) if is_create_element_call(call, ctx) => {
+                let Some(props) = get_create_element_props(call) else { return };
+                if get_object_property(props, "dangerouslySetInnerHTML").is_none() {
+                    return;
+                }
+                if call.arguments.len() > 2 || get_object_property(props, "children").is_some() {
+                    ctx.diagnostic(NoDangerWithChildrenDiagnostic(call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Children besides line breaks and indentation, which JSX removes
+fn has_jsx_children(element: &JSXElement) -> bool {
+    element.children.iter().any(|child| match child {
+        JSXChild::Text(text) => {
+            !(text.value.contains('\n') && text.value.chars().all(char::is_whitespace))
+        }
+        _ => true,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<div>Children</div>",
+        "<div {...props} />",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }} />",
+        "<div children=\"Children\" />",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }}>\n</div>",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }}>\n\n  </div>",
+        "React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"HTML\" } });",
+        "React.createElement(\"div\", {}, \"Children\");",
+        "React.createElement(\"div\", { children: \"Children\" });",
+        "document.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"HTML\" } }, \"Children\");",
+    ];
+
+    let fail = vec![
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }}>Children</div>",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }}> </div>",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }}>{children}</div>",
+        "<div dangerouslySetInnerHTML={{ __html: \"HTML\" }} children=\"Children\" />",
+        "<Hello dangerouslySetInnerHTML={{ __html: \"HTML\" }}>\n  <div />\n</Hello>",
+        "React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"HTML\" } }, \"Children\");",
+        "React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"HTML\" }, children: \"Children\" });",
+        "/** @jsx Preact.h */ Preact.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"HTML\" } }, \"Children\");",
+    ];
+
+    Tester::new_without_config(NoDangerWithChildren::NAME, pass, fail).test_and_snapshot();
+}
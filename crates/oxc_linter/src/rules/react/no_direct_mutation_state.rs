@@ -0,0 +1,141 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, Expression, MemberExpression, MethodDefinitionKind,
+        SimpleAssignmentTarget,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, react_ast_util::get_parent_component, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Do not mutate state directly, use `setState()`")]
+#[diagnostic(
+    severity(warning),
+    help("Direct mutations do not re-render the component and may be overwritten by `setState()`")
+)]
+struct NoDirectMutationStateDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDirectMutationState;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows mutating `this.state` of class components and `createReactClass` components,
+    /// besides initializing it in the constructor.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mutating the state directly does not re-render the component,
+    /// and a later call of `setState()` may replace the mutation.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// class Hello extends React.Component {
+    ///   componentDidMount() {
+    ///     this.state.name = "John";
+    ///   }
+    /// }
+    /// ```
+    NoDirectMutationState,
+    correctness
+);
+
+impl Rule for NoDirectMutationState {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::AssignmentExpression, AstType::UpdateExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (target, span) = match node.kind() {
+            AstKind::AssignmentExpression(assign) => match &assign.left {
+                AssignmentTarget::SimpleAssignmentTarget(target) => (target, assign.span),
+                AssignmentTarget::AssignmentTargetPattern(_) => return,
+            },
+            AstKind::UpdateExpression(update) => (&update.argument, update.span),
+            _ => return,
+        };
+        let SimpleAssignmentTarget::MemberAssignmentTarget(member) = target else { return };
+        if !is_state_member(member) {
+            return;
+        }
+        let Some(component) = get_parent_component(node, ctx) else { return };
+        if is_in_constructor(node, component, ctx) {
+            return;
+        }
+        ctx.diagnostic(NoDirectMutationStateDiagnostic(span));
+    }
+}
+
+/// `this.state`, or a member of it, e.g. `this.state.items[0]`
+fn is_state_member(member: &MemberExpression) -> bool {
+    let mut member = member;
+    loop {
+        match member.object() {
+            Expression::ThisExpression(_) => return member.static_property_name() == Some("state"),
+            Expression::MemberExpression(object) => member = object,
+            _ => return false,
+        }
+    }
+}
+
+/// Initializing the state in the constructor, but not in callbacks passed to calls there
+fn is_in_constructor<'a>(
+    node: &AstNode<'a>,
+    component: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    for ancestor in ctx.nodes().iter_parents(node.id()).skip(1) {
+        if ancestor.id() == component.id() {
+            break;
+        }
+        match ancestor.kind() {
+            AstKind::CallExpression(_) => return false,
+            AstKind::MethodDefinition(method) => {
+                return method.kind == MethodDefinitionKind::Constructor;
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var Hello = createReactClass({ render: function() { return <div>Hello {this.props.name}</div>; } });",
+        "class Hello extends React.Component { constructor() { super(); this.state = { name: 'John' }; } }",
+        "class Hello extends React.Component { constructor() { super(); this.state = {}; this.state.name = 'John'; } }",
+        "class Hello extends React.Component { constructor() { super(); const onClick = () => { this.state.a = 1; }; } }",
+        "class Hello extends React.Component { componentDidMount() { this.setState({ name: 'John' }); } }",
+        "class Hello extends React.Component { componentDidMount() { const state = this.state; state.name = 'John'; this.props.name = 'John'; } }",
+        "class Hello extends React.Component { componentDidMount() { this.other.state = {}; } }",
+        "class Hello { componentDidMount() { this.state.name = 'John'; } }",
+        "class Hello extends Other { componentDidMount() { this.state.name = 'John'; } }",
+        "function Hello() { this.state.name = 'John'; }",
+        "var obj = { method() { this.state.name = 'John'; } };",
+    ];
+
+    let fail = vec![
+        "var Hello = createReactClass({ render: function() { this.state.foo = 'bar'; return <div>Hello {this.props.name}</div>; } });",
+        "class Hello extends React.Component { componentDidMount() { this.state.name = 'John'; } }",
+        "class Hello extends Component { componentDidMount() { this.state = { name: 'John' }; } }",
+        "class Hello extends React.PureComponent { componentDidMount() { this.state.person.name = 'John'; } }",
+        "class Hello extends React.Component { componentDidMount() { this.state.items[0] = 'a'; } }",
+        "class Hello extends React.Component { componentDidMount() { this.state.count++; --this.state.count; } }",
+        "class Hello extends React.Component { componentDidMount() { this.state.count += 1; } }",
+        "class Hello extends React.Component { constructor() { super(); doSomething(() => { this.state.name = 'John'; }); } }",
+        "class Hello extends React.Component { handleClick = () => { this.state.name = 'John'; } }",
+        "/** @jsx Preact.h */ class Hello extends Preact.Component { componentDidMount() { this.state.name = 'John'; } }",
+    ];
+
+    Tester::new_without_config(NoDirectMutationState::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,149 @@
+use oxc_ast::{
+    ast::{Expression, JSXAttributeItem, JSXAttributeValue, JSXExpression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    react_ast_util::{get_parent_component, get_prop_name},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoStringRefsDiagnostic {
+    #[error("Using `this.refs` is deprecated")]
+    #[diagnostic(severity(warning), help("Use `useRef`, `createRef` or a callback ref instead"))]
+    ThisRefs(#[label] Span),
+    #[error("Using string literals in `ref` attributes is deprecated")]
+    #[diagnostic(severity(warning), help("Use `useRef`, `createRef` or a callback ref instead"))]
+    StringRef(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStringRefs {
+    /// Also report template literals, e.g. ``ref={`hello`}``
+    no_template_literals: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows string refs, e.g. `ref="hello"`, and reading them with `this.refs` in components.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// String refs are deprecated and removed in React 19.
+    /// They are slower than object and callback refs, and do not compose.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// class Hello extends React.Component {
+    ///   render() {
+    ///     return <div ref="hello">{this.refs.hello.textContent}</div>;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `noTemplateLiterals`: also report template literals, default `false`
+    NoStringRefs,
+    correctness
+);
+
+impl Rule for NoStringRefs {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::JSXAttributeItem, AstType::MemberExpression]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let no_template_literals = value
+            .get(0)
+            .and_then(|config| config.get("noTemplateLiterals"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { no_template_literals }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr))
+                if get_prop_name(&attr.name) == "ref" =>
+            {
+                let is_string_ref = match &attr.value {
+                    Some(JSXAttributeValue::StringLiteral(_)) => true,
+                    Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                        match &container.expression {
+                            JSXExpression::Expression(expr) => match expr.get_inner_expression() {
+                                Expression::StringLiteral(_) => true,
+                                Expression::TemplateLiteral(_) => self.no_template_literals,
+                                _ => false,
+                            },
+                            JSXExpression::EmptyExpression(_) => false,
+                        }
+                    }
+                    _ => false,
+                };
+                if is_string_ref {
+                    ctx.diagnostic(NoStringRefsDiagnostic::StringRef(attr.span));
+                }
+            }
+            AstKind::MemberExpression(member) => {
+                if matches!(member.object(), Expression::ThisExpression(_))
+                    && member.static_property_name() == Some("refs")
+                    && get_parent_component(node, ctx).is_some()
+                {
+                    ctx.diagnostic(NoStringRefsDiagnostic::ThisRefs(member.span()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "var Hello = createReactClass({ componentDidMount: function() { var component = this.hello; }, render: function() { return <div ref={c => this.hello = c}>Hello {this.props.name}</div>; } });",
+            None,
+        ),
+        (
+            "class Hello extends React.Component { hello = React.createRef(); render() { return <div ref={this.hello} />; } }",
+            None,
+        ),
+        ("function Hello() { const hello = useRef(); return <div ref={hello} />; }", None),
+        ("<div ref={`hello`} />;", None),
+        ("<div ref={`hello${index}`} />;", None),
+        ("<div refs=\"hello\" />;", None),
+        ("class Hello { method() { return this.refs.hello; } }", None),
+        ("function hello() { return this.refs.hello; }", None),
+    ];
+
+    let fail = vec![
+        (
+            "var Hello = createReactClass({ componentDidMount: function() { var component = this.refs.hello; }, render: function() { return <div>Hello {this.props.name}</div>; } });",
+            None,
+        ),
+        ("var Hello = createReactClass({ render: function() { return <div ref=\"hello\">Hello {this.props.name}</div>; } });", None),
+        ("<div ref={'hello'} />;", None),
+        (
+            "class Hello extends React.Component { componentDidMount() { var component = this.refs.hello; } render() { return <div ref=\"hello\" />; } }",
+            None,
+        ),
+        ("<div ref={`hello`} />;", Some(json!([{ "noTemplateLiterals": true }]))),
+        ("<div ref={`hello${index}`} />;", Some(json!([{ "noTemplateLiterals": true }]))),
+    ];
+
+    Tester::new(NoStringRefs::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,159 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnescapedEntitiesDiagnostic {
+    #[error("`{0}` can be escaped with {1}")]
+    #[diagnostic(severity(warning), help("Or wrap it in an expression, e.g. `{{\"{0}\"}}`"))]
+    Escapable(char, String, #[label] Span),
+    #[error("HTML entity `{0}` must be escaped")]
+    #[diagnostic(severity(warning), help("Wrap it in an expression, e.g. `{{\"{0}\"}}`"))]
+    Forbidden(char, #[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUnescapedEntities {
+    /// Forbidden characters and the HTML entities they can be escaped with
+    forbid: Vec<(char, Vec<String>)>,
+}
+
+impl Default for NoUnescapedEntities {
+    fn default() -> Self {
+        let entities = |entities: &[&str]| entities.iter().map(ToString::to_string).collect();
+        Self {
+            forbid: vec![
+                ('>', entities(&["&gt;"])),
+                ('"', entities(&["&quot;", "&ldquo;", "&#34;", "&rdquo;"])),
+                ('\'', entities(&["&apos;", "&lsquo;", "&#39;", "&rsquo;"])),
+                ('}', entities(&["&#125;"])),
+            ],
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows characters in JSX text which are most likely meant to be markup or code,
+    /// `>`, `"`, `'` and `}` unless configured otherwise.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A misplaced character, e.g. a `>` of a tag or a `}` of an expression,
+    /// is rendered as text instead of causing a syntax error.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <div> > </div>;
+    /// <div>{"Hello"}}</div>;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `forbid`: the forbidden characters, either strings or objects with the HTML entities to suggest,
+    ///   e.g. `[">", { "char": "}", "alternatives": ["&#125;"] }]`
+    NoUnescapedEntities,
+    pedantic
+);
+
+impl Rule for NoUnescapedEntities {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXText]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(forbid) =
+            value.get(0).and_then(|config| config.get("forbid")).and_then(serde_json::Value::as_array)
+        else {
+            return Self::default();
+        };
+        let first_char = |value: &serde_json::Value| value.as_str()?.chars().next();
+        let forbid = forbid
+            .iter()
+            .filter_map(|entry| match entry {
+                serde_json::Value::Object(entry) => {
+                    let alternatives = entry
+                        .get("alternatives")
+                        .and_then(serde_json::Value::as_array)
+                        .map(|alternatives| {
+                            alternatives
+                                .iter()
+                                .filter_map(serde_json::Value::as_str)
+                                .map(ToString::to_string)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    Some((first_char(entry.get("char")?)?, alternatives))
+                }
+                entry => Some((first_char(entry)?, vec![])),
+            })
+            .collect();
+        Self { forbid }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXText(text) = node.kind() else { return };
+        let source = text.span.source_text(ctx.source_text());
+        for (offset, c) in source.char_indices() {
+            let Some((_, alternatives)) = self.forbid.iter().find(|(forbidden, _)| *forbidden == c)
+            else {
+                continue;
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            let start = text.span.start + offset as u32;
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::new(start, start + c.len_utf8() as u32);
+            if alternatives.is_empty() {
+                ctx.diagnostic(NoUnescapedEntitiesDiagnostic::Forbidden(c, span));
+            } else {
+                let alternatives = alternatives
+                    .iter()
+                    .map(|alternative| format!("`{alternative}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                ctx.diagnostic(NoUnescapedEntitiesDiagnostic::Escapable(c, alternatives, span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div>Here is some text!</div>", None),
+        ("<div>I&rsquo;ve escaped some entities: &gt; &lt; &amp;</div>", None),
+        ("<div>{\">\" + \"<\" + \"&\" + '\"'}</div>", None),
+        ("<div title=\"It's a > title\">Text</div>", None),
+        ("<></>", None),
+        ("<>Here is some text!</>", None),
+        ("<>{\"Don't\"}</>", None),
+        ("<div>Don't</div>", Some(json!([{ "forbid": [">"] }]))),
+        ("<div>Hello & goodbye</div>", None),
+    ];
+
+    let fail = vec![
+        ("<div>></div>", None),
+        ("<div>Don't</div>", None),
+        ("<>Don't</>", None),
+        ("<div>\"Quoted\"</div>", None),
+        ("<div>{\"Hello\"}}</div>", None),
+        ("<div>\n  first > second\n  <span>'</span>\n</div>", None),
+        ("<div>></div>", Some(json!([{ "forbid": [">"] }]))),
+        ("<div>Hello & goodbye</div>", Some(json!([{ "forbid": ["&"] }]))),
+        (
+            "<div>Hello & goodbye</div>",
+            Some(json!([{ "forbid": [{ "char": "&", "alternatives": ["&amp;"] }] }])),
+        ),
+    ];
+
+    Tester::new(NoUnescapedEntities::NAME, pass, fail).test_and_snapshot();
+}
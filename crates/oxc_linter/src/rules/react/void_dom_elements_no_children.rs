@@ -0,0 +1,138 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    react_ast_util::{
+        get_create_element_props, get_element_type, get_jsx_attribute, get_object_property,
+        is_create_element_call,
+    },
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Void DOM element <{0} /> cannot receive children")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the children, `children` and `dangerouslySetInnerHTML` props")
+)]
+struct VoidDomElementsNoChildrenDiagnostic(Atom, #[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct VoidDomElementsNoChildren;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows children of void DOM elements, e.g. `<img />` or `<br />`,
+    /// including the `children` and `dangerouslySetInnerHTML` props.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React throws an error when a void element has children.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// <br>Children</br>;
+    /// <img dangerouslySetInnerHTML={{ __html: "HTML" }} />;
+    /// React.createElement("br", undefined, "Children");
+    /// ```
+    VoidDomElementsNoChildren,
+    correctness
+);
+
+const VOID_DOM_ELEMENTS: [&str; 16] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "menuitem",
+    "meta", "param", "source", "track", "wbr",
+];
+
+impl Rule for VoidDomElementsNoChildren {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::JSXElement, AstType::CallExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(element) => {
+                let opening = &element.opening_element;
+                let name = get_element_type(&opening.name);
+                if !VOID_DOM_ELEMENTS.contains(&name.as_ref()) {
+                    return;
+                }
+                if !element.children.is_empty()
+                    || get_jsx_attribute(opening, "children").is_some()
+                    || get_jsx_attribute(opening, "dangerouslySetInnerHTML").is_some()
+                {
+                    ctx.diagnostic(VoidDomElementsNoChildrenDiagnostic(
+                        Atom::from(name.as_ref()),
+                        opening.name.span(),
+                    ));
+                }
+            }
+            AstKind::CallExpression(call) if is_create_element_call(call, ctx) => {
+                let Some(Argument::Expression(Expression::StringLiteral(name))) =
+                    call.arguments.first()
+                else {
+                    return;
+                };
+                if !VOID_DOM_ELEMENTS.contains(&name.value.as_str()) {
+                    return;
+                }
+                let has_children_prop = get_create_element_props(call).is_some_and(|props| {
+                    get_object_property(props, "children").is_some()
+                        || get_object_property(props, "dangerouslySetInnerHTML").is_some()
+                });
+                if call.arguments.len() > 2 || has_children_prop {
+                    ctx.diagnostic(VoidDomElementsNoChildrenDiagnostic(
+                        name.value.clone(),
+                        name.span,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<div>Foo</div>;",
+        "<div children=\"Foo\"></div>;",
+        "<div dangerouslySetInnerHTML={{ __html: \"Foo\" }}></div>;",
+        "<br className=\"foo\" />;",
+        "<img />;",
+        "<input></input>;",
+        "<Br>Foo</Br>;",
+        "<foo.br>Foo</foo.br>;",
+        "React.createElement(\"img\");",
+        "React.createElement(\"img\", { className: \"foo\" });",
+        "React.createElement(\"div\", {}, \"Foo\");",
+        "React.createElement(\"div\", { children: \"Foo\" });",
+        "React.createElement(Br, {}, \"Foo\");",
+        "document.createElement(\"img\", {}, \"Foo\");",
+    ];
+
+    let fail = vec![
+        "<br>Foo</br>;",
+        "<br children=\"Foo\" />;",
+        "<img {...props} children=\"Foo\" />;",
+        "<br dangerouslySetInnerHTML={{ __html: \"Foo\" }} />;",
+        "React.createElement(\"br\", {}, \"Foo\");",
+        "React.createElement(\"br\", { children: \"Foo\" });",
+        "React.createElement(\"br\", { dangerouslySetInnerHTML: { __html: \"Foo\" } });",
+        "/** @jsx Preact.h */ Preact.createElement(\"img\", undefined, \"Foo\");",
+    ];
+
+    Tester::new_without_config(VoidDomElementsNoChildren::NAME, pass, fail).test_and_snapshot();
+}
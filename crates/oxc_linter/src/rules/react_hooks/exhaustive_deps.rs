@@ -0,0 +1,410 @@
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, BindingPatternKind, CallExpression, Expression,
+        MemberExpression,
+    },
+    AstKind, AstType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    ast_util::get_enclosing_function, context::LintContext, fixer::Fix,
+    react_ast_util::get_hook_name, rule::Rule, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ExhaustiveDepsDiagnostic {
+    #[error("React Hook {0:?} has missing dependencies: {1}")]
+    #[diagnostic(severity(warning), help("Either include them or remove the dependency array"))]
+    MissingDependencies(Atom, String, #[label] Span),
+    #[error("React Hook {0:?} has unnecessary dependencies: {1}")]
+    #[diagnostic(
+        severity(warning),
+        help("Either exclude them or remove the dependency array, outer scope values are not valid dependencies because mutating them does not re-render the component")
+    )]
+    UnnecessaryDependencies(Atom, String, #[label] Span),
+    #[error("React Hook {0:?} does nothing when called with only one argument")]
+    #[diagnostic(severity(warning), help("Did you forget to pass an array of dependencies?"))]
+    MissingDependencyArray(Atom, #[label] Span),
+    #[error("React Hook {0:?} was passed a dependency list that is not an array literal")]
+    #[diagnostic(
+        severity(warning),
+        help("The dependencies cannot be checked, pass an array literal instead")
+    )]
+    NonArrayDependencies(Atom, #[label] Span),
+    #[error("React Hook {0:?} has a complex expression in the dependency array")]
+    #[diagnostic(
+        severity(warning),
+        help("Extract it to a separate variable so it can be statically checked")
+    )]
+    ComplexDependency(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps {
+    /// Custom hooks whose first argument is a callback and whose second argument are its dependencies
+    additional_hooks: Option<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the dependency arrays of `useEffect`, `useLayoutEffect`, `useInsertionEffect`,
+    /// `useCallback`, `useMemo` and `useImperativeHandle`
+    /// against the values of the component their callback captures.
+    /// The setters of `useState`, the dispatchers of `useReducer` and `useTransition`,
+    /// and the refs of `useRef` are stable and not required.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A callback with missing dependencies sees stale values from a previous render,
+    /// unnecessary dependencies run the callback more often than needed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function Component({ id }) {
+    ///   useEffect(() => {
+    ///     fetchUser(id);
+    ///   }, []);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// * `additionalHooks`: a regular expression of custom hooks to check, e.g. `"(useMyEffect|useOtherEffect)"`
+    ExhaustiveDeps,
+    pedantic
+);
+
+impl Rule for ExhaustiveDeps {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let additional_hooks = value
+            .get(0)
+            .and_then(|v| v.get("additionalHooks"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|pattern| Regex::new(pattern).ok());
+        Self { additional_hooks }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook) = get_hook_name(call) else { return };
+        let Some(kind) = self.hook_kind(hook) else { return };
+        let hook = Atom::from(hook);
+        let callback_index = usize::from(kind == HookKind::ImperativeHandle);
+        let Some(Argument::Expression(callback)) = call.arguments.get(callback_index) else {
+            return;
+        };
+        let Some(deps) = call.arguments.get(callback_index + 1) else {
+            if matches!(kind, HookKind::Memo) {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::MissingDependencyArray(
+                    hook,
+                    call.callee.span(),
+                ));
+            }
+            return;
+        };
+        let Argument::Expression(Expression::ArrayExpression(deps)) = deps else {
+            ctx.diagnostic(ExhaustiveDepsDiagnostic::NonArrayDependencies(hook, deps.span()));
+            return;
+        };
+        // The dependencies of callbacks declared elsewhere are unknown
+        if !callback.get_inner_expression().is_function() {
+            return;
+        }
+        let Some(component) = get_enclosing_function(node, ctx) else { return };
+        // Scopes of the component the hook call is in, its values are dependencies
+        let mut component_scopes = vec![];
+        for scope_id in ctx.scopes().ancestors(node.scope_id()) {
+            component_scopes.push(scope_id);
+            if scope_id == component.scope_id() {
+                break;
+            }
+        }
+
+        let captured = collect_captured(callback.span(), &component_scopes, ctx);
+
+        // Declared dependencies and whether they are used
+        let mut declared = vec![];
+        for element in &deps.elements {
+            let expr = match element {
+                ArrayExpressionElement::Expression(expr) => expr,
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    ctx.diagnostic(ExhaustiveDepsDiagnostic::ComplexDependency(hook, spread.span));
+                    return;
+                }
+                ArrayExpressionElement::Elision(_) => continue,
+            };
+            let Some((root, path)) = get_dependency_path(expr) else {
+                ctx.diagnostic(ExhaustiveDepsDiagnostic::ComplexDependency(hook, expr.span()));
+                return;
+            };
+            let symbol_id = resolve(&root, node.scope_id(), ctx);
+            let is_component_value = symbol_id.is_some_and(|symbol_id| {
+                component_scopes
+                    .iter()
+                    .any(|scope_id| ctx.scopes().get_binding(*scope_id, &root) == Some(symbol_id))
+            });
+            let is_used = is_component_value
+                && (kind == HookKind::Effect
+                    || symbol_id.is_some_and(|symbol_id| is_stable(symbol_id, ctx))
+                    || captured
+                        .iter()
+                        .any(|captured| is_prefix(captured, &path) || is_prefix(&path, captured)));
+            declared.push((expr, path, is_used));
+        }
+
+        let mut missing = Vec::<String>::new();
+        for path in &captured {
+            let is_declared = declared.iter().any(|(_, declared, _)| is_prefix(declared, path));
+            let is_missing = missing.iter().any(|missing| is_prefix(missing, path));
+            if !is_declared && !is_missing {
+                missing.push(path.clone());
+            }
+        }
+        let unnecessary = declared
+            .iter()
+            .filter(|(_, _, is_used)| !is_used)
+            .map(|(_, path, _)| path)
+            .collect::<Vec<_>>();
+        if missing.is_empty() && unnecessary.is_empty() {
+            return;
+        }
+
+        let diagnostic = if missing.is_empty() {
+            ExhaustiveDepsDiagnostic::UnnecessaryDependencies(
+                hook,
+                quote_list(&unnecessary),
+                deps.span,
+            )
+        } else {
+            ExhaustiveDepsDiagnostic::MissingDependencies(hook, quote_list(&missing), deps.span)
+        };
+        ctx.diagnostic_with_suggestion(diagnostic, || {
+            let dependencies = declared
+                .iter()
+                .filter(|(_, _, is_used)| *is_used)
+                .map(|(expr, _, _)| expr.span().source_text(ctx.source_text()).to_string())
+                .chain(missing.iter().cloned())
+                .collect::<Vec<_>>();
+            Fix::new(format!("[{}]", dependencies.join(", ")), deps.span)
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HookKind {
+    /// Dependencies which are not captured by the callback are allowed, e.g. to re-run an effect
+    Effect,
+    Memo,
+    ImperativeHandle,
+}
+
+impl ExhaustiveDeps {
+    fn hook_kind(&self, hook: &str) -> Option<HookKind> {
+        match hook {
+            "useEffect" | "useLayoutEffect" | "useInsertionEffect" => Some(HookKind::Effect),
+            "useCallback" | "useMemo" => Some(HookKind::Memo),
+            "useImperativeHandle" => Some(HookKind::ImperativeHandle),
+            _ if self.additional_hooks.as_ref().is_some_and(|regex| regex.is_match(hook)) => {
+                Some(HookKind::Effect)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Paths of the values of the component read by the callback, in source order,
+/// e.g. `props.user.id` of `props.user.id.toString()`
+fn collect_captured(
+    callback: Span,
+    component_scopes: &[ScopeId],
+    ctx: &LintContext,
+) -> Vec<String> {
+    let mut references = vec![];
+    for scope_id in component_scopes {
+        for symbol_id in ctx.scopes().get_bindings(*scope_id).values() {
+            if is_stable(*symbol_id, ctx) {
+                continue;
+            }
+            for reference in ctx.symbols().get_resolved_references(*symbol_id) {
+                if reference.is_read() && contains(callback, reference.span()) {
+                    references.push(reference);
+                }
+            }
+        }
+    }
+    references.sort_unstable_by_key(|reference| reference.span().start);
+
+    let mut seen = FxHashSet::default();
+    references
+        .into_iter()
+        .map(|reference| get_reference_path(ctx.nodes().get_node(reference.node_id()), ctx))
+        .filter(|path| seen.insert(path.clone()))
+        .collect()
+}
+
+/// The static member chain read from an identifier reference,
+/// without the name of a called method, e.g. `a.b` of `a.b.c()`
+fn get_reference_path<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> String {
+    let AstKind::IdentifierReference(ident) = node.kind() else { return String::new() };
+    let mut path = ident.name.to_string();
+    let mut node = node;
+    while let Some(parent) = ctx.nodes().parent_node(node.id()) {
+        let AstKind::MemberExpression(member) = parent.kind() else { break };
+        let MemberExpression::StaticMemberExpression(member) = member else { break };
+        if member.object.span() != node_span(node) {
+            break;
+        }
+        let is_callee = matches!(
+            ctx.nodes().parent_kind(parent.id()),
+            Some(AstKind::CallExpression(CallExpression { callee, .. })) if callee.span() == member.span
+        );
+        if is_callee {
+            break;
+        }
+        path.push('.');
+        path.push_str(&member.property.name);
+        node = parent;
+    }
+    path
+}
+
+fn node_span(node: &AstNode) -> Span {
+    match node.kind() {
+        AstKind::IdentifierReference(ident) => ident.span,
+        AstKind::MemberExpression(member) => member.span(),
+        _ => Span::default(),
+    }
+}
+
+/// The root identifier and path of a dependency, e.g. `props` and `props.user.id`
+fn get_dependency_path(expr: &Expression) -> Option<(Atom, String)> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some((ident.name.clone(), ident.name.to_string())),
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::StaticMemberExpression(member) => {
+                let (root, path) = get_dependency_path(&member.object)?;
+                Some((root, format!("{path}.{}", member.property.name)))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn resolve(name: &Atom, scope_id: ScopeId, ctx: &LintContext) -> Option<SymbolId> {
+    ctx.scopes().ancestors(scope_id).find_map(|scope_id| ctx.scopes().get_binding(scope_id, name))
+}
+
+/// The setters of `useState`, the dispatchers of `useReducer` and `useTransition`
+/// and the refs of `useRef` do not change between renders
+fn is_stable(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    let AstKind::VariableDeclarator(decl) = declaration.kind() else { return false };
+    let Some(Expression::CallExpression(init)) = &decl.init else { return false };
+    let Some(hook) = get_hook_name(init) else { return false };
+    let span = ctx.symbols().get_span(symbol_id);
+    match &decl.id.kind {
+        BindingPatternKind::BindingIdentifier(_) => hook == "useRef",
+        BindingPatternKind::ArrayPattern(array) => {
+            matches!(hook, "useState" | "useReducer" | "useTransition")
+                && array.elements.get(1).and_then(Option::as_ref).is_some_and(|element| {
+                    matches!(&element.kind, BindingPatternKind::BindingIdentifier(ident) if ident.span == span)
+                })
+        }
+        _ => false,
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Whether `path` is `prefix` or a member of it, e.g. `a.b` of `a`
+fn is_prefix(prefix: &str, path: &str) -> bool {
+    path.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+fn quote_list<S: AsRef<str>>(names: &[S]) -> String {
+    names.iter().map(|name| format!("'{}'", name.as_ref())).collect::<Vec<_>>().join(", ")
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function C({ a }) { useEffect(() => { f(a); }, [a]); }", None),
+        ("function C() { useEffect(() => {}); }", None),
+        ("function C() { useEffect(() => { f(); }, []); }", None),
+        ("const b = 1; function C() { useEffect(() => { f(b); }, []); }", None),
+        ("function C() { const [s, setS] = useState(0); useEffect(() => { setS(1); }, []); }", None),
+        ("function C() { const [s, dispatch] = useReducer(r, 0); useEffect(() => { dispatch(1); }, []); }", None),
+        ("function C() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }", None),
+        ("function C(props) { useEffect(() => { f(props.a.b); }, [props.a]); }", None),
+        ("function C(props) { useEffect(() => { f(props.a); }, [props]); }", None),
+        ("function C(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("function C({ a }) { const b = useMemo(() => a * 2, [a]); }", None),
+        ("function C({ a }) { const f = useCallback(() => { const c = 1; g(c, a); }, [a]); }", None),
+        ("function C({ a, b }) { useEffect(() => { f(a); }, [a, b]); }", None),
+        ("function C({ a }) { React.useEffect(() => { f(a); }, [a]); }", None),
+        ("function C({ a }) { useEffect(handler, [a]); }", None),
+        ("function C({ a }) { useImperativeHandle(ref, () => ({ a }), [a]); }", None),
+        ("function C({ a }) { useCustom(() => { f(a); }, []); }", None),
+        ("function C({ a }) { useCustom(() => { f(a); }, [a]); }", Some(json!([{ "additionalHooks": "useCustom" }]))),
+    ];
+
+    let fail = vec![
+        ("function C({ a }) { useEffect(() => { f(a); }, []); }", None),
+        ("function C({ a, b }) { useEffect(() => { f(a, b); }, [a]); }", None),
+        ("function C(props) { useEffect(() => { f(props.a); }, []); }", None),
+        ("function C(props) { useEffect(() => { f(props.a); }, [props.b]); }", None),
+        ("function C() { const [s, setS] = useState(0); useEffect(() => { f(s); }, []); }", None),
+        ("function C() { function g() {} useEffect(() => { g(); }, []); }", None),
+        ("function C({ a, b }) { const c = useMemo(() => a, [a, b]); }", None),
+        ("const b = 1; function C() { useEffect(() => {}, [b]); }", None),
+        ("function C({ a }) { const c = useMemo(() => a); }", None),
+        ("function C({ a }) { useEffect(() => { f(a); }, deps); }", None),
+        ("function C({ a }) { useEffect(() => { f(a); }, [a.b()]); }", None),
+        ("function C({ a }) { useImperativeHandle(ref, () => ({ a }), []); }", None),
+        (
+            "function C({ a }) { useCustom(() => { f(a); }, []); }",
+            Some(json!([{ "additionalHooks": "useCustom" }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "function C({ a }) { useEffect(() => { f(a); }, []); }",
+            "function C({ a }) { useEffect(() => { f(a); }, [a]); }",
+            None,
+        ),
+        (
+            "function C(props) { useEffect(() => { f(props.a, props.b); }, [props.a]); }",
+            "function C(props) { useEffect(() => { f(props.a, props.b); }, [props.a, props.b]); }",
+            None,
+        ),
+        (
+            "function C({ a, b }) { const c = useMemo(() => a, [a, b]); }",
+            "function C({ a, b }) { const c = useMemo(() => a, [a]); }",
+            None,
+        ),
+    ];
+
+    let mut tester = Tester::new(ExhaustiveDeps::NAME, pass, fail);
+    tester.test_and_snapshot();
+    tester.test_fix(fix);
+}
@@ -0,0 +1,290 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{BasicBlockId, EdgeType};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    ast_util::get_enclosing_function,
+    context::LintContext,
+    react_ast_util::{
+        get_function_name, get_hook_name, is_component_name, is_component_wrapper_callback,
+        is_hook_name,
+    },
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum RulesOfHooksDiagnostic {
+    #[error("React Hook {0:?} is called conditionally")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in the exact same order in every component render")
+    )]
+    Conditional(Atom, #[label] Span),
+    #[error("React Hook {0:?} is called after an early return")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in the exact same order in every component render, call it before the `return`")
+    )]
+    EarlyReturn(Atom, #[label] Span),
+    #[error("React Hook {0:?} is called in a loop")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in the exact same order in every component render, it may be called more than once")
+    )]
+    Loop(Atom, #[label] Span),
+    #[error("React Hook {0:?} cannot be called in an async function")]
+    #[diagnostic(severity(warning))]
+    Async(Atom, #[label] Span),
+    #[error(
+        "React Hook {0:?} is called in function {1:?} that is neither a React function component nor a custom React Hook function"
+    )]
+    #[diagnostic(
+        severity(warning),
+        help("React component names must start with an uppercase letter, React Hook names must start with the word \"use\"")
+    )]
+    InvalidFunction(Atom, Atom, #[label] Span),
+    #[error("React Hook {0:?} cannot be called inside a callback")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in a React function component or a custom React Hook function")
+    )]
+    Callback(Atom, #[label] Span),
+    #[error("React Hook {0:?} cannot be called in a class component")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in a React function component or a custom React Hook function")
+    )]
+    Class(Atom, #[label] Span),
+    #[error("React Hook {0:?} cannot be called at the top level")]
+    #[diagnostic(
+        severity(warning),
+        help("React Hooks must be called in a React function component or a custom React Hook function")
+    )]
+    TopLevel(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RulesOfHooks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces the Rules of Hooks: hooks are only called at the top level
+    /// of React function components and custom hooks.
+    /// Hooks are functions whose name starts with `use`, e.g. `useState` or `React.useState`,
+    /// components are functions whose name starts with an uppercase letter
+    /// and the callbacks of `memo` and `forwardRef`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React relies on the order in which hooks are called to associate them with their state.
+    /// Hooks called conditionally, in loops or after an early return
+    /// are not called in the same order in every render.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function Component({ enabled }) {
+    ///   if (enabled) {
+    ///     useEffect(() => {});
+    ///   }
+    /// }
+    /// ```
+    RulesOfHooks,
+    correctness
+);
+
+impl Rule for RulesOfHooks {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook) = get_hook_name(call) else { return };
+        let hook = Atom::from(hook);
+        let span = call.callee.span();
+
+        let Some(function) = get_enclosing_function(node, ctx) else {
+            ctx.diagnostic(RulesOfHooksDiagnostic::TopLevel(hook, span));
+            return;
+        };
+        let is_method = matches!(
+            ctx.nodes().parent_kind(function.id()),
+            Some(AstKind::MethodDefinition(_) | AstKind::PropertyDefinition(_))
+        );
+        if is_method {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Class(hook, span));
+            return;
+        }
+        let name = get_function_name(function, ctx);
+        let is_component_or_hook = is_component_wrapper_callback(function, ctx)
+            || name.as_ref().is_some_and(|name| is_component_name(name) || is_hook_name(name));
+        if !is_component_or_hook {
+            ctx.diagnostic(match name {
+                Some(name) => RulesOfHooksDiagnostic::InvalidFunction(hook, name, span),
+                None => RulesOfHooksDiagnostic::Callback(hook, span),
+            });
+            return;
+        }
+
+        let is_async = match function.kind() {
+            AstKind::Function(func) => func.r#async,
+            AstKind::ArrowExpression(arrow) => arrow.r#async,
+            _ => return,
+        };
+        if is_async {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Async(hook, span));
+        } else if is_in_loop(node, function, ctx) {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Loop(hook, span));
+        } else if is_conditional(node, function, ctx) {
+            ctx.diagnostic(RulesOfHooksDiagnostic::Conditional(hook, span));
+        } else if can_return_without(node, function, ctx) {
+            ctx.diagnostic(RulesOfHooksDiagnostic::EarlyReturn(hook, span));
+        }
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// The ancestors of `node` inside of `function`
+fn ancestors_within<'a, 'b>(
+    node: &'b AstNode<'a>,
+    function: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> impl Iterator<Item = &'b AstNode<'a>> + 'b {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .take_while(move |parent| parent.id() != function.id())
+}
+
+/// Whether the call is in the body of a loop, evaluated more than once per call of the function
+fn is_in_loop<'a>(node: &AstNode<'a>, function: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let AstKind::CallExpression(call) = node.kind() else { return false };
+    ancestors_within(node, function, ctx).any(|ancestor| match ancestor.kind() {
+        AstKind::ForStatement(stmt) => {
+            !stmt.init.as_ref().is_some_and(|init| contains(init.span(), call.span))
+        }
+        AstKind::ForInStatement(stmt) => !contains(stmt.right.span(), call.span),
+        AstKind::ForOfStatement(stmt) => !contains(stmt.right.span(), call.span),
+        AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => true,
+        _ => false,
+    })
+}
+
+/// Whether the call is only evaluated on some paths, e.g. in `if` statements,
+/// the branches of `?:` or the right side of `&&`
+fn is_conditional<'a>(node: &AstNode<'a>, function: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let AstKind::CallExpression(call) = node.kind() else { return false };
+    if call.optional {
+        return true;
+    }
+    ancestors_within(node, function, ctx).any(|ancestor| match ancestor.kind() {
+        AstKind::IfStatement(stmt) => !contains(stmt.test.span(), call.span),
+        AstKind::ConditionalExpression(expr) => !contains(expr.test.span(), call.span),
+        AstKind::LogicalExpression(expr) => contains(expr.right.span(), call.span),
+        AstKind::SwitchCase(case) => {
+            !case.test.as_ref().is_some_and(|test| contains(test.span(), call.span))
+        }
+        AstKind::AssignmentPattern(pattern) => contains(pattern.right.span(), call.span),
+        AstKind::AssignmentTargetWithDefault(target) => contains(target.init.span(), call.span),
+        _ => false,
+    })
+}
+
+/// Whether the function can return on a path that does not evaluate the call,
+/// i.e. there is a `return` before it or the call is unreachable.
+/// Exceptions are not returns, the paths into `catch` and `finally` are ignored.
+fn can_return_without<'a>(
+    node: &AstNode<'a>,
+    function: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let cfg = ctx.semantic().cfg();
+    let (Some(entry), Some(end)) = (cfg.function_entry(function.id()), cfg.function_end(function.id()))
+    else {
+        return false;
+    };
+    let call_block = cfg.basic_block_id(node.id());
+
+    let mut visited = FxHashSet::<BasicBlockId>::default();
+    let mut stack = vec![entry];
+    while let Some(block_id) = stack.pop() {
+        if block_id == call_block || !visited.insert(block_id) {
+            continue;
+        }
+        let block = cfg.basic_block(block_id);
+        let returns = block
+            .nodes()
+            .iter()
+            .any(|node_id| matches!(ctx.nodes().kind(*node_id), AstKind::ReturnStatement(_)));
+        if block_id == end || returns {
+            return true;
+        }
+        stack.extend(
+            block
+                .successors()
+                .iter()
+                .filter(|(_, edge_type)| *edge_type != EdgeType::Exception)
+                .map(|(successor, _)| *successor),
+        );
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function Component() { const [a, setA] = useState(0); useEffect(() => {}); }",
+        "function useCustom() { return useState(0); }",
+        "const Component = () => { useState(0); }",
+        "const useCustom = function () { useContext(Context); }",
+        "function Component() { const a = React.useState(0); }",
+        "function Component() { use(promise); }",
+        "const Component = memo(function () { useState(0); })",
+        "const Component = React.forwardRef((props, ref) => { useImperativeHandle(ref, () => ({})); })",
+        "function Component() { if (a) { return null; } } function useB() { useState(0); }",
+        "function Component() { const a = cond ? b : c; useState(a); }",
+        "function Component() { useEffect(() => { if (a) { b(); } }); }",
+        "function Component() { const onClick = () => { user(); }; useState(0); }",
+        "function Component() { for (const a of useItems()) {} }",
+        "function Component() { function inner() { return 1; } useState(inner()); }",
+        "function Component() { useState(0); if (a) { return null; } }",
+        "function Component() { try { useState(0); } catch { return null; } }",
+        "const obj = { useCustom() { useState(0); } }",
+        "function normal() { userFoo(); useless(); }",
+        "foo.useState()",
+    ];
+
+    let fail = vec![
+        "function Component() { if (a) { useState(0); } }",
+        "function Component() { a && useEffect(() => {}); }",
+        "function Component() { const a = b ? useState(0) : null; }",
+        "function Component() { switch (a) { case 1: useState(0); } }",
+        "function Component() { for (let i = 0; i < 3; i++) { useState(i); } }",
+        "function Component() { while (a) { useState(0); } }",
+        "function Component() { items.forEach(() => { useState(0); }); }",
+        "function Component() { if (a) { return null; } useState(0); }",
+        "function Component() { return null; useState(0); }",
+        "function Component() { a: { if (b) { break a; } return null; } useState(0); }",
+        "function Component({ a = useContext(C) }) {}",
+        "function Component() { useState?.(0); }",
+        "async function Component() { useState(0); }",
+        "function normal() { useState(0); }",
+        "const normal = () => { React.useState(0); }",
+        "class Component extends React.Component { render() { useState(0); } }",
+        "useState(0);",
+        "export default () => { useState(0); }",
+    ];
+
+    Tester::new_without_config(RulesOfHooks::NAME, pass, fail).test_and_snapshot();
+}
@@ -3,7 +3,7 @@ use oxc_ast::{
         ClassElement, Declaration, ExportDefaultDeclarationKind, Expression, FunctionType,
         ModuleDeclaration, PropertyKey, Statement, TSSignature,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -15,9 +15,7 @@ use oxc_span::{Atom, GetSpan, Span};
 use crate::{ast_util::get_name_from_property_key, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(adjacent-overload-signatures): All {0:?} signatures should be adjacent."
-)]
+#[error("All {0:?} signatures should be adjacent.")]
 #[diagnostic(severity(warning))]
 struct AdjacentOverloadSignaturesDiagnostic(Atom, #[label] pub Option<Span>, #[label] pub Span);
 
@@ -281,6 +279,16 @@ fn check_and_report(methods: &Vec<Option<Method>>, ctx: &LintContext<'_>) {
 }
 
 impl Rule for AdjacentOverloadSignatures {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::Class,
+        AstType::TSTypeLiteral,
+        AstType::Program,
+        AstType::TSModuleBlock,
+        AstType::TSInterfaceDeclaration,
+        AstType::BlockStatement,
+        AstType::FunctionBody,
+    ]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::Class(class) => {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ExportNamedDeclaration, ImportOrExportKind, ModuleDeclaration},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -13,7 +13,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(consistent-type-export): Consistent type exports")]
+#[error("Consistent type exports")]
 #[diagnostic(severity(error), help("Consistent type export"))]
 struct ConsistentTypeExportDiagnostic(#[label] pub Span);
 
@@ -54,6 +54,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ConsistentTypeExports {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(module_declaration) = node.kind() else { return };
 
@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{
         BindingPatternKind, Class, ClassElement, Function, MethodDefinitionKind, PropertyDefinition,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -16,15 +16,15 @@ use crate::{ast_util::IsPrivate, context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 enum IsolatedDeclarationDiagnostic {
-    #[error("isolated-declaration: Requires type annotation on exported properties")]
+    #[error("Requires type annotation on exported properties")]
     #[diagnostic(severity(warning))]
     Property(#[label] Span),
 
-    #[error("isolated-declaration: Requires type annotation on export parameters")]
+    #[error("Requires type annotation on export parameters")]
     #[diagnostic(severity(warning))]
     FunctionParam(#[label] Span),
 
-    #[error("isolated-declaration: Requires return type annotation on exported functions")]
+    #[error("Requires return type annotation on exported functions")]
     #[diagnostic(severity(warning))]
     FunctionReturnType(#[label] Span),
 }
@@ -66,6 +66,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for IsolatedDeclaration {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[]);
+
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
         let symbol_table = ctx.semantic().symbols();
         if symbol_table.get_flag(symbol_id).is_export() {
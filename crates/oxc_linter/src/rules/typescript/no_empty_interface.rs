@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,14 +9,12 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(no-empty-interface): an empty interface is equivalent to `{{}}`")]
+#[error("an empty interface is equivalent to `{{}}`")]
 #[diagnostic(severity(warning))]
 struct NoEmptyInterfaceDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(no-empty-interface): an interface declaring no members is equivalent to its supertype"
-)]
+#[error("an interface declaring no members is equivalent to its supertype")]
 #[diagnostic(severity(warning))]
 struct NoEmptyInterfaceExtendDiagnostic(#[label] pub Span);
 
@@ -44,6 +42,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyInterface {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSInterfaceDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSInterfaceDeclaration(interface) = node.kind() {
             if interface.body.body.is_empty() {
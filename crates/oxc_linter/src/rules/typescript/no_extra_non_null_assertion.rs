@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(no-extra-non-null-assertion): extra non-null assertion")]
+#[error("extra non-null assertion")]
 #[diagnostic(severity(warning))]
 struct NoExtraNonNullAssertionDiagnostic(#[label] pub Span);
 
@@ -34,6 +34,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExtraNonNullAssertion {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::TSNonNullExpression, AstType::MemberExpression, AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let expr = match node.kind() {
             AstKind::TSNonNullExpression(expr) => {
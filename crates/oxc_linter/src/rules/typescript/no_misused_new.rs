@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ClassElement, PropertyKey, TSSignature, TSType, TSTypeName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,12 +12,12 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(no-misused-new): Interfaces cannot be constructed, only classes.")]
+#[error("Interfaces cannot be constructed, only classes.")]
 #[diagnostic(severity(warning), help("Consider removing this method from your interface."))]
 struct NoMisusedNewInterfaceDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(no-misused-new): Class cannot have method named `new`.")]
+#[error("Class cannot have method named `new`.")]
 #[diagnostic(
     severity(warning),
     help("This method name is confusing, consider renaming the method to `constructor`")
@@ -57,6 +57,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoMisusedNew {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::TSInterfaceDeclaration, AstType::TSMethodSignature, AstType::Class]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSInterfaceDeclaration(interface_decl) => {
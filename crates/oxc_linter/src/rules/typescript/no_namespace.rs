@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ModifierKind, TSModuleDeclarationName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -43,6 +43,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNamespace {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSModuleDeclaration]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             allow_declarations: value
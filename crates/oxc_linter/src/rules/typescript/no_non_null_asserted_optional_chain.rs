@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ChainElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,9 +12,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(no-non-null-asserted-optional-chain): non-null assertions after an optional chain expression"
-)]
+#[error("non-null assertions after an optional chain expression")]
 #[diagnostic(
     severity(warning),
     help(
@@ -47,6 +45,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNonNullAssertedOptionalChain {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSNonNullExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSNonNullExpression(non_null_expr) = node.kind() {
             let chain_span = match non_null_expr.expression.get_inner_expression() {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{AssignmentTarget, BindingPatternKind, Expression, SimpleAssignmentTarget},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,7 +12,7 @@ use oxc_span::{Atom, GetSpan, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("typescript-eslint(no-this-alias): Unexpected aliasing of 'this' to local variable.")]
+#[error("Unexpected aliasing of 'this' to local variable.")]
 #[diagnostic(
     severity(warning),
     help(
@@ -22,9 +22,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 struct NoThisAliasDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(no-this-alias): Unexpected aliasing of members of 'this' to local variables."
-)]
+#[error("Unexpected aliasing of members of 'this' to local variables.")]
 #[diagnostic(
     severity(warning),
     help("Disabling destructuring of this is not a default, consider allowing destructuring")
@@ -62,6 +60,9 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoThisAlias {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::VariableDeclarator, AstType::AssignmentExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
         let allowed_names = value
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::TSType, AstKind};
+use oxc_ast::{ast::TSType, AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,9 +9,7 @@ use oxc_span::{Atom, Span};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(no-unnecessary-type-constraint): constraining the generic type {0:?} to {1:?} does nothing and is unnecessary"
-)]
+#[error("constraining the generic type {0:?} to {1:?} does nothing and is unnecessary")]
 #[diagnostic(severity(warning), help("Remove the unnecessary {1:?} constraint"))]
 struct NoUnnecessaryTypeConstraintDiagnostic(
     Atom,
@@ -50,6 +48,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnnecessaryTypeConstraint {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSTypeParameterDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSTypeParameterDeclaration(decl) = node.kind() {
             for param in &decl.params {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -9,9 +9,7 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
-#[error(
-    "typescript-eslint(no-var-requires): Use ES6 style imports or import foo = require(\"foo\") imports."
-)]
+#[error("Use ES6 style imports or import foo = require(\"foo\") imports.")]
 #[diagnostic(severity(warning))]
 struct NoVarRequiresDiagnostic(#[label] pub Span);
 
@@ -37,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoVarRequires {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if !ctx.source_type().is_typescript() {
             return;
@@ -0,0 +1,309 @@
+//! Linting files, shared by the CLI and the language server:
+//! discovering the files, parsing them, running the linter, applying fixes,
+//! and mapping the diagnostics to lines and columns.
+
+mod walk;
+
+use std::{
+    ffi::OsStr,
+    path::Path,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+
+pub use self::walk::{has_lintable_extension, Walk, WalkOptions};
+use crate::{Fixer, LintContext, Linter, Message, PartialLoader, RuleInfo};
+
+/// The result of linting a file.
+#[derive(Debug)]
+pub struct LintResult {
+    /// The source text with the fixes applied, `None` when no fix was applied
+    pub fixed_code: Option<String>,
+    /// Diagnostics of the fixed code, or of the source text when no fix was applied
+    pub messages: Vec<Message<'static>>,
+    /// Fixes which were not applied because they overlap with other fixes,
+    /// after all passes of [`Fixer::fix_until_stable`]
+    pub skipped_fixes: Vec<SkippedFix>,
+}
+
+/// A fix which was not applied, the message reporting it is still part of [`LintResult::messages`].
+#[derive(Debug, Clone, Copy)]
+pub struct SkippedFix {
+    pub rule: Option<RuleInfo>,
+    /// Span of the message in the fixed code
+    pub span: Span,
+}
+
+impl SkippedFix {
+    fn new(message: &Message) -> Self {
+        Self { rule: message.rule, span: Span::new(message.start(), message.end()) }
+    }
+
+    /// Move the span by `offset`, for a script embedded at `offset` of a file.
+    fn with_offset(self, offset: u32) -> Self {
+        let span = Span::new(self.span.start + offset, self.span.end + offset);
+        Self { span, ..self }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LintService {
+    linter: Arc<Linter>,
+    /// Apply the fixes of the linter to the source text,
+    /// otherwise they are only attached to the messages, e.g. for code actions of an editor
+    apply_fixes: bool,
+}
+
+impl LintService {
+    pub fn new(linter: Arc<Linter>) -> Self {
+        Self { linter, apply_fixes: true }
+    }
+
+    #[must_use]
+    pub fn with_apply_fixes(mut self, yes: bool) -> Self {
+        self.apply_fixes = yes;
+        self
+    }
+
+    pub fn linter(&self) -> &Arc<Linter> {
+        &self.linter
+    }
+
+    /// Lint the files of `walk` on the rayon thread pool.
+    /// `lint_path` is called on a worker thread for each file and its results are sent to `tx`,
+    /// `number_of_files` is set once all files are walked.
+    ///
+    /// # Panics
+    ///
+    /// * When `mpsc::channel` fails to send.
+    pub fn spawn<T, F>(
+        &self,
+        walk: Walk,
+        number_of_files: &Arc<AtomicUsize>,
+        tx: mpsc::Sender<T>,
+        lint_path: F,
+    ) where
+        T: Send + 'static,
+        F: Fn(&Self, &Path) -> Option<T> + Send + Sync + 'static,
+    {
+        let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
+
+        let number_of_files = Arc::clone(number_of_files);
+        rayon::spawn(move || {
+            let mut count = 0;
+            walk.iter().for_each(|path| {
+                count += 1;
+                tx_path.send(path).unwrap();
+            });
+            number_of_files.store(count, Ordering::Relaxed);
+        });
+
+        let service = self.clone();
+        let lint_path = Arc::new(lint_path);
+        rayon::spawn(move || {
+            while let Ok(path) = rx_path.recv() {
+                let tx = tx.clone();
+                let service = service.clone();
+                let lint_path = Arc::clone(&lint_path);
+                rayon::spawn(move || {
+                    if let Some(result) = lint_path(&service, &path) {
+                        tx.send(result).unwrap();
+                    }
+                    drop(tx);
+                });
+            }
+        });
+    }
+
+    /// Lint `source_text` of the file at `path`, or the scripts embedded in it for
+    /// [`crate::LINT_PARTIAL_LOADER_EXT`]. `None` for unsupported extensions.
+    pub fn lint_source(&self, path: &Path, source_text: &str) -> Option<LintResult> {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let Some(sources) = PartialLoader::parse(ext, source_text) else {
+            let source_type = SourceType::from_path(path).ok()?;
+            return Some(self.lint_text(path, source_text, source_type));
+        };
+
+        let mut fixed_code = String::with_capacity(source_text.len());
+        let mut is_fixed = false;
+        let mut messages = vec![];
+        let mut skipped_fixes = vec![];
+        let mut last_end = 0;
+        for source in sources {
+            let result = self.lint_text(path, source.source_text, source.source_type);
+            let start = source.start as usize;
+            fixed_code.push_str(&source_text[last_end..start]);
+            // The messages are those of the fixed script, which starts here in the fixed file
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = fixed_code.len() as u32;
+            messages.extend(result.messages.into_iter().map(|message| message.with_offset(offset)));
+            skipped_fixes
+                .extend(result.skipped_fixes.into_iter().map(|fix| fix.with_offset(offset)));
+            is_fixed |= result.fixed_code.is_some();
+            fixed_code.push_str(result.fixed_code.as_deref().unwrap_or(source.source_text));
+            last_end = start + source.source_text.len();
+        }
+        fixed_code.push_str(&source_text[last_end..]);
+        Some(LintResult { fixed_code: is_fixed.then_some(fixed_code), messages, skipped_fixes })
+    }
+
+    /// Lint `source_text`, fixing it until it is stable when fixes are applied.
+    fn lint_text(&self, path: &Path, source_text: &str, source_type: SourceType) -> LintResult {
+        if !self.apply_fixes || !self.linter.has_fix() {
+            let messages = self.lint_program(path, source_text, source_type);
+            return LintResult { fixed_code: None, messages, skipped_fixes: vec![] };
+        }
+        let fix_result = Fixer::fix_until_stable(source_text, |source_text| {
+            self.lint_program(path, source_text, source_type)
+        });
+        let skipped_fixes = fix_result.skipped_fixes().map(SkippedFix::new).collect();
+        let fixed_code = fix_result.fixed.then(|| fix_result.fixed_code.into_owned());
+        LintResult { fixed_code, messages: fix_result.messages, skipped_fixes }
+    }
+
+    /// Parse errors and semantic errors are returned instead of lint results.
+    fn lint_program(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Vec<Message<'static>> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+
+        if !ret.errors.is_empty() {
+            return ret.errors.into_iter().map(|error| Message::new(error, None)).collect();
+        };
+
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .with_module_record_builder(true)
+            .build(program);
+
+        if !semantic_ret.errors.is_empty() {
+            return semantic_ret
+                .errors
+                .into_iter()
+                .map(|error| Message::new(error, None))
+                .collect();
+        };
+
+        let lint_ctx =
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        self.linter.run(lint_ctx).into_iter().map(Message::into_owned).collect()
+    }
+}
+
+/// 1-based line and column, the column is counted in UTF-16 code units like ESLint and LSP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Byte offset to line and column conversion
+pub struct LineIndex<'a> {
+    source_text: &'a str,
+    /// Byte offsets of the start of each line
+    line_starts: Vec<usize>,
+    /// UTF-16 code unit offsets of the start of each line
+    utf16_line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        let mut utf16_line_starts = vec![0];
+        let mut utf16_offset = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            utf16_offset += ch.len_utf16();
+            let is_line_break = match ch {
+                '\r' => !chars.peek().is_some_and(|(_, next)| *next == '\n'),
+                '\n' | '\u{2028}' | '\u{2029}' => true,
+                _ => false,
+            };
+            if is_line_break {
+                line_starts.push(offset + ch.len_utf8());
+                utf16_line_starts.push(utf16_offset);
+            }
+        }
+        Self { source_text, line_starts, utf16_line_starts }
+    }
+
+    pub fn location(&self, offset: usize) -> Location {
+        let (line, column) = self.line_and_column(offset);
+        Location { line: line + 1, column: column + 1 }
+    }
+
+    /// The offset in UTF-16 code units, like the `range` of ESLint fixes.
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        let (line, column) = self.line_and_column(offset);
+        self.utf16_line_starts[line] + column
+    }
+
+    /// 0-based line and column in UTF-16 code units of the byte offset
+    fn line_and_column(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.source_text.len());
+        while !self.source_text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let column = self.source_text[self.line_starts[line]..offset].encode_utf16().count();
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use super::{LineIndex, LintService, Location};
+    use crate::Linter;
+
+    #[test]
+    fn line_index() {
+        let line_index = LineIndex::new("a\r\nb\nc😀d\re");
+        assert_eq!(line_index.location(0), Location { line: 1, column: 1 });
+        assert_eq!(line_index.location(3), Location { line: 2, column: 1 });
+        assert_eq!(line_index.location(5), Location { line: 3, column: 1 });
+        // the emoji is two UTF-16 code units
+        assert_eq!(line_index.location(10), Location { line: 3, column: 4 });
+        assert_eq!(line_index.location(12), Location { line: 4, column: 1 });
+        assert_eq!(line_index.utf16_offset(5), 5);
+        assert_eq!(line_index.utf16_offset(10), 8);
+        assert_eq!(line_index.utf16_offset(13), 11);
+    }
+
+    #[test]
+    fn lint_source() {
+        let service = LintService::new(Arc::new(Linter::new().with_fix(true)));
+        let result = service.lint_source(Path::new("a.js"), "debugger;\nlet a = 1;\n").unwrap();
+        assert_eq!(result.fixed_code.as_deref(), Some("\nlet a = 1;\n"));
+        assert!(result.messages.is_empty());
+
+        // Fixes are attached to the messages instead
+        let service = service.with_apply_fixes(false);
+        let result = service.lint_source(Path::new("a.vue"), "<script>debugger;</script>").unwrap();
+        assert!(result.fixed_code.is_none());
+        assert_eq!(result.messages.len(), 1);
+        let fix = result.messages[0].fix.as_ref().unwrap();
+        assert_eq!((fix.span.start, fix.span.end), (8, 17));
+
+        let result = service.lint_source(Path::new("a.js"), "let <").unwrap();
+        assert!(result.messages[0].rule.is_none());
+        assert!(service.lint_source(Path::new("a.txt"), "").is_none());
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use ignore::{overrides::OverrideBuilder, DirEntry, WalkBuilder};
+use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::FxHashSet;
+
+use crate::{config::ConfigStore, LINT_PARTIAL_LOADER_EXT};
+
+/// Which files to walk
+#[derive(Debug, Clone)]
+pub struct WalkOptions {
+    pub paths: Vec<PathBuf>,
+    /// Name of the ignore files, e.g. `.eslintignore`
+    pub ignore_path: PathBuf,
+    /// Do not apply the ignore files and `ignore_pattern`
+    pub no_ignore: bool,
+    /// Patterns of files to ignore, in the `.gitignore` format
+    pub ignore_pattern: Vec<String>,
+}
+
+pub struct Walk {
+    inner: ignore::Walk,
+    /// Skip files matching `ignorePatterns` of the configuration files
+    config_store: Option<Arc<ConfigStore>>,
+    /// Only yield these files, with canonical paths, for `--changed-since` and `--staged`
+    changed_files: Option<Arc<FxHashSet<PathBuf>>>,
+}
+
+impl Walk {
+    /// # Panics
+    pub fn new(options: &WalkOptions) -> Self {
+        let mut inner = WalkBuilder::new(&options.paths[0]);
+
+        if let Some(paths) = options.paths.get(1..) {
+            for path in paths {
+                inner.add(path);
+            }
+        }
+
+        if !options.no_ignore {
+            inner.add_custom_ignore_filename(&options.ignore_path);
+
+            if !options.ignore_pattern.is_empty() {
+                let mut override_builder = OverrideBuilder::new(Path::new("/"));
+                for pattern in &options.ignore_pattern {
+                    // TODO: check this command arg parser
+                    override_builder.add(pattern).unwrap();
+                }
+                let r#override = override_builder.build().unwrap();
+                inner.overrides(r#override);
+            }
+        }
+        // Turning off `follow_links` because:
+        // * following symlinks is a really slow syscall
+        // * it is super rare to have symlinked source code
+        let inner = inner.ignore(false).git_global(false).follow_links(false).build();
+        Self { inner, config_store: None, changed_files: None }
+    }
+
+    #[must_use]
+    pub fn with_config_store(mut self, config_store: Option<Arc<ConfigStore>>) -> Self {
+        self.config_store = config_store;
+        self
+    }
+
+    #[must_use]
+    pub fn with_changed_files(mut self, changed_files: Option<Arc<FxHashSet<PathBuf>>>) -> Self {
+        self.changed_files = changed_files;
+        self
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Box<Path>> {
+        let config_store = self.config_store;
+        let changed_files = self.changed_files;
+        self.inner
+            .filter_map(Result::ok)
+            .filter(Self::is_wanted_entry)
+            .filter(move |entry| {
+                // The walked paths may be relative or contain `..`, e.g. `oxlint ../src`
+                changed_files.as_ref().map_or(true, |changed_files| {
+                    entry.path().canonicalize().is_ok_and(|path| changed_files.contains(&path))
+                })
+            })
+            .filter(move |entry| {
+                // Errors in nested configuration files are reported when linting the file.
+                config_store.as_ref().map_or(true, |config_store| {
+                    !config_store.is_ignored(entry.path()).unwrap_or_default()
+                })
+            })
+            .map(|entry| entry.path().to_path_buf().into_boxed_path())
+    }
+
+    fn is_wanted_entry(dir_entry: &DirEntry) -> bool {
+        let Some(file_type) = dir_entry.file_type() else { return false };
+        if file_type.is_dir() {
+            return false;
+        }
+        let Some(file_name) = dir_entry.path().file_name() else { return false };
+        if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
+            return false;
+        }
+        has_lintable_extension(dir_entry.path())
+    }
+}
+
+/// JavaScript and TypeScript files, and the files [`crate::PartialLoader`] extracts scripts from.
+pub fn has_lintable_extension(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| {
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+            || LINT_PARTIAL_LOADER_EXT.contains(&extension.as_ref())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use rustc_hash::FxHashSet;
+
+    use super::{Walk, WalkOptions};
+
+    #[test]
+    fn changed_files() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let changed = fixtures.join("index.ts").canonicalize().unwrap();
+        let options = WalkOptions {
+            paths: vec![fixtures.join("../import")],
+            ignore_path: ".eslintignore".into(),
+            no_ignore: false,
+            ignore_pattern: vec![],
+        };
+        let paths = Walk::new(&options)
+            .with_changed_files(Some(Arc::new(FxHashSet::from_iter([changed]))))
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec![fixtures.join("../import/index.ts").into_boxed_path()]);
+    }
+}
@@ -7,13 +7,15 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 use serde_json::Value;
 
-use crate::{rules::RULES, Fixer, LintContext, Linter, Message};
+use crate::{rules::RULES, AllowWarnDeny, Fixer, LintContext, Linter, Message, RuleWithSeverity};
 
 pub struct Tester {
     rule_name: &'static str,
     expect_pass: Vec<(String, Option<Value>)>,
     expect_fail: Vec<(String, Option<Value>)>,
     snapshot: String,
+    report_unused_directives: bool,
+    import_plugin: bool,
 }
 
 impl Tester {
@@ -24,7 +26,14 @@ impl Tester {
     ) -> Self {
         let expect_pass = expect_pass.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
         let expect_fail = expect_fail.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
-        Self { rule_name, expect_pass, expect_fail, snapshot: String::new() }
+        Self {
+            rule_name,
+            expect_pass,
+            expect_fail,
+            snapshot: String::new(),
+            report_unused_directives: false,
+            import_plugin: false,
+        }
     }
 
     pub fn new_without_config<S: Into<String>>(
@@ -34,7 +43,28 @@ impl Tester {
     ) -> Self {
         let expect_pass = expect_pass.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
         let expect_fail = expect_fail.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
-        Self { rule_name, expect_pass, expect_fail, snapshot: String::new() }
+        Self {
+            rule_name,
+            expect_pass,
+            expect_fail,
+            snapshot: String::new(),
+            report_unused_directives: false,
+            import_plugin: false,
+        }
+    }
+
+    /// Also report `eslint-disable` directives which did not suppress any diagnostic
+    #[must_use]
+    pub fn with_report_unused_directives(mut self) -> Self {
+        self.report_unused_directives = true;
+        self
+    }
+
+    /// Lint the code as `fixtures/import/index.ts` with the module graph of the import plugin
+    #[must_use]
+    pub fn with_import_plugin(mut self) -> Self {
+        self.import_plugin = true;
+        self
     }
 
     pub fn test(&mut self) {
@@ -82,8 +112,7 @@ impl Tester {
     }
 
     fn run(&mut self, source_text: &str, config: Option<Value>) -> bool {
-        let name = self.rule_name.replace('-', "_");
-        let path = PathBuf::from(name).with_extension("tsx");
+        let path = self.path();
         let allocator = Allocator::default();
         let result = self.run_rules(&allocator, &path, source_text, config, false);
         if result.is_empty() {
@@ -109,8 +138,7 @@ impl Tester {
         source_text: &'a str,
         config: Option<Value>,
     ) -> Option<Cow<'a, str>> {
-        let name = self.rule_name.replace('-', "_");
-        let path = PathBuf::from(name).with_extension("tsx");
+        let path = self.path();
         let result = self.run_rules(allocator, &path, source_text, config, true);
         if result.is_empty() {
             return None;
@@ -120,6 +148,15 @@ impl Tester {
         Some(fix_result.fixed_code)
     }
 
+    fn path(&self) -> PathBuf {
+        if self.import_plugin {
+            // Relative to the crate root, so snapshots do not depend on the checkout location
+            return PathBuf::from("fixtures/import/index.ts");
+        }
+        let name = self.rule_name.replace('-', "_");
+        PathBuf::from(name).with_extension("tsx")
+    }
+
     fn run_rules<'a>(
         &mut self,
         allocator: &'a Allocator,
@@ -144,7 +181,15 @@ impl Tester {
             .find(|rule| rule.name() == self.rule_name)
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name));
         let rule = rule.read_json(config);
-        let lint_context = LintContext::new(&Rc::new(semantic_ret.semantic));
-        Linter::from_rules(vec![rule]).with_fix(is_fix).run(lint_context)
+        let lint_context =
+            LintContext::new(path.clone().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        // Snapshots show the severity declared by the rule, `-W` and `-D` are tested by the linter
+        Linter::from_rules(vec![RuleWithSeverity::new(rule, AllowWarnDeny::Deny)])
+            .with_declared_severity()
+            .with_fix(is_fix)
+            .with_fix_suggestions(is_fix)
+            .with_report_unused_directives(self.report_unused_directives)
+            .with_import_plugin(self.import_plugin)
+            .run(lint_context)
     }
 }
@@ -99,12 +99,24 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            pub fn source_path(&self) -> &'static str {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::SOURCE_PATH),*
+                }
+            }
+
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #mod_names),*
                 }
             }
 
+            pub fn node_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::NODE_TYPES),*
+                }
+            }
+
             pub fn read_json(&self, maybe_value: Option<serde_json::Value>) -> Self {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
@@ -85,6 +85,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
+
+            const SOURCE_PATH: &'static str = file!();
         }
     };
 
@@ -23,18 +23,25 @@ fn main() {
     let name = args.subcommand().ok().flatten().unwrap_or_else(|| String::from("test.js"));
     let mangle = args.contains("--mangle");
     let twice = args.contains("--twice");
+    let source_map = args.contains("--source-map");
 
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{name} not found"));
     let source_type = SourceType::from_path(path).unwrap();
 
-    let options = MinifierOptions { mangle, ..MinifierOptions::default() };
-    let printed = Minifier::new(&source_text, source_type, options).build();
+    let options = MinifierOptions { mangle, source_map, ..MinifierOptions::default() };
+    let ret = Minifier::new(&source_text, source_type, options).with_source_name(&name).build();
+    let printed = ret.code;
     println!("{printed}");
 
+    if let Some(source_map) = ret.source_map {
+        source_map.to_writer(std::io::stdout()).unwrap();
+        println!();
+    }
+
     if twice {
         let options = MinifierOptions { mangle, ..MinifierOptions::default() };
-        let printed = Minifier::new(&printed, source_type, options).build();
+        let printed = Minifier::new(&printed, source_type, options).build().code;
         println!("{printed}");
     }
 }
@@ -12,7 +12,7 @@ use oxc_span::SourceType;
 pub use crate::{
     compressor::{CompressOptions, Compressor},
     mangler::ManglerBuilder,
-    printer::{Printer, PrinterOptions},
+    printer::{Printer, PrinterOptions, PrinterReturn, SourceMap},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -20,26 +20,43 @@ pub struct MinifierOptions {
     pub mangle: bool,
     pub compress: CompressOptions,
     pub print: PrinterOptions,
+    /// Generate a Source Map v3 for the minified code, see [`Minifier::with_source_name`].
+    pub source_map: bool,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: true, compress: CompressOptions::default(), print: PrinterOptions }
+        Self {
+            mangle: true,
+            compress: CompressOptions::default(),
+            print: PrinterOptions,
+            source_map: false,
+        }
     }
 }
 
 pub struct Minifier<'a> {
     source_text: &'a str,
     source_type: SourceType,
+    source_name: &'a str,
     options: MinifierOptions,
 }
 
+pub type MinifierReturn = PrinterReturn;
+
 impl<'a> Minifier<'a> {
     pub fn new(source_text: &'a str, source_type: SourceType, options: MinifierOptions) -> Self {
-        Self { source_text, source_type, options }
+        Self { source_text, source_type, source_name: "", options }
     }
 
-    pub fn build(self) -> String {
+    /// The name of the source file listed in `sources` of the generated source map.
+    #[must_use]
+    pub fn with_source_name(mut self, source_name: &'a str) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    pub fn build(self) -> MinifierReturn {
         let allocator = Allocator::default();
         let ret = Parser::new(&allocator, self.source_text, self.source_type).parse();
         let ret = AstLower::new(&allocator, self.source_text, self.source_type).build(&ret.program);
@@ -51,6 +68,9 @@ impl<'a> Minifier<'a> {
             let mangler = ManglerBuilder::new(self.source_text, self.source_type).build(program);
             printer.with_mangler(mangler);
         }
+        if self.options.source_map {
+            printer.with_source_map(self.source_name, self.source_text);
+        }
         printer.build(program)
     }
 }
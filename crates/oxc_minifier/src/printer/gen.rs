@@ -2,6 +2,7 @@ use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
 use oxc_hir::hir::*;
 use oxc_hir::precedence;
+use oxc_span::GetSpan;
 use oxc_syntax::{
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
@@ -71,6 +72,7 @@ impl Gen for Directive {
 
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Printer, ctx: Context) {
+        p.add_source_mapping(self.span().start);
         match self {
             Self::BlockStatement(stmt) => stmt.gen(p, ctx),
             Self::BreakStatement(stmt) => stmt.gen(p, ctx),
@@ -722,6 +724,7 @@ impl<'a> Gen for ExportDefaultDeclarationKind<'a> {
 
 impl<'a> GenExpr for Expression<'a> {
     fn gen_expr(&self, p: &mut Printer, precedence: Precedence, ctx: Context) {
+        p.add_source_mapping(self.span().start);
         match self {
             Self::BooleanLiteral(lit) => lit.gen(p, ctx),
             Self::NullLiteral(lit) => lit.gen(p, ctx),
@@ -765,7 +768,11 @@ impl Gen for IdentifierReference {
     fn gen(&self, p: &mut Printer, ctx: Context) {
         if let Some(mangler) = &p.mangler {
             if let Some(name) = mangler.get_reference_name(self.reference_id.clone().into_inner()) {
-                p.print_str(name.clone().as_bytes());
+                let name = name.clone();
+                if name != self.name {
+                    p.add_source_mapping_for_name(self.span.start, &self.name);
+                }
+                p.print_str(name.as_bytes());
                 return;
             }
         }
@@ -781,7 +788,7 @@ impl Gen for IdentifierName {
 
 impl Gen for BindingIdentifier {
     fn gen(&self, p: &mut Printer, ctx: Context) {
-        p.print_symbol(self.symbol_id.clone().into_inner(), &self.name);
+        p.print_symbol(self.span, self.symbol_id.clone().into_inner(), &self.name);
     }
 }
 
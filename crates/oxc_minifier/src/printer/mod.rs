@@ -6,6 +6,7 @@
 mod context;
 mod gen;
 mod operator;
+mod source_map;
 
 use std::{rc::Rc, str::from_utf8_unchecked};
 
@@ -21,11 +22,13 @@ use oxc_syntax::{
     },
     precedence::Precedence,
 };
+pub use sourcemap::SourceMap;
 
 use self::{
     context::Context,
     gen::{Gen, GenExpr},
     operator::Operator,
+    source_map::SourceMapGenerator,
 };
 use crate::mangler::Mangler;
 
@@ -37,6 +40,8 @@ pub struct Printer {
 
     mangler: Option<Mangler>,
 
+    source_map: Option<SourceMapGenerator>,
+
     /// Output Code
     code: Vec<u8>,
 
@@ -55,6 +60,12 @@ pub struct Printer {
     start_of_default_export: usize,
 }
 
+pub struct PrinterReturn {
+    pub code: String,
+    /// Present when the printer is configured with [`Printer::with_source_map`].
+    pub source_map: Option<SourceMap>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Separator {
     Comma,
@@ -72,6 +83,7 @@ impl Printer {
         Self {
             options,
             mangler: None,
+            source_map: None,
             code: Vec::with_capacity(capacity),
             needs_semicolon: false,
             need_space_before_dot: 0,
@@ -88,15 +100,36 @@ impl Printer {
         self.mangler = Some(mangler);
     }
 
-    pub fn build(mut self, program: &Program<'_>) -> String {
+    /// Record a Source Map v3 mapping the printed code back to `source_text`,
+    /// which is embedded into the source map as the content of `source_name`.
+    pub fn with_source_map(&mut self, source_name: &str, source_text: &str) {
+        self.source_map = Some(SourceMapGenerator::new(source_name, source_text));
+    }
+
+    pub fn build(mut self, program: &Program<'_>) -> PrinterReturn {
         program.gen(&mut self, Context::default());
-        self.into_code()
+        let source_map = self.source_map.take().map(SourceMapGenerator::into_source_map);
+        PrinterReturn { code: self.into_code(), source_map }
     }
 
     fn into_code(self) -> String {
         unsafe { String::from_utf8_unchecked(self.code) }
     }
 
+    /// Map the current position in the output to `position` in the original source.
+    fn add_source_mapping(&mut self, position: u32) {
+        if let Some(source_map) = &mut self.source_map {
+            source_map.add_mapping(&self.code, position, None);
+        }
+    }
+
+    /// Map the current position in the output to an identifier renamed from `original_name`.
+    fn add_source_mapping_for_name(&mut self, position: u32, original_name: &str) {
+        if let Some(source_map) = &mut self.source_map {
+            source_map.add_mapping(&self.code, position, Some(original_name));
+        }
+    }
+
     fn code(&self) -> &Vec<u8> {
         &self.code
     }
@@ -246,10 +279,13 @@ impl Printer {
         }
     }
 
-    fn print_symbol(&mut self, symbol_id: SymbolId, fallback: &Atom) {
+    fn print_symbol(&mut self, span: Span, symbol_id: SymbolId, fallback: &Atom) {
         if let Some(mangler) = &self.mangler {
-            let name = mangler.get_symbol_name(symbol_id);
-            self.print_str(name.clone().as_bytes());
+            let name = mangler.get_symbol_name(symbol_id).clone();
+            if name != fallback {
+                self.add_source_mapping_for_name(span.start, fallback);
+            }
+            self.print_str(name.as_bytes());
         } else {
             self.print_str(fallback.as_bytes());
         }
@@ -0,0 +1,134 @@
+use std::str::from_utf8_unchecked;
+
+use sourcemap::{SourceMap, SourceMapBuilder};
+
+/// Records mappings from the printed code back to the original source,
+/// with line and column numbers counted in UTF-16 code units as required by Source Map v3.
+pub struct SourceMapGenerator {
+    builder: SourceMapBuilder,
+    source_id: u32,
+
+    /// Byte offsets of the start of each line in the original source
+    line_offsets: Vec<usize>,
+
+    // Position in the printed code at the last mapping
+    generated_offset: usize,
+    generated_line: u32,
+    generated_column: u32,
+
+    /// The last mapping is held back until the generated position moves on,
+    /// so the innermost node printed at a position wins, e.g. the identifier over its statement.
+    pending: Option<Mapping>,
+}
+
+struct Mapping {
+    generated_line: u32,
+    generated_column: u32,
+    original_line: u32,
+    original_column: u32,
+    name: Option<String>,
+}
+
+impl SourceMapGenerator {
+    pub fn new(source_name: &str, source_text: &str) -> Self {
+        let mut builder = SourceMapBuilder::new(None);
+        let source_id = builder.add_source(source_name);
+        builder.set_source_contents(source_id, Some(source_text));
+        Self {
+            builder,
+            source_id,
+            line_offsets: line_offsets(source_text),
+            generated_offset: 0,
+            generated_line: 0,
+            generated_column: 0,
+            pending: None,
+        }
+    }
+
+    /// Map the end of the printed `code` to byte offset `position` in the original source.
+    /// `name` is the original name of a renamed identifier.
+    pub fn add_mapping(&mut self, code: &[u8], position: u32, name: Option<&str>) {
+        self.advance_generated_position(code);
+
+        let position = position as usize;
+        let Some(source_text) = self.builder.get_source_contents(self.source_id) else { return };
+        if position > source_text.len() || !source_text.is_char_boundary(position) {
+            return;
+        }
+        let line = self.line_offsets.partition_point(|offset| *offset <= position) - 1;
+        let original_column = utf16_len(&source_text[self.line_offsets[line]..position]);
+
+        if self.pending.as_ref().is_some_and(|pending| {
+            pending.generated_line != self.generated_line
+                || pending.generated_column != self.generated_column
+        }) {
+            self.flush();
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let original_line = line as u32;
+        self.pending = Some(Mapping {
+            generated_line: self.generated_line,
+            generated_column: self.generated_column,
+            original_line,
+            original_column,
+            name: name.map(ToString::to_string),
+        });
+    }
+
+    pub fn into_source_map(mut self) -> SourceMap {
+        self.flush();
+        self.builder.into_sourcemap()
+    }
+
+    fn flush(&mut self) {
+        let Some(mapping) = self.pending.take() else { return };
+        let name_id = mapping.name.map(|name| self.builder.add_name(&name));
+        self.builder.add_raw(
+            mapping.generated_line,
+            mapping.generated_column,
+            mapping.original_line,
+            mapping.original_column,
+            Some(self.source_id),
+            name_id,
+        );
+    }
+
+    fn advance_generated_position(&mut self, code: &[u8]) {
+        // Mappings are only added between tokens, which always lie on char boundaries.
+        let printed = unsafe { from_utf8_unchecked(&code[self.generated_offset..]) };
+        for ch in printed.chars() {
+            if is_line_terminator(ch) {
+                self.generated_line += 1;
+                self.generated_column = 0;
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let len = ch.len_utf16() as u32;
+                self.generated_column += len;
+            }
+        }
+        self.generated_offset = code.len();
+    }
+}
+
+fn line_offsets(source_text: &str) -> Vec<usize> {
+    let mut offsets = vec![0];
+    let mut chars = source_text.char_indices().peekable();
+    while let Some((offset, ch)) = chars.next() {
+        if ch == '\r' && chars.peek().is_some_and(|(_, next)| *next == '\n') {
+            continue;
+        }
+        if is_line_terminator(ch) {
+            offsets.push(offset + ch.len_utf8());
+        }
+    }
+    offsets
+}
+
+fn is_line_terminator(ch: char) -> bool {
+    matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn utf16_len(s: &str) -> u32 {
+    s.chars().map(char::len_utf16).sum::<usize>() as u32
+}
@@ -6,7 +6,7 @@ mod oxc;
 mod tdewolff;
 mod terser;
 
-use oxc_minifier::{CompressOptions, Minifier, MinifierOptions, PrinterOptions};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
 use oxc_span::SourceType;
 
 pub(crate) fn test(source_text: &str, expected: &str) {
@@ -16,7 +16,7 @@ pub(crate) fn test(source_text: &str, expected: &str) {
 
 pub(crate) fn test_with_options(source_text: &str, expected: &str, options: MinifierOptions) {
     let source_type = SourceType::default();
-    let minified = Minifier::new(source_text, source_type, options).build();
+    let minified = Minifier::new(source_text, source_type, options).build().code;
     assert_eq!(expected, minified, "for source {source_text}");
 }
 
@@ -27,8 +27,8 @@ pub(crate) fn test_same(source_text: &str) {
 pub(crate) fn test_reparse(source_text: &str) {
     let source_type = SourceType::default();
     let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
-    let minified = Minifier::new(source_text, source_type, options).build();
-    let minified2 = Minifier::new(&minified, source_type, options).build();
+    let minified = Minifier::new(source_text, source_type, options).build().code;
+    let minified2 = Minifier::new(&minified, source_type, options).build().code;
     assert_eq!(minified, minified2, "for source {source_text}");
 }
 
@@ -36,7 +36,7 @@ pub(crate) fn test_without_compress_booleans(source_text: &str, expected: &str)
     let source_type = SourceType::default();
     let compress_options = CompressOptions { booleans: false, ..CompressOptions::default() };
     let options =
-        MinifierOptions { mangle: false, compress: compress_options, print: PrinterOptions };
-    let minified = Minifier::new(source_text, source_type, options).build();
+        MinifierOptions { mangle: false, compress: compress_options, ..MinifierOptions::default() };
+    let minified = Minifier::new(source_text, source_type, options).build().code;
     assert_eq!(expected, minified, "for source {source_text}");
 }
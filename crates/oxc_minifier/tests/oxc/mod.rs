@@ -1,2 +1,3 @@
 mod code_removal;
 mod precedence;
+mod source_map;
@@ -0,0 +1,34 @@
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_span::SourceType;
+
+#[test]
+fn source_map() {
+    let source_text = "(function (bar) {\n  return bar + 1;\n})(2);\nbaz();\n";
+    let options = MinifierOptions { mangle: true, source_map: true, ..MinifierOptions::default() };
+    let ret = Minifier::new(source_text, SourceType::default(), options)
+        .with_source_name("input.js")
+        .build();
+    assert_eq!(ret.code, "(function(a){return a+1})(2);baz()");
+
+    let source_map = ret.source_map.expect("source map should be generated");
+    assert_eq!(source_map.get_source(0), Some("input.js"));
+    assert_eq!(source_map.get_source_contents(0), Some(source_text));
+    assert_eq!(source_map.names().collect::<Vec<_>>(), vec!["bar"]);
+
+    // `return` on line 2
+    let token = source_map.lookup_token(0, 13).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 2));
+    // the mangled `a` in `return a+1`
+    let token = source_map.lookup_token(0, 20).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 9));
+    assert_eq!(token.get_name(), Some("bar"));
+    // `baz()` on line 4
+    let token = source_map.lookup_token(0, 29).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (3, 0));
+}
+
+#[test]
+fn no_source_map_by_default() {
+    let ret = Minifier::new("let x = 1", SourceType::default(), MinifierOptions::default()).build();
+    assert!(ret.source_map.is_none());
+}
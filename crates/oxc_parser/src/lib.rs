@@ -265,4 +265,21 @@ mod test {
         assert!(ret.program.is_empty());
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
+
+    #[test]
+    fn jsx_text_span() {
+        use oxc_ast::ast::{Expression, JSXChild, Statement};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let source = "<div>{a} hello </div>";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        // The text starts after the `}` of the preceding child, not at a previous token
+        let JSXChild::Text(text) = &element.children[1] else { unreachable!() };
+        assert_eq!(text.span, Span::new(8, 15));
+        assert_eq!(&source[8..15], " hello ");
+    }
 }
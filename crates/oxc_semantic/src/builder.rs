@@ -13,6 +13,7 @@ use rustc_hash::FxHashMap;
 use crate::{
     binder::Binder,
     checker::{EarlyErrorJavaScript, EarlyErrorTypeScript},
+    control_flow::{ControlFlowBuilder, EdgeType},
     diagnostics::Redeclaration,
     jsdoc::JSDocBuilder,
     module_record::ModuleRecordBuilder,
@@ -69,6 +70,8 @@ pub struct SemanticBuilder<'a> {
 
     jsdoc: JSDocBuilder<'a>,
 
+    cfg: ControlFlowBuilder<'a>,
+
     check_syntax_error: bool,
 }
 
@@ -101,6 +104,7 @@ impl<'a> SemanticBuilder<'a> {
             module_record_builder: ModuleRecordBuilder::default(),
             unused_labels: UnusedLabels { scopes: vec![], curr_scope: 0, labels: vec![] },
             jsdoc: JSDocBuilder::new(source_text, &trivias),
+            cfg: ControlFlowBuilder::default(),
             check_syntax_error: false,
         }
     }
@@ -152,6 +156,7 @@ impl<'a> SemanticBuilder<'a> {
             module_record,
             jsdoc: self.jsdoc.build(),
             unused_labels: self.unused_labels.labels,
+            cfg: self.cfg.build(),
         };
         SemanticBuilderReturn { semantic, errors: self.errors.into_inner() }
     }
@@ -167,6 +172,7 @@ impl<'a> SemanticBuilder<'a> {
             module_record: ModuleRecord::default(),
             jsdoc: self.jsdoc.build(),
             unused_labels: self.unused_labels.labels,
+            cfg: self.cfg.build(),
         }
     }
 
@@ -184,6 +190,7 @@ impl<'a> SemanticBuilder<'a> {
         let parent_node_id =
             if matches!(kind, AstKind::Program(_)) { None } else { Some(self.current_node_id) };
         self.current_node_id = self.nodes.add_node(ast_node, parent_node_id);
+        self.cfg.add_node(self.current_node_id);
     }
 
     fn pop_ast_node(&mut self) {
@@ -437,6 +444,288 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.pop_ast_node();
         self.try_leave_scope(kind);
     }
+
+    /* ----------  Control Flow ---------- */
+
+    fn visit_break_statement(&mut self, stmt: &'a BreakStatement) {
+        let kind = AstKind::BreakStatement(stmt);
+        self.enter_node(kind);
+        if let Some(break_target) = &stmt.label {
+            self.visit_label_identifier(break_target);
+        }
+        self.cfg.add_break(stmt.label.as_ref().map(|label| label.name.as_str()));
+        self.leave_node(kind);
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &'a ContinueStatement) {
+        let kind = AstKind::ContinueStatement(stmt);
+        self.enter_node(kind);
+        if let Some(continue_target) = &stmt.label {
+            self.visit_label_identifier(continue_target);
+        }
+        self.cfg.add_continue(stmt.label.as_ref().map(|label| label.name.as_str()));
+        self.leave_node(kind);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &'a DoWhileStatement<'a>) {
+        let kind = AstKind::DoWhileStatement(stmt);
+        self.enter_node(kind);
+        let after_block = self.cfg.new_block();
+        let test_block = self.cfg.new_block();
+        let body_block = self.cfg.start_block();
+        self.cfg.enter_breakable(after_block, Some(test_block));
+        self.visit_statement(&stmt.body);
+        self.cfg.leave_jump_target();
+        self.cfg.add_edge(self.cfg.current_block(), test_block, EdgeType::Normal);
+        self.cfg.set_current_block(test_block);
+        self.visit_expression(&stmt.test);
+        self.cfg.add_edge(self.cfg.current_block(), body_block, EdgeType::Backedge);
+        if !is_always_true(&stmt.test) {
+            self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+        }
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &'a ForStatement<'a>) {
+        let kind = AstKind::ForStatement(stmt);
+        self.enter_node(kind);
+        if let Some(init) = &stmt.init {
+            self.visit_for_statement_init(init);
+        }
+        let after_block = self.cfg.new_block();
+        let test_block = self.cfg.start_block();
+        if let Some(test) = &stmt.test {
+            self.visit_expression(test);
+            if !is_always_true(test) {
+                self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+            }
+        }
+        let test_end_block = self.cfg.current_block();
+        // The update expression is visited before the body, but runs after it.
+        let continue_block = if let Some(update) = &stmt.update {
+            let update_block = self.cfg.new_block();
+            self.cfg.set_current_block(update_block);
+            self.visit_expression(update);
+            self.cfg.add_edge(self.cfg.current_block(), test_block, EdgeType::Backedge);
+            update_block
+        } else {
+            test_block
+        };
+        self.cfg.set_current_block(test_end_block);
+        self.cfg.start_block();
+        self.cfg.enter_breakable(after_block, Some(continue_block));
+        self.visit_statement(&stmt.body);
+        self.cfg.leave_jump_target();
+        let edge_type = if stmt.update.is_some() { EdgeType::Normal } else { EdgeType::Backedge };
+        self.cfg.add_edge(self.cfg.current_block(), continue_block, edge_type);
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &'a ForInStatement<'a>) {
+        let kind = AstKind::ForInStatement(stmt);
+        self.enter_node(kind);
+        self.visit_for_statement_left(&stmt.left);
+        self.visit_expression(&stmt.right);
+        self.visit_for_each_body(&stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &'a ForOfStatement<'a>) {
+        let kind = AstKind::ForOfStatement(stmt);
+        self.enter_node(kind);
+        self.visit_for_statement_left(&stmt.left);
+        self.visit_expression(&stmt.right);
+        self.visit_for_each_body(&stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &'a IfStatement<'a>) {
+        let kind = AstKind::IfStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.test);
+        let test_block = self.cfg.current_block();
+        let after_block = self.cfg.new_block();
+        self.cfg.start_block();
+        self.visit_statement(&stmt.consequent);
+        self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+        self.cfg.set_current_block(test_block);
+        if let Some(alternate) = &stmt.alternate {
+            self.cfg.start_block();
+            self.visit_statement(alternate);
+        }
+        self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &'a LabeledStatement<'a>) {
+        let kind = AstKind::LabeledStatement(stmt);
+        self.enter_node(kind);
+        self.visit_label_identifier(&stmt.label);
+        let label = stmt.label.name.as_str();
+        if matches!(
+            stmt.body,
+            Statement::LabeledStatement(_)
+                | Statement::DoWhileStatement(_)
+                | Statement::ForStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::SwitchStatement(_)
+        ) {
+            // `continue label` targets the loop itself
+            self.cfg.push_pending_label(label);
+            self.visit_statement(&stmt.body);
+        } else {
+            let after_block = self.cfg.new_block();
+            self.cfg.enter_labeled_statement(label, after_block);
+            self.visit_statement(&stmt.body);
+            self.cfg.leave_jump_target();
+            self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+            self.cfg.set_current_block(after_block);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &'a ReturnStatement<'a>) {
+        let kind = AstKind::ReturnStatement(stmt);
+        self.enter_node(kind);
+        if let Some(arg) = &stmt.argument {
+            self.visit_expression(arg);
+        }
+        self.cfg.add_return();
+        self.leave_node(kind);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &'a SwitchStatement<'a>) {
+        let kind = AstKind::SwitchStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.discriminant);
+        let discriminant_block = self.cfg.current_block();
+        let after_block = self.cfg.new_block();
+        self.cfg.enter_breakable(after_block, None);
+        // Every case is assumed to be selectable, and each falls through to the next one.
+        let mut previous_case_block = None;
+        for case in &stmt.cases {
+            let case_block = self.cfg.new_block();
+            self.cfg.add_edge(discriminant_block, case_block, EdgeType::Normal);
+            if let Some(previous_case_block) = previous_case_block {
+                self.cfg.add_edge(previous_case_block, case_block, EdgeType::Normal);
+            }
+            self.cfg.set_current_block(case_block);
+            self.visit_switch_case(case);
+            previous_case_block = Some(self.cfg.current_block());
+        }
+        self.cfg.leave_jump_target();
+        if let Some(previous_case_block) = previous_case_block {
+            self.cfg.add_edge(previous_case_block, after_block, EdgeType::Normal);
+        }
+        if !stmt.cases.iter().any(SwitchCase::is_default_case) {
+            self.cfg.add_edge(discriminant_block, after_block, EdgeType::Normal);
+        }
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_throw_statement(&mut self, stmt: &'a ThrowStatement<'a>) {
+        let kind = AstKind::ThrowStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.argument);
+        self.cfg.add_throw();
+        self.leave_node(kind);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &'a TryStatement<'a>) {
+        let kind = AstKind::TryStatement(stmt);
+        self.enter_node(kind);
+        let catch_block = stmt.handler.as_ref().map(|_| self.cfg.new_block());
+        let finally_block = stmt.finalizer.as_ref().map(|_| self.cfg.new_block());
+        let after_block = self.cfg.new_block();
+        let completion_block = finally_block.unwrap_or(after_block);
+
+        self.cfg.enter_try(catch_block, finally_block);
+        self.visit_block_statement(&stmt.block);
+        let mut completes_normally = self.cfg.is_current_block_reachable();
+        self.cfg.add_edge(self.cfg.current_block(), completion_block, EdgeType::Normal);
+
+        if let (Some(handler), Some(catch_block)) = (&stmt.handler, catch_block) {
+            self.cfg.enter_catch(catch_block);
+            self.visit_catch_clause(handler);
+            completes_normally |= self.cfg.is_current_block_reachable();
+            self.cfg.add_edge(self.cfg.current_block(), completion_block, EdgeType::Normal);
+        }
+        self.cfg.leave_try();
+
+        if let (Some(finalizer), Some(finally_block)) = (&stmt.finalizer, finally_block) {
+            self.cfg.set_current_block(finally_block);
+            self.visit_finally_clause(finalizer);
+            // The `finally` clause is also entered by `return`, `throw` and exceptions,
+            // which do not continue after the `try` statement.
+            if completes_normally {
+                self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+            }
+        }
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_while_statement(&mut self, stmt: &'a WhileStatement<'a>) {
+        let kind = AstKind::WhileStatement(stmt);
+        self.enter_node(kind);
+        let after_block = self.cfg.new_block();
+        let test_block = self.cfg.start_block();
+        self.visit_expression(&stmt.test);
+        if !is_always_true(&stmt.test) {
+            self.cfg.add_edge(self.cfg.current_block(), after_block, EdgeType::Normal);
+        }
+        self.cfg.start_block();
+        self.cfg.enter_breakable(after_block, Some(test_block));
+        self.visit_statement(&stmt.body);
+        self.cfg.leave_jump_target();
+        self.cfg.add_edge(self.cfg.current_block(), test_block, EdgeType::Backedge);
+        self.cfg.set_current_block(after_block);
+        self.leave_node(kind);
+    }
+
+    fn visit_function(&mut self, func: &'a Function<'a>) {
+        let kind = AstKind::Function(func);
+        self.enter_node(kind);
+        self.cfg.enter_function(self.current_node_id);
+        if let Some(ident) = &func.id {
+            self.visit_binding_identifier(ident);
+        }
+        self.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            self.visit_function_body(body);
+        }
+        if let Some(parameters) = &func.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &func.return_type {
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.cfg.leave_function();
+        self.leave_node(kind);
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &'a ArrowExpression<'a>) {
+        let kind = AstKind::ArrowExpression(expr);
+        self.enter_node(kind);
+        self.cfg.enter_function(self.current_node_id);
+        self.visit_formal_parameters(&expr.params);
+        self.visit_function_body(&expr.body);
+        if expr.expression {
+            // `() => expr` implicitly returns `expr`
+            self.cfg.add_return();
+        }
+        if let Some(parameters) = &expr.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.cfg.leave_function();
+        self.leave_node(kind);
+    }
 }
 
 impl<'a> SemanticBuilder<'a> {
@@ -667,6 +956,19 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// `for-in` and `for-of` bodies run zero or more times.
+    fn visit_for_each_body(&mut self, body: &'a Statement<'a>) {
+        let after_block = self.cfg.new_block();
+        let head_block = self.cfg.start_block();
+        self.cfg.add_edge(head_block, after_block, EdgeType::Normal);
+        self.cfg.start_block();
+        self.cfg.enter_breakable(after_block, Some(head_block));
+        self.visit_statement(body);
+        self.cfg.leave_jump_target();
+        self.cfg.add_edge(self.cfg.current_block(), head_block, EdgeType::Backedge);
+        self.cfg.set_current_block(after_block);
+    }
+
     fn symbol_flag_from_module_declaration(module: &ModuleDeclaration) -> SymbolFlags {
         if matches!(module, ModuleDeclaration::ImportDeclaration(_)) {
             SymbolFlags::Import
@@ -675,3 +977,8 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 }
+
+/// Loops with a `true` test, such as `while (true)`, can only be exited by `break`.
+fn is_always_true(expr: &Expression) -> bool {
+    matches!(expr.get_inner_expression(), Expression::BooleanLiteral(lit) if lit.value)
+}
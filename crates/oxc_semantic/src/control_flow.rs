@@ -0,0 +1,387 @@
+//! Control Flow Graph
+//!
+//! The graph is built by [`crate::SemanticBuilder`] during the AST pass. Every AST node is
+//! assigned to the basic block that is current when the node is entered, so statements
+//! following a `return`, `throw`, `break` or `continue` end up in a block without any
+//! reachable predecessor.
+//!
+//! Only statements split basic blocks, expressions such as `a && b` or `a ? b : c` stay in
+//! the block of their enclosing statement.
+
+use oxc_index::{define_index_type, IndexVec};
+use rustc_hash::FxHashMap;
+
+use crate::node::AstNodeId;
+
+define_index_type! {
+    pub struct BasicBlockId = usize;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeType {
+    /// Sequential flow, or flow into a branch of a conditional statement
+    Normal,
+    /// `break`, `continue`, or the jump into a `finally` clause caused by them or by `return`
+    Jump,
+    /// Flow back to the head of a loop
+    Backedge,
+    /// Flow into a `catch` or `finally` clause when an exception is thrown
+    Exception,
+}
+
+#[derive(Debug, Default)]
+pub struct BasicBlock {
+    nodes: Vec<AstNodeId>,
+    successors: Vec<(BasicBlockId, EdgeType)>,
+    predecessors: Vec<(BasicBlockId, EdgeType)>,
+    reachable: bool,
+}
+
+impl BasicBlock {
+    /// AST nodes entered while this block was current, in visiting order.
+    pub fn nodes(&self) -> &Vec<AstNodeId> {
+        &self.nodes
+    }
+
+    pub fn successors(&self) -> &Vec<(BasicBlockId, EdgeType)> {
+        &self.successors
+    }
+
+    pub fn predecessors(&self) -> &Vec<(BasicBlockId, EdgeType)> {
+        &self.predecessors
+    }
+
+    /// Is this block reachable from the entry of the program or of its enclosing function?
+    pub fn is_reachable(&self) -> bool {
+        self.reachable
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FunctionFlow {
+    entry: BasicBlockId,
+    /// The block current at the end of the function body, i.e. the implicit `return`.
+    end: BasicBlockId,
+}
+
+#[derive(Debug, Default)]
+pub struct ControlFlowGraph {
+    basic_blocks: IndexVec<BasicBlockId, BasicBlock>,
+    node_blocks: IndexVec<AstNodeId, BasicBlockId>,
+    functions: FxHashMap<AstNodeId, FunctionFlow>,
+}
+
+impl ControlFlowGraph {
+    pub fn basic_blocks(&self) -> &IndexVec<BasicBlockId, BasicBlock> {
+        &self.basic_blocks
+    }
+
+    pub fn basic_block(&self, block_id: BasicBlockId) -> &BasicBlock {
+        &self.basic_blocks[block_id]
+    }
+
+    /// The basic block containing the node.
+    pub fn basic_block_id(&self, node_id: AstNodeId) -> BasicBlockId {
+        self.node_blocks[node_id]
+    }
+
+    /// Can the node be executed?
+    ///
+    /// Function bodies are considered reachable regardless of where the function is declared.
+    pub fn is_reachable(&self, node_id: AstNodeId) -> bool {
+        self.basic_block(self.basic_block_id(node_id)).reachable
+    }
+
+    /// The entry block of a `Function` or `ArrowExpression` node.
+    pub fn function_entry(&self, function_node_id: AstNodeId) -> Option<BasicBlockId> {
+        self.functions.get(&function_node_id).map(|flow| flow.entry)
+    }
+
+    /// The block at the end of the body of a `Function` or `ArrowExpression` node,
+    /// i.e. the implicit `return`.
+    pub fn function_end(&self, function_node_id: AstNodeId) -> Option<BasicBlockId> {
+        self.functions.get(&function_node_id).map(|flow| flow.end)
+    }
+
+    /// Does every path through the body of a `Function` or `ArrowExpression` node
+    /// end in a `return` or `throw` statement?
+    ///
+    /// Arrow functions with an expression body always return.
+    /// Returns `false` for other nodes and for functions without a body.
+    pub fn all_paths_return(&self, function_node_id: AstNodeId) -> bool {
+        self.functions
+            .get(&function_node_id)
+            .is_some_and(|flow| !self.basic_block(flow.end).reachable)
+    }
+}
+
+#[derive(Debug)]
+struct JumpTarget<'a> {
+    labels: Vec<&'a str>,
+    break_target: BasicBlockId,
+    continue_target: Option<BasicBlockId>,
+    /// Loops and `switch` can be exited by a `break` without a label.
+    unlabeled_break: bool,
+}
+
+#[derive(Debug)]
+struct ExceptionTarget {
+    catch: Option<BasicBlockId>,
+    finally: Option<BasicBlockId>,
+    /// Number of jump targets outside of the `try` statement.
+    jump_targets_depth: usize,
+}
+
+#[derive(Debug)]
+struct FunctionState<'a> {
+    current_block: BasicBlockId,
+    jump_targets: Vec<JumpTarget<'a>>,
+    exception_targets: Vec<ExceptionTarget>,
+    pending_labels: Vec<&'a str>,
+}
+
+impl<'a> FunctionState<'a> {
+    fn new(entry: BasicBlockId) -> Self {
+        Self {
+            current_block: entry,
+            jump_targets: vec![],
+            exception_targets: vec![],
+            pending_labels: vec![],
+        }
+    }
+}
+
+/// Control Flow Graph Builder, driven by the visitor of [`crate::SemanticBuilder`].
+#[derive(Debug)]
+pub struct ControlFlowBuilder<'a> {
+    graph: ControlFlowGraph,
+    state: FunctionState<'a>,
+    function_stack: Vec<(AstNodeId, BasicBlockId, FunctionState<'a>)>,
+}
+
+impl<'a> Default for ControlFlowBuilder<'a> {
+    fn default() -> Self {
+        let mut graph = ControlFlowGraph::default();
+        // Program entry
+        let entry =
+            graph.basic_blocks.push(BasicBlock { reachable: true, ..BasicBlock::default() });
+        Self { graph, state: FunctionState::new(entry), function_stack: vec![] }
+    }
+}
+
+impl<'a> ControlFlowBuilder<'a> {
+    pub fn build(self) -> ControlFlowGraph {
+        self.graph
+    }
+
+    pub fn current_block(&self) -> BasicBlockId {
+        self.state.current_block
+    }
+
+    pub fn set_current_block(&mut self, block_id: BasicBlockId) {
+        self.state.current_block = block_id;
+    }
+
+    pub fn is_current_block_reachable(&self) -> bool {
+        self.graph.basic_blocks[self.state.current_block].reachable
+    }
+
+    /// Add a node to the current block, called for nodes in creation order.
+    pub fn add_node(&mut self, node_id: AstNodeId) {
+        let block_id = self.state.current_block;
+        self.graph.basic_blocks[block_id].nodes.push(node_id);
+        let id = self.graph.node_blocks.push(block_id);
+        debug_assert_eq!(id, node_id);
+    }
+
+    /// Create a block without predecessors.
+    pub fn new_block(&mut self) -> BasicBlockId {
+        self.graph.basic_blocks.push(BasicBlock::default())
+    }
+
+    fn new_root_block(&mut self) -> BasicBlockId {
+        self.graph.basic_blocks.push(BasicBlock { reachable: true, ..BasicBlock::default() })
+    }
+
+    /// Create a block and continue in it, flowing from the current block.
+    pub fn start_block(&mut self) -> BasicBlockId {
+        let block_id = self.new_block();
+        self.add_edge(self.state.current_block, block_id, EdgeType::Normal);
+        self.state.current_block = block_id;
+        block_id
+    }
+
+    /// Continue in a new block which cannot be reached from the current one,
+    /// e.g. after a `return` statement.
+    pub fn terminate(&mut self) {
+        self.state.current_block = self.new_block();
+    }
+
+    pub fn add_edge(&mut self, from: BasicBlockId, to: BasicBlockId, edge_type: EdgeType) {
+        self.graph.basic_blocks[from].successors.push((to, edge_type));
+        self.graph.basic_blocks[to].predecessors.push((from, edge_type));
+        if self.graph.basic_blocks[from].reachable {
+            self.mark_reachable(to);
+        }
+    }
+
+    /// Blocks may receive their first reachable predecessor after their successors have
+    /// been connected, e.g. the update expression of a `for` loop, so reachability is
+    /// propagated eagerly.
+    fn mark_reachable(&mut self, block_id: BasicBlockId) {
+        let mut stack = vec![block_id];
+        while let Some(block_id) = stack.pop() {
+            let block = &mut self.graph.basic_blocks[block_id];
+            if block.reachable {
+                continue;
+            }
+            block.reachable = true;
+            stack.extend(block.successors.iter().map(|(to, _)| *to));
+        }
+    }
+
+    /* ----------  Functions ---------- */
+
+    pub fn enter_function(&mut self, function_node_id: AstNodeId) {
+        let entry = self.new_root_block();
+        let outer = std::mem::replace(&mut self.state, FunctionState::new(entry));
+        self.function_stack.push((function_node_id, entry, outer));
+    }
+
+    pub fn leave_function(&mut self) {
+        let Some((function_node_id, entry, outer)) = self.function_stack.pop() else { return };
+        let end = self.state.current_block;
+        self.graph.functions.insert(function_node_id, FunctionFlow { entry, end });
+        self.state = outer;
+    }
+
+    /* ----------  Labels, loops and switch ---------- */
+
+    /// Labels of the labeled statements directly enclosing the next loop or `switch`.
+    pub fn push_pending_label(&mut self, label: &'a str) {
+        self.state.pending_labels.push(label);
+    }
+
+    /// Enter the body of a labeled statement which is not a loop or `switch`, e.g. a block.
+    /// The pending labels of enclosing labeled statements also target it, as in `a: b: { break a; }`.
+    pub fn enter_labeled_statement(&mut self, label: &'a str, break_target: BasicBlockId) {
+        let mut labels = std::mem::take(&mut self.state.pending_labels);
+        labels.push(label);
+        self.state.jump_targets.push(JumpTarget {
+            labels,
+            break_target,
+            continue_target: None,
+            unlabeled_break: false,
+        });
+    }
+
+    pub fn enter_breakable(
+        &mut self,
+        break_target: BasicBlockId,
+        continue_target: Option<BasicBlockId>,
+    ) {
+        let labels = std::mem::take(&mut self.state.pending_labels);
+        self.state.jump_targets.push(JumpTarget {
+            labels,
+            break_target,
+            continue_target,
+            unlabeled_break: true,
+        });
+    }
+
+    pub fn leave_jump_target(&mut self) {
+        self.state.jump_targets.pop();
+    }
+
+    pub fn add_break(&mut self, label: Option<&str>) {
+        let target = self.state.jump_targets.iter().rposition(|target| {
+            label.map_or(target.unlabeled_break, |label| target.labels.contains(&label))
+        });
+        if let Some(index) = target {
+            let break_target = self.state.jump_targets[index].break_target;
+            self.add_jump(index, break_target);
+        }
+        self.terminate();
+    }
+
+    pub fn add_continue(&mut self, label: Option<&str>) {
+        let target = self.state.jump_targets.iter().rposition(|target| {
+            target.continue_target.is_some()
+                && label.map_or(true, |label| target.labels.contains(&label))
+        });
+        if let Some(index) = target {
+            if let Some(continue_target) = self.state.jump_targets[index].continue_target {
+                self.add_jump(index, continue_target);
+            }
+        }
+        self.terminate();
+    }
+
+    /// Jump to a target, passing through the `finally` clauses of the `try` statements
+    /// between the jump and the target.
+    fn add_jump(&mut self, jump_target_index: usize, target: BasicBlockId) {
+        let current_block = self.state.current_block;
+        let finally_blocks = self
+            .state
+            .exception_targets
+            .iter()
+            .filter(|exception| exception.jump_targets_depth > jump_target_index)
+            .filter_map(|exception| exception.finally)
+            .collect::<Vec<_>>();
+        for finally_block in finally_blocks {
+            self.add_edge(current_block, finally_block, EdgeType::Jump);
+        }
+        self.add_edge(current_block, target, EdgeType::Jump);
+    }
+
+    /* ----------  Return, throw and try ---------- */
+
+    pub fn add_return(&mut self) {
+        if let Some(finally_block) =
+            self.state.exception_targets.iter().rev().find_map(|exception| exception.finally)
+        {
+            self.add_edge(self.state.current_block, finally_block, EdgeType::Jump);
+        }
+        self.terminate();
+    }
+
+    pub fn add_throw(&mut self) {
+        if let Some(handler) = self
+            .state
+            .exception_targets
+            .iter()
+            .rev()
+            .find_map(|exception| exception.catch.or(exception.finally))
+        {
+            self.add_edge(self.state.current_block, handler, EdgeType::Exception);
+        }
+        self.terminate();
+    }
+
+    /// Enter the block of a `try` statement. Any statement inside it may throw, so the
+    /// exception edge leaves from the start of the block.
+    pub fn enter_try(&mut self, catch: Option<BasicBlockId>, finally: Option<BasicBlockId>) {
+        let try_block = self.start_block();
+        if let Some(handler) = catch.or(finally) {
+            self.add_edge(try_block, handler, EdgeType::Exception);
+        }
+        let jump_targets_depth = self.state.jump_targets.len();
+        self.state.exception_targets.push(ExceptionTarget { catch, finally, jump_targets_depth });
+    }
+
+    /// Enter the `catch` clause, exceptions thrown from it go to the `finally` clause.
+    pub fn enter_catch(&mut self, catch: BasicBlockId) {
+        self.state.current_block = catch;
+        let finally = self.state.exception_targets.last_mut().and_then(|exception| {
+            exception.catch = None;
+            exception.finally
+        });
+        if let Some(finally) = finally {
+            self.add_edge(catch, finally, EdgeType::Exception);
+        }
+    }
+
+    pub fn leave_try(&mut self) {
+        self.state.exception_targets.pop();
+    }
+}
@@ -1,6 +1,7 @@
 mod binder;
 mod builder;
 mod checker;
+mod control_flow;
 mod diagnostics;
 mod jsdoc;
 mod module_record;
@@ -13,6 +14,7 @@ use std::rc::Rc;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use module_record::ModuleRecordBuilder;
 use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
 use oxc_span::SourceType;
 pub use oxc_syntax::{
@@ -22,6 +24,7 @@ pub use oxc_syntax::{
 };
 
 pub use crate::{
+    control_flow::{BasicBlock, BasicBlockId, ControlFlowGraph, EdgeType},
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
@@ -46,6 +49,8 @@ pub struct Semantic<'a> {
     jsdoc: JSDoc<'a>,
 
     unused_labels: Vec<AstNodeId>,
+
+    cfg: ControlFlowGraph,
 }
 
 impl<'a> Semantic<'a> {
@@ -89,6 +94,10 @@ impl<'a> Semantic<'a> {
         &self.unused_labels
     }
 
+    pub fn cfg(&self) -> &ControlFlowGraph {
+        &self.cfg
+    }
+
     pub fn is_unresolved_reference(&self, node_id: AstNodeId) -> bool {
         let reference_node = self.nodes.get_node(node_id);
         let AstKind::IdentifierReference(id) = reference_node.kind() else {
@@ -0,0 +1,120 @@
+mod util;
+
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use util::SemanticTester;
+
+/// Is the call expression `name()` reachable?
+fn is_call_reachable(semantic: &Semantic, name: &str) -> bool {
+    let node = semantic
+        .nodes()
+        .iter()
+        .find(|node| {
+            matches!(node.kind(), AstKind::CallExpression(call) if call.callee.is_specific_id(name))
+        })
+        .unwrap_or_else(|| panic!("Could not find call to {name}"));
+    semantic.cfg().is_reachable(node.id())
+}
+
+/// Does every path through the first function in the source return?
+fn first_function_always_returns(semantic: &Semantic) -> bool {
+    let node = semantic
+        .nodes()
+        .iter()
+        .find(|node| matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_)))
+        .expect("Could not find a function");
+    semantic.cfg().all_paths_return(node.id())
+}
+
+#[test]
+fn test_reachable() {
+    let cases = [
+        "a(); b();",
+        "function foo() { if (x) { return } a() }",
+        "function foo() { if (x) { return } else { b() } a() }",
+        "while (x) { if (y) break; b() } a()",
+        "while (true) { break } a()",
+        "for (;;) { if (x) break } a()",
+        "for (;;) { break } a()",
+        "for (const x of y) { break; b() } a()",
+        "label: { if (x) break label; b() } a()",
+        "outer: for (;;) { for (;;) { break outer } } a()",
+        "outer: inner: { break outer } a()",
+        "outer: inner: { if (x) break inner; break outer } a()",
+        "switch (x) { case 1: break; default: b() } a()",
+        "try { throw x } catch { } a()",
+        "function foo() { try { return } catch { a() } }",
+        "function foo() { try { return } finally { a() } }",
+        "function foo() { return; function bar() { a() } }",
+        "do { if (x) continue; b() } while (false); a()",
+        "function foo() { for (let i = 0; i < 1; i++) { if (i) continue; return } a() }",
+    ];
+    for source in cases {
+        let tester = SemanticTester::js(source);
+        assert!(
+            is_call_reachable(&tester.build(), "a"),
+            "expected a() to be reachable in\n{source}"
+        );
+    }
+}
+
+#[test]
+fn test_unreachable() {
+    let cases = [
+        "function foo() { return; a() }",
+        "function foo() { if (x) { return } else { return } a() }",
+        "throw x; a()",
+        "while (true) { } a()",
+        "for (;;) { } a()",
+        "for (;;) { continue; a() }",
+        "do { } while (true); a()",
+        "while (x) { break; a() }",
+        "outer: for (;;) { for (;;) { continue outer } a() }",
+        "label: { break label; a() }",
+        "outer: inner: { break outer; a() }",
+        "function foo() { switch (x) { case 1: return; default: throw y } a() }",
+        "try { throw x } finally { } a()",
+        "function foo() { try { return } finally { } a() }",
+        "function foo() { try { return } catch { return } a() }",
+        "function foo() { try { a() } finally { return } a() }",
+    ];
+    for source in cases {
+        let tester = SemanticTester::js(source);
+        let semantic = tester.build();
+        let reachable = semantic.nodes().iter().filter(|node| {
+            matches!(node.kind(), AstKind::CallExpression(call) if call.callee.is_specific_id("a"))
+                && semantic.cfg().is_reachable(node.id())
+        });
+        // In the last case only the call inside the `try` block is reachable.
+        let expected = usize::from(source.contains("try { a() }"));
+        assert_eq!(reachable.count(), expected, "expected a() to be unreachable in\n{source}");
+    }
+}
+
+#[test]
+fn test_all_paths_return() {
+    let cases = [
+        ("function foo() { return 1 }", true),
+        ("function foo() { throw new Error() }", true),
+        ("function foo() { if (x) { return 1 } else { return 2 } }", true),
+        ("function foo() { if (x) { return 1 } }", false),
+        ("function foo() { while (true) { } }", true),
+        ("function foo() { while (true) { if (x) break } }", false),
+        ("function foo() { switch (x) { case 1: return 1; default: return 2 } }", true),
+        ("function foo() { switch (x) { case 1: return 1; case 2: return 2 } }", false),
+        ("function foo() { switch (x) { case 1: default: return 2 } }", true),
+        ("function foo() { try { return 1 } catch { return 2 } }", true),
+        ("function foo() { try { return 1 } catch { } }", false),
+        ("function foo() { try { } finally { return 1 } }", true),
+        ("function foo() { function bar() { return 1 } }", false),
+        ("function foo() { }", false),
+        ("const foo = () => 1", true),
+        ("const foo = () => { }", false),
+        ("class A { get foo() { return 1 } }", true),
+        ("class A { get foo() { } }", false),
+    ];
+    for (source, expected) in cases {
+        let tester = SemanticTester::js(source);
+        assert_eq!(first_function_always_returns(&tester.build()), expected, "{source}");
+    }
+}
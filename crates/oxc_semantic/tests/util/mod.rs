@@ -120,6 +120,7 @@ impl SemanticTester {
     /// 1. No symbol with the given name exists,
     /// 2. More than one symbol with the given name exists, so a symbol cannot
     ///    be uniquely obtained.
+    #[allow(dead_code)]
     pub fn has_some_symbol(&self, name: &str) -> SymbolTester {
         SymbolTester::new_unique(self, self.build(), name)
     }
@@ -165,6 +166,7 @@ impl<'a> SymbolTester<'a> {
         SymbolTester { parent, semantic, target_symbol_name: target.to_string(), test_result: data }
     }
 
+    #[allow(dead_code)]
     pub(super) fn new_unique(
         parent: &'a SemanticTester,
         semantic: Semantic<'a>,
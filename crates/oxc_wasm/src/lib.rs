@@ -1,6 +1,6 @@
 mod options;
 
-use std::{cell::RefCell, collections::BTreeMap, rc::Rc, sync::Arc};
+use std::{cell::RefCell, collections::BTreeMap, path::PathBuf, rc::Rc, sync::Arc};
 
 use oxc_allocator::Allocator;
 use oxc_ast_lower::AstLower;
@@ -192,7 +192,7 @@ impl Oxc {
             self.save_diagnostics(semantic_ret.errors);
 
             let semantic = Rc::new(semantic_ret.semantic);
-            let lint_ctx = LintContext::new(&semantic);
+            let lint_ctx = LintContext::new(PathBuf::from("test.tsx").into_boxed_path(), &semantic);
             let linter_ret = Linter::new().run(lint_ctx);
             let diagnostics = linter_ret.into_iter().map(|e| e.error).collect();
             self.save_diagnostics(diagnostics);
@@ -222,7 +222,7 @@ impl Oxc {
                 printer.with_mangler(mangler);
             }
 
-            self.minified_text = printer.build(hir);
+            self.minified_text = printer.build(hir).code;
         }
 
         if run_options.type_check() {
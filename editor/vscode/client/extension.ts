@@ -98,11 +98,17 @@ export async function activate(context: ExtensionContext) {
       "javascript",
       "typescriptreact",
       "javascriptreact",
+      "vue",
+      "svelte",
+      "astro",
+      "html",
+      "markdown",
+      "mdx",
     ].map(lang => ({ language: lang, scheme: "file" })),
 
     synchronize: {
-      // Notify the server about file changes to '.clientrc files contained in the workspace
-      fileEvents: workspace.createFileSystemWatcher("**/.clientrc"),
+      // Notify the server about changes to the configuration files, the linter is built again
+      fileEvents: workspace.createFileSystemWatcher("**/{.oxlintrc.json,.eslintrc.json}"),
     },
     outputChannel,
     traceOutputChannel,
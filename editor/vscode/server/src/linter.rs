@@ -1,26 +1,19 @@
 use std::{
     fs,
     path::{Path, PathBuf},
-    rc::Rc,
-    sync::{
-        atomic::{AtomicUsize, Ordering},
-        mpsc, Arc,
-    },
+    sync::{atomic::AtomicUsize, mpsc, Arc},
 };
 
-use crate::options::LintOptions;
-use crate::walk::Walk;
+use dashmap::DashMap;
 use miette::NamedSource;
-use oxc_allocator::Allocator;
 use oxc_diagnostics::{
     miette::{self},
     Error, Severity,
 };
-use oxc_linter::{LintContext, Linter, RuleCategory, RULES};
-use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use ropey::Rope;
+use oxc_linter::{
+    config::ConfigStore, has_lintable_extension, AllowWarnDeny, LineIndex, LintResult, LintService,
+    Linter, RuleCategory, RuleInfo, RuleWithSeverity, Walk, WalkOptions, RULES,
+};
 use tower_lsp::lsp_types::{self, Position, Range, Url};
 
 #[derive(Debug)]
@@ -30,6 +23,7 @@ struct ErrorWithPosition {
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
+    pub rule: Option<RuleInfo>,
 }
 
 #[derive(Debug)]
@@ -40,15 +34,19 @@ struct LabeledSpanWithPosition {
 }
 
 impl ErrorWithPosition {
-    pub fn new(error: Error, text: &str, fixed_content: Option<FixedContent>) -> Self {
+    pub fn new(
+        error: Error,
+        line_index: &LineIndex,
+        fixed_content: Option<FixedContent>,
+        rule: Option<RuleInfo>,
+    ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
 
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset(), text).unwrap_or_default(),
-                end_pos: offset_to_position(labeled_span.offset() + labeled_span.len(), text)
-                    .unwrap_or_default(),
+                start_pos: position(line_index, labeled_span.offset()),
+                end_pos: position(line_index, labeled_span.offset() + labeled_span.len()),
                 message: labeled_span.label().map(ToString::to_string),
             })
             .collect();
@@ -56,7 +54,7 @@ impl ErrorWithPosition {
         let start_pos = labels_with_pos[0].start_pos;
         let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content, rule }
     }
 
     fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
@@ -93,13 +91,20 @@ impl ErrorWithPosition {
             |help| format!("{}\nhelp: {}", self.miette_err, help),
         );
 
+        let code = self.rule.map(|rule| lsp_types::NumberOrString::String(rule.to_string()));
+        let code_description = self
+            .rule
+            .and_then(|rule| rule.documentation_url())
+            .and_then(|url| Url::parse(&url).ok())
+            .map(|href| lsp_types::CodeDescription { href });
+
         lsp_types::Diagnostic {
             range: Range { start: self.start_pos, end: self.end_pos },
             severity,
-            code: None,
+            code,
             message,
             source: Some("oxc".into()),
-            code_description: None,
+            code_description,
             related_information,
             tags: None,
             data: None,
@@ -124,6 +129,7 @@ pub struct DiagnosticReport {
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub rule: Option<RuleInfo>,
 }
 
 #[derive(Debug, Clone)]
@@ -134,29 +140,34 @@ pub struct FixedContent {
 
 #[derive(Debug)]
 pub struct IsolatedLintHandler {
-    options: Arc<LintOptions>,
-    linter: Arc<Linter>,
+    options: Arc<WalkOptions>,
+    service: LintService,
 }
 
 impl IsolatedLintHandler {
-    pub fn new(options: Arc<LintOptions>, linter: Arc<Linter>) -> Self {
-        Self { options, linter }
+    pub fn new(options: Arc<WalkOptions>, linter: Arc<Linter>) -> Self {
+        // Fixes are offered as code actions instead of being written to the files
+        let service = LintService::new(linter).with_apply_fixes(false);
+        Self { options, service }
     }
 
-    /// # Panics
-    ///
-    /// * When `mpsc::channel` fails to send.
     pub fn run_full(&self) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
         let number_of_files = Arc::new(AtomicUsize::new(0));
         let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<ErrorWithPosition>)>();
 
-        self.process_paths(&number_of_files, tx_error);
+        let walk = Walk::new(&self.options)
+            .with_config_store(self.service.linter().config_store().cloned());
+        self.service.spawn(walk, &number_of_files, tx_error, Self::lint_path);
         Self::process_diagnostics(&rx_error)
     }
 
     pub fn run_single(&self, path: &Path) -> Option<Vec<DiagnosticReport>> {
-        if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path).map_or(vec![], |(p, errors)| {
+        // Clear the diagnostics of files ignored by `ignorePatterns`, like the walk of `run_full`
+        if self.is_ignored(path) {
+            return Some(vec![]);
+        }
+        if has_lintable_extension(path) {
+            Some(Self::lint_path(&self.service, path).map_or(vec![], |(p, errors)| {
                 errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect()
             }))
         } else {
@@ -164,42 +175,11 @@ impl IsolatedLintHandler {
         }
     }
 
-    fn is_wanted_ext(path: &Path) -> bool {
-        path.extension()
-            .map_or(false, |ext| VALID_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
-    }
-
-    fn process_paths(
-        &self,
-        number_of_files: &Arc<AtomicUsize>,
-        tx_error: mpsc::Sender<(PathBuf, Vec<ErrorWithPosition>)>,
-    ) {
-        let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
-
-        let walk = Walk::new(&self.options);
-        let number_of_files = Arc::clone(number_of_files);
-        rayon::spawn(move || {
-            let mut count = 0;
-            walk.iter().for_each(|path| {
-                count += 1;
-                tx_path.send(path).unwrap();
-            });
-            number_of_files.store(count, Ordering::Relaxed);
-        });
-
-        let linter = Arc::clone(&self.linter);
-        rayon::spawn(move || {
-            while let Ok(path) = rx_path.recv() {
-                let tx_error = tx_error.clone();
-                let linter = Arc::clone(&linter);
-                rayon::spawn(move || {
-                    if let Some(diagnostics) = Self::lint_path(&linter, &path) {
-                        tx_error.send(diagnostics).unwrap();
-                    }
-                    drop(tx_error);
-                });
-            }
-        });
+    fn is_ignored(&self, path: &Path) -> bool {
+        self.service
+            .linter()
+            .config_store()
+            .is_some_and(|config_store| config_store.is_ignored(path).unwrap_or_default())
     }
 
     fn process_diagnostics(
@@ -216,79 +196,39 @@ impl IsolatedLintHandler {
             .collect()
     }
 
-    fn lint_path(linter: &Linter, path: &Path) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+    fn lint_path(service: &LintService, path: &Path) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let source_text =
             fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
-        let allocator = Allocator::default();
-        let source_type =
-            SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
-        let ret = Parser::new(&allocator, &source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
-
-        if !ret.errors.is_empty() {
-            let reports = ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
-
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
-        };
+        let LintResult { messages, .. } =
+            service.lint_source(path, &source_text).unwrap_or_else(|| panic!("Incorrect {path:?}"));
 
-        let program = allocator.alloc(ret.program);
-        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
-            .with_trivias(ret.trivias)
-            .with_check_syntax_error(true)
-            .build(program);
-
-        if !semantic_ret.errors.is_empty() {
-            let reports = semantic_ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
-        };
-
-        let lint_ctx = LintContext::new(&Rc::new(semantic_ret.semantic));
-        let result = linter.run(lint_ctx);
-
-        if result.is_empty() {
+        if messages.is_empty() {
             return None;
         }
 
-        if linter.has_fix() {
-            let reports = result
-                .into_iter()
-                .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(f.span.start as usize, &source_text)
-                                .unwrap_or_default(),
-                            end: offset_to_position(f.span.end as usize, &source_text)
-                                .unwrap_or_default(),
-                        },
-                    });
-
-                    ErrorReport { error: msg.error, fixed_content }
-                })
-                .collect::<Vec<ErrorReport>>();
-
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
-        }
-
-        let errors = result
+        let line_index = LineIndex::new(&source_text);
+        let has_fix = service.linter().has_fix();
+        let reports = messages
             .into_iter()
-            .map(|diagnostic| ErrorReport { error: diagnostic.error, fixed_content: None })
+            .map(|message| {
+                let fixed_content = message.fix.filter(|_| has_fix).map(|fix| FixedContent {
+                    code: fix.content.to_string(),
+                    range: Range {
+                        start: position(&line_index, fix.span.start as usize),
+                        end: position(&line_index, fix.span.end as usize),
+                    },
+                });
+
+                ErrorReport { error: message.error, fixed_content, rule: message.rule }
+            })
             .collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        Some(Self::wrap_diagnostics(path, &source_text, &line_index, reports))
     }
 
     fn wrap_diagnostics(
         path: &Path,
         source_text: &str,
+        line_index: &LineIndex,
         reports: Vec<ErrorReport>,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
@@ -297,8 +237,9 @@ impl IsolatedLintHandler {
             .map(|report| {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
-                    source_text,
+                    line_index,
                     report.fixed_content,
+                    report.rule,
                 )
             })
             .collect();
@@ -306,56 +247,79 @@ impl IsolatedLintHandler {
     }
 }
 
-#[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_char_to_line(offset).ok()?;
-    let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    let column = offset - first_char_of_line;
-    Some(Position::new(line as u32, column as u32))
+/// LSP positions are 0-based, with the character counted in UTF-16 code units.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn position(line_index: &LineIndex, offset: usize) -> Position {
+    let location = line_index.location(offset);
+    Position::new((location.line - 1) as u32, (location.column - 1) as u32)
 }
 
 #[derive(Debug)]
 pub struct ServerLinter {
-    linter: Arc<Linter>,
+    /// Built for each workspace root on first use, which is only known after initialization,
+    /// and again by [`ServerLinter::reload`] when a configuration file changes
+    linters: DashMap<Url, Arc<Linter>>,
 }
 
 impl ServerLinter {
     pub fn new() -> Self {
+        Self { linters: DashMap::default() }
+    }
+
+    /// Build the linter of `root_uri` again from its configuration files.
+    /// Returns the error of an invalid configuration file, the default rules are used instead.
+    pub fn reload(&self, root_uri: &Url) -> Option<Error> {
+        let (linter, error) = Self::create_linter(root_uri);
+        self.linters.insert(root_uri.clone(), Arc::new(linter));
+        error
+    }
+
+    pub fn run_full(&self, root_uri: &Url) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
+        let options = Self::walk_options(root_uri);
+        IsolatedLintHandler::new(Arc::new(options), self.linter(root_uri)).run_full()
+    }
+
+    pub fn run_single(&self, root_uri: &Url, uri: &Url) -> Option<Vec<DiagnosticReport>> {
+        let options = Self::walk_options(root_uri);
+        IsolatedLintHandler::new(Arc::new(options), self.linter(root_uri))
+            .run_single(&uri.to_file_path().unwrap())
+    }
+
+    fn linter(&self, root_uri: &Url) -> Arc<Linter> {
+        if let Some(linter) = self.linters.get(root_uri) {
+            return Arc::clone(&linter);
+        }
+        // The errors of the configuration files are reported by `reload` when the server is initialized
+        let (linter, _) = Self::create_linter(root_uri);
+        Arc::clone(&self.linters.entry(root_uri.clone()).or_insert_with(|| Arc::new(linter)))
+    }
+
+    /// The configuration files are discovered from the workspace root, like the CLI does
+    /// from its working directory.
+    fn create_linter(root_uri: &Url) -> (Linter, Option<Error>) {
         let linter = Linter::from_rules(
             RULES
                 .iter()
                 .cloned()
                 .filter(|rule| rule.category() != RuleCategory::Nursery)
+                .map(|rule| RuleWithSeverity::new(rule, AllowWarnDeny::Warn))
                 .collect::<Vec<_>>(),
         )
-        .with_fix(true);
-
-        Self { linter: Arc::new(linter) }
-    }
-
-    pub fn run_full(&self, root_uri: &Url) -> Vec<(PathBuf, Vec<DiagnosticReport>)> {
-        let options = LintOptions {
-            paths: vec![root_uri.to_file_path().unwrap()],
-            ignore_path: "node_modules".into(),
-            ignore_pattern: vec!["!**/node_modules/**/*".into()],
-            fix: true,
-            ..LintOptions::default()
-        };
-
-        IsolatedLintHandler::new(Arc::new(options), Arc::clone(&self.linter)).run_full()
+        .with_fix(true)
+        .with_walk_options(Self::walk_options(root_uri));
+        match ConfigStore::discover(&root_uri.to_file_path().unwrap()) {
+            Ok(config_store) => (linter.with_config_store(Arc::new(config_store)), None),
+            // Lint with the default rules instead of failing for every file
+            Err(error) => (linter, Some(error)),
+        }
     }
 
-    pub fn run_single(&self, root_uri: &Url, uri: &Url) -> Option<Vec<DiagnosticReport>> {
-        let options = LintOptions {
+    fn walk_options(root_uri: &Url) -> WalkOptions {
+        WalkOptions {
             paths: vec![root_uri.to_file_path().unwrap()],
             ignore_path: "node_modules".into(),
+            no_ignore: false,
             ignore_pattern: vec!["!**/node_modules/**/*".into()],
-            fix: true,
-            ..LintOptions::default()
-        };
-
-        IsolatedLintHandler::new(Arc::new(options), Arc::clone(&self.linter))
-            .run_single(&uri.to_file_path().unwrap())
+        }
     }
 }
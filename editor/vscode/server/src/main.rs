@@ -1,22 +1,22 @@
 mod linter;
-mod options;
-mod walk;
 
 use crate::linter::{DiagnosticReport, ServerLinter};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::path::PathBuf;
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use futures::future::join_all;
+use oxc_linter::config::CONFIG_FILE_NAMES;
 use tokio::sync::{OnceCell, SetError};
 use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
     CodeActionProviderCapability, CodeActionResponse, Diagnostic, DidChangeTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, MessageType, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    DidChangeWatchedFilesParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    InitializeParams, InitializeResult, InitializedParams, MessageType, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+    WorkDoneProgressOptions, WorkspaceEdit,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
@@ -26,6 +26,8 @@ struct Backend {
     root_uri: OnceCell<Option<Url>>,
     server_linter: ServerLinter,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// Files with diagnostics from linting the whole workspace, cleared when linting it again
+    workspace_files: DashSet<PathBuf>,
 }
 
 #[tower_lsp::async_trait]
@@ -58,15 +60,24 @@ impl LanguageServer for Backend {
         self.client.log_message(MessageType::INFO, "oxc initialized.").await;
 
         if let Some(Some(root_uri)) = self.root_uri.get() {
-            let result = self.server_linter.run_full(root_uri);
+            self.reload_linter(root_uri).await;
+            self.lint_workspace(root_uri).await;
+        }
+    }
 
-            self.publish_all_diagnostics(
-                &result
-                    .into_iter()
-                    .map(|(p, d)| (p, d.into_iter().map(|d| d.diagnostic).collect()))
-                    .collect(),
-            )
-            .await;
+    /// The client watches the configuration files, see `synchronize.fileEvents` of the extension
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let is_config_file = |uri: &Url| {
+            uri.to_file_path().ok().is_some_and(|path| {
+                path.file_name().is_some_and(|name| CONFIG_FILE_NAMES.iter().any(|n| name == *n))
+            })
+        };
+        if !params.changes.iter().any(|change| is_config_file(&change.uri)) {
+            return;
+        }
+        if let Some(Some(root_uri)) = self.root_uri.get() {
+            self.reload_linter(root_uri).await;
+            self.lint_workspace(root_uri).await;
         }
     }
 
@@ -140,6 +151,41 @@ impl Backend {
         })
     }
 
+    /// Build the linter from the configuration files, showing their errors to the user.
+    async fn reload_linter(&self, root_uri: &Url) {
+        if let Some(error) = self.server_linter.reload(root_uri) {
+            self.client
+                .show_message(
+                    MessageType::ERROR,
+                    format!("oxc: {error}, linting with the default rules instead."),
+                )
+                .await;
+        }
+    }
+
+    /// Lint all files of the workspace, clearing the diagnostics of the files without any now.
+    async fn lint_workspace(&self, root_uri: &Url) {
+        let result = self
+            .server_linter
+            .run_full(root_uri)
+            .into_iter()
+            .map(|(p, d)| (p, d.into_iter().map(|d| d.diagnostic).collect()))
+            .collect::<Vec<(PathBuf, Vec<Diagnostic>)>>();
+        let files = result.iter().map(|(path, _)| path.clone()).collect::<HashSet<_>>();
+        let cleared = self
+            .workspace_files
+            .iter()
+            .filter(|path| !files.contains(path.key()))
+            .map(|path| (path.clone(), vec![]))
+            .collect::<Vec<_>>();
+        self.workspace_files.clear();
+        for path in files {
+            self.workspace_files.insert(path);
+        }
+        let result = result.into_iter().chain(cleared).collect();
+        self.publish_all_diagnostics(&result).await;
+    }
+
     #[allow(clippy::ptr_arg)]
     async fn publish_all_diagnostics(&self, result: &Vec<(PathBuf, Vec<Diagnostic>)>) {
         join_all(result.iter().map(|(path, diagnostics)| {
@@ -184,6 +230,7 @@ async fn main() {
         root_uri: OnceCell::new(),
         server_linter,
         diagnostics_report_map,
+        workspace_files: DashSet::new(),
     })
     .finish();
 
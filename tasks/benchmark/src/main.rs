@@ -8,10 +8,11 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use std::time::Duration;
+use std::{path::Path, rc::Rc, time::Duration};
 
 use criterion::{BenchmarkId, Criterion, Throughput};
 use oxc_allocator::Allocator;
+use oxc_linter::{AllowWarnDeny, LintContext, Linter, RuleWithSeverity, RULES};
 use oxc_minifier::{Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -55,6 +56,7 @@ pub fn main() -> Result<(), String> {
     bench_parser(&mut criterion, &files);
     bench_semantic(&mut criterion, &files);
     bench_minifier(&mut criterion, &files);
+    bench_linter(&mut criterion, &files);
     drop(criterion);
 
     Ok(())
@@ -121,3 +123,38 @@ fn bench_semantic(criterion: &mut Criterion, files: &[&TestFile]) {
     }
     group.finish();
 }
+
+/// All rules are enabled, most of them only handle a few types of AST nodes.
+fn bench_linter(criterion: &mut Criterion, files: &[&TestFile]) {
+    let mut group = criterion.benchmark_group("linter");
+    let rules = RULES
+        .iter()
+        .cloned()
+        .map(|rule| RuleWithSeverity::new(rule, AllowWarnDeny::Deny))
+        .collect::<Vec<_>>();
+    let linter = Linter::from_rules(rules);
+    for file in files {
+        group.throughput(Throughput::Bytes(file.source_text.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&file.file_name),
+            &file.source_text,
+            |b, source_text| {
+                let source_type = SourceType::from_path(&file.file_name).unwrap();
+                let allocator = Allocator::default();
+                let ret = Parser::new(&allocator, source_text, source_type).parse();
+                let program = allocator.alloc(ret.program);
+                let semantic = SemanticBuilder::new(source_text, source_type)
+                    .with_trivias(ret.trivias)
+                    .with_module_record_builder(true)
+                    .build(program)
+                    .semantic;
+                let semantic = Rc::new(semantic);
+                b.iter_with_large_drop(|| {
+                    let ctx = LintContext::new(Path::new(&file.file_name).into(), &semantic);
+                    linter.run(ctx)
+                });
+            },
+        );
+    }
+    group.finish();
+}